@@ -8,6 +8,38 @@ macro_rules! any_impl {
         where
             A: Allocator,
         {
+            #[must_use]
+            /// Gets a reference to the front value of the list, downcast to `T`.
+            ///
+            /// If the list is empty or `T` does not match the value's type, this returns [`None`].
+            pub fn front_downcast_ref<T: 'static>(&self) -> Option<&T> {
+                self.front()?.downcast_ref()
+            }
+
+            #[must_use]
+            /// Gets a reference to the back value of the list, downcast to `T`.
+            ///
+            /// If the list is empty or `T` does not match the value's type, this returns [`None`].
+            pub fn back_downcast_ref<T: 'static>(&self) -> Option<&T> {
+                self.back()?.downcast_ref()
+            }
+
+            #[must_use]
+            /// Gets a mutable reference to the front value of the list, downcast to `T`.
+            ///
+            /// If the list is empty or `T` does not match the value's type, this returns [`None`].
+            pub fn front_downcast_mut<T: 'static>(&mut self) -> Option<&mut T> {
+                self.front_mut()?.downcast_mut()
+            }
+
+            #[must_use]
+            /// Gets a mutable reference to the back value of the list, downcast to `T`.
+            ///
+            /// If the list is empty or `T` does not match the value's type, this returns [`None`].
+            pub fn back_downcast_mut<T: 'static>(&mut self) -> Option<&mut T> {
+                self.back_mut()?.downcast_mut()
+            }
+
             /// Removes the front value from the list, downcasts it and returns it.
             ///
             /// If the list is empty or `T` does not match the value's type, this returns [`None`] and no nodes are removed.
@@ -47,6 +79,96 @@ macro_rules! any_impl {
                     unsafe { node.value_ptr().cast::<T>().read() },
                 )
             }
+
+            #[cfg(feature = "alloc")]
+            /// Removes the front value from the list and downcasts it, or returns it in a [`Box`](crate::alloc::Box) if it is not a `T`.
+            ///
+            /// Unlike [`Self::pop_front_downcast`], the node is removed whether or not `T` matches the value's type, so a sequence of typed pops always makes progress.
+            /// If the list is empty, this returns [`None`].
+            ///
+            /// # Examples
+            /// ```
+            /// # use core::any::Any;
+            /// # use dyn_list::DynList;
+            /// let mut list = DynList::<dyn Any>::new();
+            /// list.push_back_unsize(1_u8);
+            /// list.push_back_unsize("two");
+            ///
+            /// let mut numbers = Vec::new();
+            /// while let Some(result) = list.pop_front_downcast_or_boxed::<u8>() {
+            ///     match result {
+            ///         Ok(number) => numbers.push(number),
+            ///         Err(other) => assert_eq!(other.downcast_ref(), Some(&"two")),
+            ///     }
+            /// }
+            /// assert_eq!(numbers, [1]);
+            /// ```
+            pub fn pop_front_downcast_or_boxed<T: 'static>(
+                &mut self,
+            ) -> Option<Result<T, crate::alloc::Box<$dynAny, A>>>
+            where
+                A: Clone,
+            {
+                let node = self.pop_front_node()?;
+                // SAFETY:
+                // The node was in the list, so its value is initialised.
+                let is_t = unsafe { node.as_ptr().as_ref() }.is::<T>();
+                Some(if is_t {
+                    // SAFETY:
+                    // The value is initialised and of type `T`, and the node is deallocated without
+                    // dropping it.
+                    Ok(unsafe { node.value_ptr().cast::<T>().read() })
+                } else {
+                    // SAFETY:
+                    // The value is initialised.
+                    Err(unsafe { node.take_boxed() })
+                })
+            }
+
+            #[cfg(feature = "alloc")]
+            /// Removes the back value from the list and downcasts it, or returns it in a [`Box`](crate::alloc::Box) if it is not a `T`.
+            ///
+            /// Unlike [`Self::pop_back_downcast`], the node is removed whether or not `T` matches the value's type, so a sequence of typed pops always makes progress.
+            /// If the list is empty, this returns [`None`].
+            ///
+            /// # Examples
+            /// ```
+            /// # use core::any::Any;
+            /// # use dyn_list::DynList;
+            /// let mut list = DynList::<dyn Any>::new();
+            /// list.push_back_unsize(1_u8);
+            /// list.push_back_unsize("two");
+            ///
+            /// let mut numbers = Vec::new();
+            /// while let Some(result) = list.pop_back_downcast_or_boxed::<u8>() {
+            ///     match result {
+            ///         Ok(number) => numbers.push(number),
+            ///         Err(other) => assert_eq!(other.downcast_ref(), Some(&"two")),
+            ///     }
+            /// }
+            /// assert_eq!(numbers, [1]);
+            /// ```
+            pub fn pop_back_downcast_or_boxed<T: 'static>(
+                &mut self,
+            ) -> Option<Result<T, crate::alloc::Box<$dynAny, A>>>
+            where
+                A: Clone,
+            {
+                let node = self.pop_back_node()?;
+                // SAFETY:
+                // The node was in the list, so its value is initialised.
+                let is_t = unsafe { node.as_ptr().as_ref() }.is::<T>();
+                Some(if is_t {
+                    // SAFETY:
+                    // The value is initialised and of type `T`, and the node is deallocated without
+                    // dropping it.
+                    Ok(unsafe { node.value_ptr().cast::<T>().read() })
+                } else {
+                    // SAFETY:
+                    // The value is initialised.
+                    Err(unsafe { node.take_boxed() })
+                })
+            }
         }
     };
 }
@@ -54,3 +176,67 @@ macro_rules! any_impl {
 any_impl! { dyn Any }
 any_impl! { dyn Any + Send }
 any_impl! { dyn Any + Send + Sync }
+
+#[cfg(test)]
+mod test {
+    use core::any::Any;
+
+    use crate::DynList;
+
+    #[test]
+    fn downcast_ref() {
+        let mut list = DynList::<dyn Any>::new();
+        assert_eq!(list.front_downcast_ref::<u8>(), None);
+
+        list.push_back_unsize(1_u8);
+        list.push_back_unsize("two");
+
+        assert_eq!(list.front_downcast_ref::<u8>(), Some(&1));
+        assert_eq!(list.front_downcast_ref::<&str>(), None);
+        assert_eq!(list.back_downcast_ref::<&str>(), Some(&"two"));
+
+        *list.front_downcast_mut::<u8>().unwrap() += 2;
+        *list.back_downcast_mut::<&str>().unwrap() = "four";
+        assert_eq!(list.back_downcast_mut::<u8>(), None);
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.pop_front_downcast::<u8>(), Some(3));
+        assert_eq!(list.pop_back_downcast::<&str>(), Some("four"));
+    }
+
+    #[test]
+    fn pop_downcast_or_boxed() {
+        let mut list = DynList::<dyn Any + Send>::new();
+        assert!(list.pop_front_downcast_or_boxed::<u8>().is_none());
+
+        list.push_back_unsize(String::from("one"));
+        list.push_back_unsize(2_u8);
+        list.push_back_unsize(3_u8);
+        list.push_back_unsize(4_u16);
+
+        assert!(list.pop_front_downcast::<u8>().is_none());
+        let Some(Err(boxed)) = list.pop_front_downcast_or_boxed::<u8>() else {
+            panic!("the front value is not a `u8`");
+        };
+        assert_eq!(
+            boxed.downcast_ref::<String>().map(String::as_str),
+            Some("one")
+        );
+
+        assert_eq!(
+            list.pop_front_downcast_or_boxed::<u8>().map(Result::ok),
+            Some(Some(2))
+        );
+        let Some(Err(boxed)) = list.pop_back_downcast_or_boxed::<u8>() else {
+            panic!("the back value is not a `u8`");
+        };
+        assert_eq!(boxed.downcast_ref::<u16>(), Some(&4));
+        assert_eq!(
+            list.pop_back_downcast_or_boxed::<u8>().map(Result::ok),
+            Some(Some(3))
+        );
+
+        assert!(list.is_empty());
+        list.check_debug();
+    }
+}
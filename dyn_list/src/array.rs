@@ -1,5 +1,8 @@
 use core::alloc::Allocator;
 
+#[cfg(feature = "alloc")]
+use crate::alloc;
+
 use dynode::AllocateError;
 
 use crate::{
@@ -25,6 +28,7 @@ where
         };
 
         node::try_new_array(self, length, header)
+            .map_err(|error| error.with_context("allocate_uninit_array_front"))
     }
 
     /// Attempts to allocate an uninitialised array node at the back of the list.
@@ -41,6 +45,7 @@ where
         };
 
         node::try_new_array(self, length, header)
+            .map_err(|error| error.with_context("allocate_uninit_array_back"))
     }
 
     #[must_use]
@@ -55,6 +60,42 @@ where
         AllocateError::unwrap_result(self.try_allocate_uninit_array_back(length))
     }
 
+    #[must_use]
+    /// Gets the length of the array at the front of the list.
+    ///
+    /// This reads the length stored in the node, so the array's elements are not accessed.
+    /// If the list is empty, this returns [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<[u8]>::new();
+    /// assert_eq!(list.front_len(), None);
+    ///
+    /// list.push_back_copy_array(&[1, 2, 3]);
+    /// list.push_back_copy_array(&[4]);
+    /// assert_eq!(list.front_len(), Some(3));
+    /// assert_eq!(list.back_len(), Some(1));
+    /// ```
+    pub fn front_len(&self) -> Option<usize> {
+        let Ends { front, .. } = self.ends?;
+        // SAFETY:
+        // The node is in the list, so it has not been deallocated.
+        Some(unsafe { front.metadata() })
+    }
+
+    #[must_use]
+    /// Gets the length of the array at the back of the list.
+    ///
+    /// This reads the length stored in the node, so the array's elements are not accessed.
+    /// If the list is empty, this returns [`None`].
+    pub fn back_len(&self) -> Option<usize> {
+        let Ends { back, .. } = self.ends?;
+        // SAFETY:
+        // The node is in the list, so it has not been deallocated.
+        Some(unsafe { back.metadata() })
+    }
+
     // TODO: rename `try_push_front_copy_slice` for consistency
     /// Attempts to copy the array `src` and push it to the front of the list.
     ///
@@ -64,7 +105,9 @@ where
     where
         T: Copy,
     {
-        let mut node = self.try_allocate_uninit_array_front(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_array_front(src.len())
+            .map_err(|error| error.with_context("push_front_copy_array"))?;
         node.copy_from_slice(src);
         unsafe { node.insert() };
         Ok(())
@@ -79,7 +122,9 @@ where
     where
         T: Copy,
     {
-        let mut node = self.try_allocate_uninit_array_back(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_array_back(src.len())
+            .map_err(|error| error.with_context("push_back_copy_array"))?;
         node.copy_from_slice(src);
         unsafe { node.insert() };
         Ok(())
@@ -116,7 +161,9 @@ where
     where
         T: Clone,
     {
-        let mut node = self.try_allocate_uninit_array_front(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_array_front(src.len())
+            .map_err(|error| error.with_context("push_front_clone_array"))?;
         node.clone_from_slice(src);
         unsafe { node.insert() };
         Ok(())
@@ -131,7 +178,9 @@ where
     where
         T: Clone,
     {
-        let mut node = self.try_allocate_uninit_array_back(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_array_back(src.len())
+            .map_err(|error| error.with_context("push_back_clone_array"))?;
         node.clone_from_slice(src);
         unsafe { node.insert() };
         Ok(())
@@ -158,4 +207,188 @@ where
         node.clone_from_slice(src);
         unsafe { node.insert() };
     }
+
+    /// Attempts to push an array of `length` elements to the front of the list, where each element is returned by calling `f` with its index.
+    ///
+    /// The node is only inserted once every element has been written.
+    /// If `f` panics, the elements that have already been written are dropped and the node is deallocated.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`](core::alloc::Layout::array), this will return an [`AllocateError`].
+    pub fn try_push_front_array_with<F>(&mut self, length: usize, f: F) -> Result<(), AllocateError>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let mut node = self
+            .try_allocate_uninit_array_front(length)
+            .map_err(|error| error.with_context("push_front_array_with"))?;
+        let written = node.write_from_iter((0..length).map(f));
+        debug_assert_eq!(written, length);
+        // SAFETY:
+        // All `length` elements have been written.
+        unsafe { node.insert() };
+        Ok(())
+    }
+
+    /// Attempts to push an array of `length` elements to the back of the list, where each element is returned by calling `f` with its index.
+    ///
+    /// See [`Self::try_push_front_array_with`] for more information.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`](core::alloc::Layout::array), this will return an [`AllocateError`].
+    pub fn try_push_back_array_with<F>(&mut self, length: usize, f: F) -> Result<(), AllocateError>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let mut node = self
+            .try_allocate_uninit_array_back(length)
+            .map_err(|error| error.with_context("push_back_array_with"))?;
+        let written = node.write_from_iter((0..length).map(f));
+        debug_assert_eq!(written, length);
+        // SAFETY:
+        // All `length` elements have been written.
+        unsafe { node.insert() };
+        Ok(())
+    }
+
+    /// Pushes an array of `length` elements to the front of the list, where each element is returned by calling `f` with its index.
+    ///
+    /// See [`Self::try_push_front_array_with`] for more information.
+    pub fn push_front_array_with<F>(&mut self, length: usize, f: F)
+    where
+        F: FnMut(usize) -> T,
+    {
+        AllocateError::unwrap_result(self.try_push_front_array_with(length, f));
+    }
+
+    /// Pushes an array of `length` elements to the back of the list, where each element is returned by calling `f` with its index.
+    ///
+    /// See [`Self::try_push_front_array_with`] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<[usize]>::new();
+    /// list.push_back_array_with(4, |index| index * index);
+    /// assert_eq!(list.back(), Some(&[0, 1, 4, 9][..]));
+    /// ```
+    pub fn push_back_array_with<F>(&mut self, length: usize, f: F)
+    where
+        F: FnMut(usize) -> T,
+    {
+        AllocateError::unwrap_result(self.try_push_back_array_with(length, f));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    /// Concatenates the arrays in the list into a single [`Vec`](alloc::Vec).
+    ///
+    /// The total length is calculated first, so this allocates only once.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<[u8]>::from_iter([&[1, 2][..], &[3]]);
+    /// assert_eq!(list.concat(), [1, 2, 3]);
+    /// ```
+    pub fn concat(&self) -> alloc::Vec<T>
+    where
+        T: Clone,
+    {
+        let length = self.iter().map(<[T]>::len).sum();
+        let mut vec = alloc::Vec::with_capacity(length);
+        for item in self {
+            vec.extend_from_slice(item);
+        }
+        vec
+    }
+}
+
+impl<'a, Item, A> Extend<&'a [Item]> for DynList<[Item], A>
+where
+    Item: Copy,
+    A: Allocator,
+{
+    fn extend<T: IntoIterator<Item = &'a [Item]>>(&mut self, iter: T) {
+        for item in iter {
+            self.push_back_copy_array(item);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, Item> FromIterator<&'a [Item]> for DynList<[Item]>
+where
+    Item: Copy,
+{
+    fn from_iter<T: IntoIterator<Item = &'a [Item]>>(iter: T) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::panic;
+
+    use crate::DynList;
+
+    #[test]
+    fn lengths() {
+        let mut list = DynList::<[u16]>::new();
+        assert_eq!((list.front_len(), list.back_len()), (None, None));
+
+        list.push_back_copy_array(&[1, 2]);
+        assert_eq!((list.front_len(), list.back_len()), (Some(2), Some(2)));
+        list.push_back_copy_array(&[]);
+        list.push_front_copy_array(&[3; 5]);
+        assert_eq!((list.front_len(), list.back_len()), (Some(5), Some(0)));
+
+        let mut cursor = list.cursor_front_mut();
+        for len in [Some(5), Some(2), Some(0), None, Some(5)] {
+            assert_eq!(cursor.current_len(), len);
+            assert_eq!(cursor.as_cursor().current_len(), len);
+            assert_eq!(cursor.current().map(|slice| slice.len()), len);
+            cursor.move_next();
+        }
+    }
+
+    #[test]
+    fn concat() {
+        let mut list = DynList::<[String]>::new();
+        assert!(list.concat().is_empty());
+
+        let strings = [String::from("a"), String::from("b")];
+        list.push_back_clone_array(&strings);
+        assert_eq!(list.concat(), strings);
+
+        list.push_back_clone_array(&[]);
+        list.push_back_clone_array(&strings[..1]);
+        assert_eq!(list.concat(), ["a", "b", "a"]);
+    }
+
+    #[test]
+    fn push_array_with() {
+        let mut list = DynList::<[String]>::new();
+        list.push_back_array_with(2, |index| index.to_string());
+        list.push_front_array_with(0, |_| unreachable!());
+        list.push_back_array_with(1, |_| String::from("a"));
+        list.check_debug();
+        assert!(list.iter().eq([
+            &[][..],
+            &[String::from("0"), String::from("1")],
+            &[String::from("a")]
+        ]));
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.push_back_array_with(3, |index| {
+                assert!(index < 2, "f panicked");
+                index.to_string()
+            });
+        }));
+        assert!(result.is_err());
+        list.check_debug();
+        assert_eq!(list.len(), 3);
+    }
 }
@@ -1,15 +1,46 @@
 use core::{alloc::Allocator, any::Any};
 
-use super::CursorMut;
+use super::{Cursor, CursorMut};
 
 macro_rules! any_impl {
     ( $dynAny:ty ) => {
+        impl<'a, A> Cursor<'a, $dynAny, A>
+        where
+            A: Allocator,
+        {
+            #[must_use]
+            /// Gets a reference to the current element, downcast to `T`.
+            ///
+            /// If the cursor is pointing to the "ghost" element, or `T` does not match the value's type, this returns [`None`].
+            pub fn current_downcast_ref<T: 'static>(&self) -> Option<&'a T> {
+                self.current()?.downcast_ref()
+            }
+        }
+
         impl<A> CursorMut<'_, $dynAny, A>
         where
             A: Allocator,
         {
+            #[must_use]
+            /// Gets a reference to the current element, downcast to `T`.
+            ///
+            /// If the cursor is pointing to the "ghost" element, or `T` does not match the value's type, this returns [`None`].
+            pub fn current_downcast_ref<T: 'static>(&self) -> Option<&T> {
+                self.as_cursor().current_downcast_ref()
+            }
+
+            #[must_use]
+            /// Gets a mutable reference to the current element, downcast to `T`.
+            ///
+            /// If the cursor is pointing to the "ghost" element, or `T` does not match the value's type, this returns [`None`].
+            pub fn current_downcast_mut<T: 'static>(&mut self) -> Option<&mut T> {
+                self.current()?.downcast_mut()
+            }
+
             /// Removes the current element, downcasts it and returns it.
             ///
+            /// On success, the cursor moves to the previous element.
+            ///
             /// If the cursor is pointing to the "ghost" element, or `T` does not match the value's type, this returns [`None`].
             pub fn remove_current_downcast<T: 'static>(&mut self) -> Option<T> {
                 if !self.current()?.is::<T>() {
@@ -34,3 +65,41 @@ macro_rules! any_impl {
 any_impl! { dyn Any }
 any_impl! { dyn Any + Send }
 any_impl! { dyn Any + Send + Sync }
+
+#[cfg(test)]
+mod test {
+    use core::any::Any;
+
+    use crate::DynList;
+
+    #[test]
+    fn current_downcast() {
+        let mut list = DynList::<dyn Any + Send>::new();
+        list.push_back_unsize(1_u32);
+        list.push_back_unsize("skip");
+        list.push_back_unsize(2_u32);
+
+        let mut cursor = list.cursor_front_mut();
+        while cursor.index().is_some() {
+            if let Some(value) = cursor.current_downcast_mut::<u32>() {
+                *value *= 10;
+            }
+            cursor.move_next();
+        }
+        assert_eq!(cursor.current_downcast_mut::<u32>(), None);
+
+        // Shared downcasts can be held alongside other shared borrows of the cursor
+        cursor.move_next();
+        let value = cursor.current_downcast_ref::<u32>();
+        assert_eq!(cursor.as_list().len(), 3);
+        assert_eq!(value, Some(&10));
+
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.current_downcast_ref::<u32>(), Some(&10));
+        cursor.move_next();
+        assert_eq!(cursor.current_downcast_ref::<u32>(), None);
+        assert_eq!(cursor.current_downcast_ref::<&str>(), Some(&"skip"));
+        cursor.move_next();
+        assert_eq!(cursor.current_downcast_ref::<u32>(), Some(&20));
+    }
+}
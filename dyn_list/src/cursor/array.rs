@@ -5,12 +5,43 @@ use crate::{
     AllocateError, Ends, MaybeUninitNode,
 };
 
-use super::CursorMut;
+use super::{Cursor, CursorMut};
+
+impl<T, A> Cursor<'_, [T], A>
+where
+    A: Allocator,
+{
+    #[must_use]
+    /// Gets the length of the current array.
+    ///
+    /// This reads the length stored in the node, so the array's elements are not accessed.
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
+    pub fn current_len(&self) -> Option<usize> {
+        self.current.map(|node| {
+            // SAFETY:
+            // The node is in the list, so it has not been deallocated.
+            unsafe { node.metadata() }
+        })
+    }
+}
 
 impl<T, A> CursorMut<'_, [T], A>
 where
     A: Allocator,
 {
+    #[must_use]
+    /// Gets the length of the current array.
+    ///
+    /// This reads the length stored in the node, so the array's elements are not accessed.
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
+    pub fn current_len(&self) -> Option<usize> {
+        self.current.map(|node| {
+            // SAFETY:
+            // The node is in the list, so it has not been deallocated.
+            unsafe { node.metadata() }
+        })
+    }
+
     /// Attempts to allocate an uninitialised array node before the current node.
     ///
     /// If the cursor is on the "ghost" element, this will allocate the node at the back of the list.
@@ -30,6 +61,7 @@ where
         );
 
         node::try_new_array(&mut *self.list, length, Header { next, previous })
+            .map_err(|error| error.with_context("allocate_uninit_array_before"))
     }
 
     /// Attempts to allocate an uninitialised array node after the current node.
@@ -51,6 +83,7 @@ where
         );
 
         node::try_new_array(&mut *self.list, length, Header { next, previous })
+            .map_err(|error| error.with_context("allocate_uninit_array_after"))
     }
 
     #[must_use]
@@ -77,7 +110,9 @@ where
     where
         T: Copy,
     {
-        let mut node = self.try_allocate_uninit_array_before(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_array_before(src.len())
+            .map_err(|error| error.with_context("insert_copy_slice_before"))?;
         node.copy_from_slice(src);
         unsafe { node.insert() };
         Ok(())
@@ -91,7 +126,9 @@ where
     where
         T: Copy,
     {
-        let mut node = self.try_allocate_uninit_array_after(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_array_after(src.len())
+            .map_err(|error| error.with_context("insert_copy_slice_after"))?;
         node.copy_from_slice(src);
         unsafe { node.insert() };
         Ok(())
@@ -127,7 +164,9 @@ where
     where
         T: Clone,
     {
-        let mut node = self.try_allocate_uninit_array_before(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_array_before(src.len())
+            .map_err(|error| error.with_context("insert_clone_slice_before"))?;
         node.clone_from_slice(src);
         unsafe { node.insert() };
         Ok(())
@@ -141,7 +180,9 @@ where
     where
         T: Clone,
     {
-        let mut node = self.try_allocate_uninit_array_after(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_array_after(src.len())
+            .map_err(|error| error.with_context("insert_clone_slice_after"))?;
         node.clone_from_slice(src);
         unsafe { node.insert() };
         Ok(())
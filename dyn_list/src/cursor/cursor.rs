@@ -1,8 +1,11 @@
 #[cfg(feature = "alloc")]
 use crate::alloc;
-use core::{alloc::Allocator, fmt};
+use core::{alloc::Allocator, fmt, iter::Rev};
 
-use crate::{DynList, Ends};
+use crate::{
+    iter::{Iter, RawIter},
+    DynList, Ends,
+};
 
 use super::super::node::Node;
 
@@ -19,6 +22,8 @@ pub struct Cursor<
 {
     pub(crate) current: Option<Node<U>>,
     pub(crate) list: &'a DynList<U, A>,
+    // The index of the current node; this is meaningless on the "ghost" element
+    pub(crate) index: usize,
 }
 
 impl<U, A> Clone for Cursor<'_, U, A>
@@ -31,6 +36,7 @@ where
         Self {
             current: self.current,
             list: self.list,
+            index: self.index,
         }
     }
 }
@@ -40,17 +46,40 @@ where
     U: ?Sized,
     A: Allocator,
 {
+    /// Gets the node after the current one, or the front if the cursor is on the "ghost" element.
+    fn next_node(&self) -> Option<Node<U>> {
+        self.current.map_or_else(
+            || self.list.ends.map(|Ends { front, .. }| front),
+            |node| {
+                // SAFETY:
+                // As the node is in the list, it's header must be properly initialised.
+                unsafe { node.header_ptr().as_ref() }.next
+            },
+        )
+    }
+
+    /// Gets the node before the current one, or the back if the cursor is on the "ghost" element.
+    fn previous_node(&self) -> Option<Node<U>> {
+        self.current.map_or_else(
+            || self.list.ends.map(|Ends { back, .. }| back),
+            |node| {
+                // SAFETY:
+                // As the node is in the list, it's header must be properly initialised.
+                unsafe { node.header_ptr().as_ref() }.previous
+            },
+        )
+    }
+
     /// Moves the cursor to the next element.
     ///
     /// If the cursor is on the "ghost" element, this moves to the front of the list.
     /// If the cursor is at the back of the list, this moves to the "ghost" element.
     pub fn move_next(&mut self) {
-        self.current = match self.current {
-            None => self.list.ends.map(|Ends { front, .. }| front),
-            // SAFETY:
-            // As the node is in the list, it's header must be properly initialised.
-            Some(node) => unsafe { node.header_ptr().as_ref() }.next,
-        }
+        self.index = match self.current {
+            None => 0,
+            Some(_) => self.index + 1,
+        };
+        self.current = self.next_node();
     }
 
     /// Moves the cursor to the previous element.
@@ -58,11 +87,103 @@ where
     /// If the cursor is on the "ghost" element, this moves to the back of the list.
     /// If the cursor is at the front of the list, this moves to the "ghost" element.
     pub fn move_previous(&mut self) {
-        self.current = match self.current {
-            None => self.list.ends.map(|Ends { back, .. }| back),
-            // SAFETY:
-            // As the node is in the list, it's header must be properly initialised.
-            Some(node) => unsafe { node.header_ptr().as_ref() }.previous,
+        self.index = match self.current {
+            None => self.list.len.saturating_sub(1),
+            Some(_) => self.index.wrapping_sub(1),
+        };
+        self.current = self.previous_node();
+    }
+
+    /// Moves the cursor forward `n` elements.
+    ///
+    /// This ends on the same element as calling [`Self::move_next`] `n` times, so the cursor passes through the "ghost" element between the back and the front, but walks the nodes in a single pass.
+    /// As the cursor is circular, an `n` larger than the length of the list wraps around, and the cursor moves backwards instead if that is shorter.
+    pub fn move_next_n(&mut self, n: usize) {
+        let cycle = self.list.len + 1;
+        let n = n % cycle;
+        if n <= cycle - n {
+            self.walk_next(n);
+        } else {
+            self.walk_previous(cycle - n);
+        }
+    }
+
+    /// Moves the cursor backward `n` elements.
+    ///
+    /// This ends on the same element as calling [`Self::move_previous`] `n` times, so the cursor passes through the "ghost" element between the front and the back, but walks the nodes in a single pass.
+    /// As the cursor is circular, an `n` larger than the length of the list wraps around, and the cursor moves forwards instead if that is shorter.
+    pub fn move_previous_n(&mut self, n: usize) {
+        let cycle = self.list.len + 1;
+        let n = n % cycle;
+        if n <= cycle - n {
+            self.walk_previous(n);
+        } else {
+            self.walk_next(cycle - n);
+        }
+    }
+
+    /// Moves the cursor forward `n` elements, where `n` is at most the length of the list.
+    fn walk_next(&mut self, n: usize) {
+        let (current, ghost_at) = super::walk_next(self.list, self.current, n);
+        self.current = current;
+        self.index = ghost_at.map_or_else(
+            || self.index + n,
+            |step| {
+                // The front is one step after the "ghost" element
+                (n - step).checked_sub(1).unwrap_or(self.list.len)
+            },
+        );
+    }
+
+    /// Moves the cursor backward `n` elements, where `n` is at most the length of the list.
+    fn walk_previous(&mut self, n: usize) {
+        let (current, ghost_at) = super::walk_previous(self.list, self.current, n);
+        self.current = current;
+        self.index = ghost_at.map_or_else(|| self.index - n, |step| self.list.len - (n - step));
+    }
+
+    /// Moves the cursor to the element at `index`.
+    ///
+    /// This walks from the front, the back or the current element, whichever is nearest.
+    /// If `index` is out of bounds, the cursor moves to the "ghost" element.
+    pub fn seek_to(&mut self, index: usize) {
+        let len = self.list.len;
+        let Some(Ends { front, back }) = self.list.ends.filter(|_| index < len) else {
+            self.current = None;
+            return;
+        };
+
+        let from_back = len - 1 - index;
+        let from_current = self
+            .current
+            .map_or(usize::MAX, |_| self.index.abs_diff(index));
+
+        if from_current <= index && from_current <= from_back {
+            if index >= self.index {
+                self.move_next_n(from_current);
+            } else {
+                self.move_previous_n(from_current);
+            }
+        } else if index <= from_back {
+            self.current = Some(front);
+            self.index = 0;
+            self.move_next_n(index);
+        } else {
+            self.current = Some(back);
+            self.index = len - 1;
+            self.move_previous_n(from_back);
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the index of the current element in the list.
+    ///
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
+    pub const fn index(&self) -> Option<usize> {
+        match self.current {
+            Some(_) => Some(self.index),
+            None => None,
         }
     }
 
@@ -81,12 +202,81 @@ where
         })
     }
 
+    #[must_use]
+    /// Gets a reference to the next element without moving the cursor.
+    ///
+    /// If the cursor is on the "ghost" element, this returns the front of the list.
+    /// If the cursor is at the back of the list, or the list is empty, this returns [`None`].
+    pub fn peek_next(&self) -> Option<&'a U> {
+        self.next_node().map(|node| {
+            // SAFETY:
+            // As the node is in the list, its metadata must be properly initialised.
+            let ptr = unsafe { node.data_ptr() };
+            // SAFETY:
+            // As the node is in the list, its value must be properly initialised.
+            unsafe { ptr.as_ref() }
+        })
+    }
+
+    #[must_use]
+    /// Gets a reference to the previous element without moving the cursor.
+    ///
+    /// If the cursor is on the "ghost" element, this returns the back of the list.
+    /// If the cursor is at the front of the list, or the list is empty, this returns [`None`].
+    pub fn peek_previous(&self) -> Option<&'a U> {
+        self.previous_node().map(|node| {
+            // SAFETY:
+            // As the node is in the list, its metadata must be properly initialised.
+            let ptr = unsafe { node.data_ptr() };
+            // SAFETY:
+            // As the node is in the list, its value must be properly initialised.
+            unsafe { ptr.as_ref() }
+        })
+    }
+
     #[must_use]
     #[inline]
     /// Returns a reference to the underlying list.
     pub const fn as_list(&self) -> &'a DynList<U, A> {
         self.list
     }
+
+    #[must_use]
+    /// Creates an iterator over the elements from the current one to the back of the list.
+    ///
+    /// The iterator stops at the back of the list rather than wrapping around.
+    /// If the cursor is on the "ghost" element, the iterator is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<u8>::from_iter([1, 2, 3, 4]);
+    /// let mut cursor = list.cursor_front();
+    /// cursor.move_next();
+    ///
+    /// assert!(cursor.clone().iter_from_here().eq(&[2, 3, 4]));
+    /// assert!(cursor.iter_rev_from_here().eq(&[2, 1]));
+    /// ```
+    pub const fn iter_from_here(self) -> Iter<'a, U> {
+        Iter::from_raw(RawIter::from_node_to_back(
+            self.list,
+            self.current,
+            self.index,
+        ))
+    }
+
+    /// Creates an iterator over the elements from the current one to the front of the list.
+    ///
+    /// The iterator stops at the front of the list rather than wrapping around.
+    /// If the cursor is on the "ghost" element, the iterator is empty.
+    pub fn iter_rev_from_here(self) -> Rev<Iter<'a, U>> {
+        Iter::from_raw(RawIter::from_front_to_node(
+            self.list,
+            self.current,
+            self.index,
+        ))
+        .rev()
+    }
 }
 
 // SAFETY:
@@ -119,6 +309,223 @@ where
     A: Allocator,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("Cursor").field(self).finish()
+        self.debug_with_name("Cursor", f)
+    }
+}
+
+impl<U, A> Cursor<'_, U, A>
+where
+    U: ?Sized + fmt::Debug,
+    A: Allocator,
+{
+    /// Formats the cursor's position for [`fmt::Debug`] with the given type name.
+    pub(crate) fn debug_with_name(&self, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.index(), self.current()) {
+            (Some(index), Some(current)) => f
+                .debug_struct(name)
+                .field("index", &index)
+                .field("current", &current)
+                .finish(),
+            _ => f.debug_tuple(name).field(&format_args!("ghost")).finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::DynList;
+
+    #[test]
+    fn move_n_matches_single_steps() {
+        let list: DynList<u8> = (0..4).collect();
+
+        for start in 0..=4 {
+            for n in 0..12 {
+                let mut stepped = list.cursor_at(start);
+                let mut walked = stepped.clone();
+                for _ in 0..n {
+                    stepped.move_next();
+                }
+                walked.move_next_n(n);
+                assert_eq!(walked.current(), stepped.current());
+                assert_eq!(walked.index(), stepped.index());
+
+                let mut stepped = list.cursor_at(start);
+                let mut walked = stepped.clone();
+                for _ in 0..n {
+                    stepped.move_previous();
+                }
+                walked.move_previous_n(n);
+                assert_eq!(walked.current(), stepped.current());
+                assert_eq!(walked.index(), stepped.index());
+            }
+        }
+    }
+
+    #[test]
+    fn peek() {
+        let mut list = DynList::<str>::new();
+        assert_eq!(list.cursor_front().peek_next(), None);
+        assert_eq!(list.cursor_front().peek_previous(), None);
+
+        list.push_back_copy_string("only");
+        let cursor = list.cursor_front();
+        assert_eq!(cursor.peek_next(), None);
+        assert_eq!(cursor.peek_previous(), None);
+
+        list.push_back_copy_string("back");
+        list.push_front_copy_string("front");
+
+        let mut cursor = list.cursor_front();
+        cursor.move_next();
+        assert_eq!(cursor.peek_next(), Some("back"));
+        assert_eq!(cursor.peek_previous(), Some("front"));
+        assert_eq!(cursor.current(), Some("only"));
+
+        // The "ghost" element sits between the back and the front
+        cursor.move_next_n(2);
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some("front"));
+        assert_eq!(cursor.peek_previous(), Some("back"));
+
+        let mut cursor = list.cursor_back_mut();
+        cursor.peek_previous().unwrap().make_ascii_uppercase();
+        assert_eq!(cursor.peek_next(), None);
+        assert!(list.iter().eq(["front", "ONLY", "back"]));
+    }
+
+    #[test]
+    fn cursor_at() {
+        let mut list = DynList::<usize>::new();
+        assert_eq!(list.cursor_at(0).current(), None);
+
+        list.extend(0..7);
+        for index in 0..7 {
+            let cursor = list.cursor_at(index);
+            assert_eq!(cursor.index(), Some(index));
+            assert_eq!(cursor.current(), Some(&index));
+        }
+        assert_eq!(list.cursor_at(7).current(), None);
+        assert_eq!(list.cursor_at(usize::MAX).current(), None);
+
+        let mut cursor = list.cursor_at_mut(5);
+        *cursor.current().unwrap() = 50;
+        cursor.move_next_n(2);
+        assert_eq!(cursor.current(), None);
+        assert!(list.iter().eq(&[0, 1, 2, 3, 4, 50, 6]));
+    }
+
+    #[test]
+    fn cursor_mut_at_node() {
+        let mut list = (0..7).collect::<DynList<usize>>();
+        let nodes = list.iter_nodes().collect::<Vec<_>>();
+
+        for (index, node) in nodes.into_iter().enumerate() {
+            // SAFETY:
+            // The node is in the list.
+            let mut cursor = unsafe { list.cursor_mut_at_node(node) };
+            assert_eq!(cursor.index(), Some(index));
+            assert_eq!(cursor.current(), Some(&mut index.clone()));
+        }
+
+        let node = list.iter_nodes().nth(2).unwrap();
+        assert_eq!(list.pop_front(), Some(0));
+        // SAFETY:
+        // The node is still in the list.
+        let mut cursor = unsafe { list.cursor_mut_at_node(node) };
+        assert_eq!(cursor.index(), Some(1));
+        cursor.move_previous();
+        assert_eq!(cursor.current(), Some(&mut 1));
+
+        // The index is found when needed while it is not known
+        let node = list.iter_nodes().nth(3).unwrap();
+        // SAFETY:
+        // The node is still in the list.
+        let mut cursor = unsafe { list.cursor_mut_at_node(node) };
+        cursor.insert_before(10);
+        cursor.insert_after(11);
+        assert_eq!(cursor.index(), Some(4));
+        assert!(cursor.swap_with_next());
+        assert_eq!(cursor.index(), Some(5));
+        cursor.move_previous_n(2);
+        assert_eq!(cursor.index(), Some(3));
+        assert_eq!(cursor.current(), Some(&mut 10));
+        let front = cursor.split_before();
+        assert!(front.iter().copied().eq(1..4));
+        assert_eq!(cursor.index(), Some(0));
+        assert!(list.iter().copied().eq([10, 11, 4, 5, 6]));
+        list.check_debug();
+
+        let node = list.iter_nodes().nth(2).unwrap();
+        // SAFETY:
+        // The node is still in the list.
+        let mut cursor = unsafe { list.cursor_mut_at_node(node) };
+        cursor.seek_to(4);
+        assert_eq!(cursor.current(), Some(&mut 6));
+        // SAFETY:
+        // The node is still in the list.
+        let mut cursor = unsafe { list.cursor_mut_at_node(node) };
+        let back = cursor.split_after();
+        assert!(back.iter().copied().eq([5, 6]));
+        cursor.move_next_n(2);
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&mut 10));
+    }
+
+    #[test]
+    fn iter_from_here() {
+        let mut list = DynList::<u8>::new();
+        assert_eq!(list.cursor_front().iter_from_here().next(), None);
+        assert_eq!(list.cursor_front().iter_rev_from_here().next(), None);
+
+        list.extend([1, 2, 3]);
+        let cursor = list.cursor_at(1);
+        let iter = cursor.clone().iter_from_here();
+        assert_eq!(iter.len(), 2);
+        assert!(iter.eq(&[2, 3]));
+        let iter = cursor.iter_rev_from_here();
+        assert_eq!(iter.len(), 2);
+        assert!(iter.eq(&[2, 1]));
+
+        // The iterators do not wrap through the "ghost" element
+        assert!(list.cursor_back().iter_from_here().eq(&[3]));
+        assert!(list.cursor_front().iter_rev_from_here().eq(&[1]));
+        assert_eq!(list.cursor_at(3).iter_from_here().next(), None);
+        assert_eq!(list.cursor_at(3).iter_rev_from_here().next(), None);
+
+        let mut cursor = list.cursor_at_mut(1);
+        cursor.insert_before(0);
+        for value in cursor.iter_mut_from_here() {
+            *value *= 10;
+        }
+        assert!(list.iter().eq(&[1, 0, 20, 30]));
+        for value in list.cursor_at_mut(1).iter_mut_rev_from_here() {
+            *value += 1;
+        }
+        assert!(list.iter().eq(&[2, 1, 20, 30]));
+    }
+
+    #[test]
+    fn debug() {
+        let mut list = DynList::<str>::new();
+        assert_eq!(format!("{:?}", list.cursor_front()), "Cursor(ghost)");
+
+        list.push_back_copy_string("a");
+        list.push_back_copy_string("b");
+        let mut cursor = list.cursor_back();
+        assert_eq!(
+            format!("{cursor:?}"),
+            r#"Cursor { index: 1, current: "b" }"#
+        );
+        cursor.move_next();
+        assert_eq!(format!("{cursor:?}"), "Cursor(ghost)");
+
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(
+            format!("{cursor:?}"),
+            r#"CursorMut { index: 0, current: "a" }"#
+        );
+        cursor.move_previous();
+        assert_eq!(format!("{cursor:?}"), "CursorMut(ghost)");
     }
 }
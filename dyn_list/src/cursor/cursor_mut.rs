@@ -3,11 +3,16 @@ use crate::alloc;
 use core::{
     alloc::Allocator,
     fmt,
+    iter::Rev,
     marker::Unsize,
+    mem,
     ptr::{self, Pointee},
 };
 
+use dynode::HeaderOpaqueNodePtr;
+
 use crate::{
+    iter::{IterMut, RawIter},
     node::{self, Header},
     AllocateError, DynList, Ends, MaybeUninitNode,
 };
@@ -27,9 +32,22 @@ pub struct CursorMut<
 {
     pub(crate) current: Option<Node<U>>,
     pub(crate) list: &'a mut DynList<U, A>,
+    // The index of the current node; this is meaningless on the "ghost" element
+    // This is `None` if the index is not known, such as for cursors created with
+    // `DynList::cursor_mut_at_node`, and it is found again by walking to the nearest end of the list
+    pub(crate) index: Option<usize>,
+    // Nodes allocated before the current node are inserted through the list without the cursor's
+    // knowledge, so this holds the list's length when the last one was allocated to check if it was
+    // inserted.
+    // This relies on the allocated node mutably borrowing the cursor: until it is inserted, dropped
+    // or converted with `into_raw`, the list cannot change in any other way, so afterwards the list
+    // is one longer if and only if the node was inserted. Every method that changes the list must
+    // call `sync_index` first, which applies the check and clears this, and `index` must only be
+    // read through `current_index` or after `sync_index`.
+    pub(crate) insert_before_len: Option<usize>,
 }
 
-impl<U, A> CursorMut<'_, U, A>
+impl<'a, U, A> CursorMut<'a, U, A>
 where
     U: ?Sized,
     A: Allocator,
@@ -37,24 +55,81 @@ where
     #[must_use]
     #[inline]
     /// Gets an immutable cursor over the list.
+    ///
+    /// The returned cursor borrows this cursor; see [`Self::into_cursor`] for one that borrows the list for as long as this cursor did.
     pub fn as_cursor(&self) -> Cursor<'_, U, A> {
         Cursor {
             current: self.current,
             list: self.list,
+            index: self.current_index(),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Converts the cursor into an immutable cursor at the same position.
+    ///
+    /// Unlike [`Self::as_cursor`], the returned cursor borrows the list for the whole of the original lifetime, so it can outlive this cursor.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::new();
+    /// list.extend([0, 2]);
+    ///
+    /// let cursor = {
+    ///     let mut cursor = list.cursor_back_mut();
+    ///     cursor.insert_before(1);
+    ///     cursor.into_cursor()
+    /// };
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// assert_eq!(cursor.index(), Some(2));
+    /// assert_eq!(list.front(), Some(&0));
+    /// ```
+    pub fn into_cursor(self) -> Cursor<'a, U, A> {
+        let index = self.current_index();
+        Cursor {
+            current: self.current,
+            list: self.list,
+            index,
         }
     }
 
+    /// Gets the node after the current one, or the front if the cursor is on the "ghost" element.
+    fn next_node(&self) -> Option<Node<U>> {
+        self.current.map_or_else(
+            || self.list.ends.map(|Ends { front, .. }| front),
+            |node| {
+                // SAFETY:
+                // As the node is in the list, it's header must be properly initialised.
+                unsafe { node.header_ptr().as_ref() }.next
+            },
+        )
+    }
+
+    /// Gets the node before the current one, or the back if the cursor is on the "ghost" element.
+    fn previous_node(&self) -> Option<Node<U>> {
+        self.current.map_or_else(
+            || self.list.ends.map(|Ends { back, .. }| back),
+            |node| {
+                // SAFETY:
+                // As the node is in the list, it's header must be properly initialised.
+                unsafe { node.header_ptr().as_ref() }.previous
+            },
+        )
+    }
+
     /// Moves the cursor to the next element.
     ///
     /// If the cursor is on the "ghost" element, this moves to the front of the list.
     /// If the cursor is at the back of the list, this moves to the "ghost" element.
     pub fn move_next(&mut self) {
-        self.current = match self.current {
-            None => self.list.ends.map(|Ends { front, .. }| front),
-            // SAFETY:
-            // As the node is in the list, it's header must be properly initialised.
-            Some(node) => unsafe { node.header_ptr().as_ref() }.next,
-        }
+        self.sync_index();
+        self.index = match self.current {
+            None => Some(0),
+            Some(_) => self.index.map(|index| index + 1),
+        };
+        self.current = self.next_node();
     }
 
     /// Moves the cursor to the previous element.
@@ -62,11 +137,138 @@ where
     /// If the cursor is on the "ghost" element, this moves to the back of the list.
     /// If the cursor is at the front of the list, this moves to the "ghost" element.
     pub fn move_previous(&mut self) {
-        self.current = match self.current {
-            None => self.list.ends.map(|Ends { back, .. }| back),
-            // SAFETY:
-            // As the node is in the list, it's header must be properly initialised.
-            Some(node) => unsafe { node.header_ptr().as_ref() }.previous,
+        self.sync_index();
+        self.index = match self.current {
+            None => Some(self.list.len.saturating_sub(1)),
+            Some(_) => self.index.map(|index| index.wrapping_sub(1)),
+        };
+        self.current = self.previous_node();
+    }
+
+    /// Moves the cursor forward `n` elements.
+    ///
+    /// This ends on the same element as calling [`Self::move_next`] `n` times, so the cursor passes through the "ghost" element between the back and the front, but walks the nodes in a single pass.
+    /// As the cursor is circular, an `n` larger than the length of the list wraps around, and the cursor moves backwards instead if that is shorter.
+    pub fn move_next_n(&mut self, n: usize) {
+        self.sync_index();
+        let cycle = self.list.len + 1;
+        let n = n % cycle;
+        if n <= cycle - n {
+            self.walk_next(n);
+        } else {
+            self.walk_previous(cycle - n);
+        }
+    }
+
+    /// Moves the cursor backward `n` elements.
+    ///
+    /// This ends on the same element as calling [`Self::move_previous`] `n` times, so the cursor passes through the "ghost" element between the front and the back, but walks the nodes in a single pass.
+    /// As the cursor is circular, an `n` larger than the length of the list wraps around, and the cursor moves forwards instead if that is shorter.
+    pub fn move_previous_n(&mut self, n: usize) {
+        self.sync_index();
+        let cycle = self.list.len + 1;
+        let n = n % cycle;
+        if n <= cycle - n {
+            self.walk_previous(n);
+        } else {
+            self.walk_next(cycle - n);
+        }
+    }
+
+    /// Moves the cursor forward `n` elements, where `n` is at most the length of the list.
+    fn walk_next(&mut self, n: usize) {
+        let (current, ghost_at) = super::walk_next(self.list, self.current, n);
+        self.current = current;
+        self.index = ghost_at.map_or_else(
+            || self.index.map(|index| index + n),
+            |step| {
+                // The front is one step after the "ghost" element
+                Some((n - step).checked_sub(1).unwrap_or(self.list.len))
+            },
+        );
+    }
+
+    /// Moves the cursor backward `n` elements, where `n` is at most the length of the list.
+    fn walk_previous(&mut self, n: usize) {
+        let (current, ghost_at) = super::walk_previous(self.list, self.current, n);
+        self.current = current;
+        self.index = ghost_at.map_or_else(
+            || self.index.map(|index| index - n),
+            |step| Some(self.list.len - (n - step)),
+        );
+    }
+
+    /// Moves the cursor to the element at `index`.
+    ///
+    /// This walks from the front, the back or the current element, whichever is nearest.
+    /// If `index` is out of bounds, the cursor moves to the "ghost" element.
+    pub fn seek_to(&mut self, index: usize) {
+        self.sync_index();
+        let len = self.list.len;
+        let Some(Ends { front, back }) = self.list.ends.filter(|_| index < len) else {
+            self.current = None;
+            return;
+        };
+
+        let from_back = len - 1 - index;
+        // If the current index is not known, walk from one of the ends instead
+        let from_current = self
+            .index
+            .filter(|_| self.current.is_some())
+            .map(|current| (current, current.abs_diff(index)))
+            .filter(|&(_, distance)| distance <= index && distance <= from_back);
+
+        if let Some((current, distance)) = from_current {
+            if index >= current {
+                self.move_next_n(distance);
+            } else {
+                self.move_previous_n(distance);
+            }
+        } else if index <= from_back {
+            self.current = Some(front);
+            self.index = Some(0);
+            self.move_next_n(index);
+        } else {
+            self.current = Some(back);
+            self.index = Some(len - 1);
+            self.move_previous_n(from_back);
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the index of the current element in the list.
+    ///
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
+    ///
+    /// For cursors created with [`DynList::cursor_mut_at_node`], the index is not known, so this walks from the current node to the nearest end of the list.
+    /// The index is known again once the cursor has passed through the "ghost" element or moved with [`Self::seek_to`].
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.current_index())
+    }
+
+    /// Gets the index of the current node, accounting for a node that may have been inserted before it.
+    ///
+    /// If the index is not known, this walks to the nearest end of the list to find it.
+    fn current_index(&self) -> usize {
+        let Some(index) = self.index else {
+            return self.current.map_or(self.list.len, |current| {
+                // SAFETY:
+                // The current node is in the list.
+                unsafe { self.list.node_index(current) }
+            });
+        };
+
+        match self.insert_before_len {
+            Some(len) if self.list.len > len => index + 1,
+            _ => index,
+        }
+    }
+
+    /// Updates the index to account for a node that may have been inserted before the current one.
+    fn sync_index(&mut self) {
+        if matches!(self.insert_before_len.take(), Some(len) if self.list.len > len) {
+            self.index = self.index.map(|index| index + 1);
         }
     }
 
@@ -85,6 +287,57 @@ where
         })
     }
 
+    #[must_use]
+    /// Gets a mutable reference to the next element without moving the cursor.
+    ///
+    /// If the cursor is on the "ghost" element, this returns the front of the list.
+    /// If the cursor is at the back of the list, or the list is empty, this returns [`None`].
+    pub fn peek_next(&mut self) -> Option<&mut U> {
+        self.next_node().map(|node| {
+            // SAFETY:
+            // As the node is in the list, its metadata must be properly initialised.
+            let mut ptr = unsafe { node.data_ptr() };
+            // SAFETY:
+            // As the node is in the list, its value must be properly initialised.
+            unsafe { ptr.as_mut() }
+        })
+    }
+
+    #[must_use]
+    /// Gets a mutable reference to the previous element without moving the cursor.
+    ///
+    /// If the cursor is on the "ghost" element, this returns the back of the list.
+    /// If the cursor is at the front of the list, or the list is empty, this returns [`None`].
+    pub fn peek_previous(&mut self) -> Option<&mut U> {
+        self.previous_node().map(|node| {
+            // SAFETY:
+            // As the node is in the list, its metadata must be properly initialised.
+            let mut ptr = unsafe { node.data_ptr() };
+            // SAFETY:
+            // As the node is in the list, its value must be properly initialised.
+            unsafe { ptr.as_mut() }
+        })
+    }
+
+    /// Swaps the current element with the next one.
+    ///
+    /// The nodes are relinked rather than having their values swapped, so this works for elements with different layouts and takes *O*(1).
+    /// The cursor stays on the same element, which moves one place towards the back.
+    ///
+    /// Returns [`false`] and does nothing if the cursor is on the "ghost" element or at the back of the list.
+    pub fn swap_with_next(&mut self) -> bool {
+        self.sync_index();
+        let (Some(current), Some(next)) = (self.current, self.next_node()) else {
+            return false;
+        };
+
+        // SAFETY:
+        // Both nodes are in the list and `current` is before `next`.
+        unsafe { self.list.swap_nodes(current, next) };
+        self.index = self.index.map(|index| index + 1);
+        true
+    }
+
     #[must_use]
     #[inline]
     /// Returns a reference to the underlying list.
@@ -92,6 +345,111 @@ where
         self.list
     }
 
+    /// Gets the header for a node inserted before the current node.
+    fn header_before(&self) -> Header<U> {
+        let (next, previous) = self.current.map_or_else(
+            || (None, self.list.ends.map(|Ends { back, .. }| back)),
+            |current| {
+                // SAFETY:
+                // As the node is in the list, it's header must be properly initialised.
+                let header = unsafe { current.header_ptr().as_ref() };
+                (Some(current), header.previous)
+            },
+        );
+        Header { next, previous }
+    }
+
+    /// Gets the header for a node inserted after the current node.
+    fn header_after(&self) -> Header<U> {
+        let (next, previous) = self.current.map_or_else(
+            || (self.list.ends.map(|Ends { front, .. }| front), None),
+            |current| {
+                // SAFETY:
+                // As the node is in the list, it's header must be properly initialised.
+                let header = unsafe { current.header_ptr().as_ref() };
+                (header.next, Some(current))
+            },
+        );
+        Header { next, previous }
+    }
+
+    /// Links a prepared node into the list with the given header.
+    ///
+    /// # Safety
+    /// See [`Self::insert_node_before`].
+    unsafe fn insert_node_with_header(&mut self, node: HeaderOpaqueNodePtr<U>, header: Header<U>) {
+        // SAFETY:
+        // All nodes for a `DynList<U, _>` have a `Header<U>` (safety condition).
+        let node = unsafe { node.to_transparent::<Header<U>>() };
+        // SAFETY:
+        // The node is not aliased (safety condition), so its header is valid for writes.
+        // The old header only contains pointers, so it does not need to be dropped.
+        unsafe { node.header_ptr().write(header) };
+        // SAFETY:
+        // The header has been set for this position in the list and the node is not aliased.
+        let node = unsafe { dynode::new_maybe_uninit(&mut *self.list, node.to_header_opaque()) };
+        // SAFETY:
+        // The value is initialised (safety condition).
+        unsafe { node.insert() };
+    }
+
+    /// Inserts a prepared node before the current node.
+    ///
+    /// If the cursor is on the "ghost" element, this inserts the node at the back of the list.
+    ///
+    /// This allows nodes to be allocated and initialised ahead of time, then inserted with [`MaybeUninitNode::into_raw`].
+    /// The node's header is rewritten for its new position, so unlike [`MaybeUninitNode::from_raw`] followed by [`MaybeUninitNode::insert`], the list may have changed since the node was allocated.
+    ///
+    /// # Safety
+    /// - `node` must have been returned by [`MaybeUninitNode::into_raw`] on a node for a `DynList<U, _>`
+    /// - `node` must have been allocated by an allocator compatible with this list's
+    /// - the node's value must be initialised
+    /// - the node must not have been deallocated and there must not be any other aliases to it
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    ///
+    /// let mut node = list.allocate_uninit_string_back(5);
+    /// node.copy_from_str("World");
+    /// let node = node.into_raw();
+    ///
+    /// list.push_back_copy_string("Hello");
+    /// // SAFETY: The node was allocated for this list and initialised above.
+    /// unsafe { list.cursor_front_mut().insert_node_after(node) };
+    /// assert!(list.iter().eq(["Hello", "World"]));
+    /// ```
+    pub unsafe fn insert_node_before(&mut self, node: HeaderOpaqueNodePtr<U>) {
+        self.sync_index();
+        let header = self.header_before();
+        // SAFETY:
+        // The safety conditions are the same.
+        unsafe { self.insert_node_with_header(node, header) };
+        if self.current.is_some() {
+            self.index = self.index.map(|index| index + 1);
+        }
+    }
+
+    /// Inserts a prepared node after the current node.
+    ///
+    /// If the cursor is on the "ghost" element, this inserts the node at the front of the list.
+    ///
+    /// See [`Self::insert_node_before`] for more information.
+    ///
+    /// # Safety
+    /// - `node` must have been returned by [`MaybeUninitNode::into_raw`] on a node for a `DynList<U, _>`
+    /// - `node` must have been allocated by an allocator compatible with this list's
+    /// - the node's value must be initialised
+    /// - the node must not have been deallocated and there must not be any other aliases to it
+    pub unsafe fn insert_node_after(&mut self, node: HeaderOpaqueNodePtr<U>) {
+        self.sync_index();
+        let header = self.header_after();
+        // SAFETY:
+        // The safety conditions are the same.
+        unsafe { self.insert_node_with_header(node, header) };
+    }
+
     /// Attempts to allocate an uninitialised node before the current node.
     ///
     /// If the cursor is on the "ghost" element, this will allocate the node at the back of the list.
@@ -105,17 +463,17 @@ where
         &mut self,
         metadata: <U as Pointee>::Metadata,
     ) -> Result<MaybeUninitNode<U, A>, AllocateError> {
-        let (next, previous) = self.current.map_or_else(
-            || (None, self.list.ends.map(|Ends { back, .. }| back)),
-            |current| {
-                // SAFETY:
-                // As the node is in the list, it's header must be properly initialised.
-                let header = unsafe { current.header_ptr().as_ref() };
-                (Some(current), header.previous)
-            },
-        );
+        self.sync_index();
+        if self.current.is_some() {
+            self.insert_before_len = Some(self.list.len);
+        }
 
-        unsafe { node::try_new(&mut *self.list, metadata, Header { next, previous }) }
+        let header = self.header_before();
+        // SAFETY:
+        // The metadata is valid (safety condition) and the header's nodes are in-order elements
+        // from the list.
+        unsafe { node::try_new(&mut *self.list, metadata, header) }
+            .map_err(|error| error.with_context("allocate_uninit_before"))
     }
 
     /// Attempts to allocate an uninitialised node after the current node.
@@ -131,15 +489,14 @@ where
         &mut self,
         metadata: <U as Pointee>::Metadata,
     ) -> Result<MaybeUninitNode<U, A>, AllocateError> {
-        let (next, previous) = self.current.map_or_else(
-            || (self.list.ends.map(|Ends { front, .. }| front), None),
-            |current| {
-                let header = unsafe { current.header_ptr().as_ref() };
-                (header.next, Some(current))
-            },
-        );
+        self.sync_index();
 
-        unsafe { node::try_new(&mut *self.list, metadata, Header { next, previous }) }
+        let header = self.header_after();
+        // SAFETY:
+        // The metadata is valid (safety condition) and the header's nodes are in-order elements
+        // from the list.
+        unsafe { node::try_new(&mut *self.list, metadata, header) }
+            .map_err(|error| error.with_context("allocate_uninit_after"))
     }
 
     /// Allocates an uninitialised node before the current node.
@@ -179,11 +536,18 @@ where
         T: Unsize<U>,
     {
         let metadata = ptr::metadata(&value as &U);
+        // SAFETY:
+        // The metadata is from a valid reference to `U`.
         let node = match unsafe { self.try_allocate_uninit_before(metadata) } {
             Ok(node) => node,
-            Err(error) => return Err(error.with_value(value)),
+            Err(error) => return Err(error.with_context("insert_before_unsize").with_value(value)),
         };
+        // SAFETY:
+        // The node was allocated with the metadata of `value` as `U`, so its value pointer is valid
+        // for writes of `T`.
         unsafe { node.value_ptr().cast().write(value) };
+        // SAFETY:
+        // The value was initialised above.
         unsafe { node.insert() };
         Ok(())
     }
@@ -197,11 +561,18 @@ where
         T: Unsize<U>,
     {
         let metadata = ptr::metadata(&value as &U);
-        let node = match unsafe { self.try_allocate_uninit_before(metadata) } {
+        // SAFETY:
+        // The metadata is from a valid reference to `U`.
+        let node = match unsafe { self.try_allocate_uninit_after(metadata) } {
             Ok(node) => node,
-            Err(error) => return Err(error.with_value(value)),
+            Err(error) => return Err(error.with_context("insert_after_unsize").with_value(value)),
         };
+        // SAFETY:
+        // The node was allocated with the metadata of `value` as `U`, so its value pointer is valid
+        // for writes of `T`.
         unsafe { node.value_ptr().cast().write(value) };
+        // SAFETY:
+        // The value was initialised above.
         unsafe { node.insert() };
         Ok(())
     }
@@ -228,11 +599,53 @@ where
         unsafe { node.insert() };
     }
 
-    #[must_use]
-    /// Removes the current node and moves to the previous.
+    /// Attempts to replace the current element with `value`, unsizing it to `U`.
     ///
-    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
-    pub fn remove_current_node(&mut self) -> Option<MaybeUninitNode<U, A>> {
+    /// As the layout of `value` may differ from the current element's, this allocates a new node, links it in place of the current one and then drops the old node.
+    /// The cursor points to the new node afterwards.
+    ///
+    /// Returns [`false`] and drops `value` if the cursor is on the "ghost" element.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`] containing `value`.
+    /// The current element is left in place.
+    pub fn try_replace_current_unsize<T>(&mut self, value: T) -> Result<bool, AllocateError<T>>
+    where
+        T: Unsize<U>,
+    {
+        if self.current.is_none() {
+            return Ok(false);
+        }
+
+        self.try_insert_after_unsize(value)
+            .map_err(|error| error.with_context("replace_current_unsize"))?;
+        let deleted = self.delete_current();
+        debug_assert!(deleted);
+        // Removing the node moves the cursor back, so move it on to the new node
+        self.move_next();
+        Ok(true)
+    }
+
+    #[inline]
+    /// Replaces the current element with `value`, unsizing it to `U`.
+    ///
+    /// As the layout of `value` may differ from the current element's, this allocates a new node, links it in place of the current one and then drops the old node.
+    /// The cursor points to the new node afterwards.
+    ///
+    /// Returns [`false`] and drops `value` if the cursor is on the "ghost" element.
+    pub fn replace_current_unsize<T>(&mut self, value: T) -> bool
+    where
+        T: Unsize<U>,
+    {
+        AllocateError::unwrap_result(self.try_replace_current_unsize(value))
+    }
+
+    /// Unlinks the current node from the list and returns it.
+    ///
+    /// The node's header still points to its old neighbours.
+    /// The cursor still points to the unlinked node, so it must be moved before it is used again.
+    fn unlink_current(&mut self) -> Option<Node<U>> {
+        self.sync_index();
         let node = self.current?;
         let header = unsafe { node.header_ptr().as_ref() };
 
@@ -252,7 +665,6 @@ where
             debug_assert_eq!(previous_header.next, Some(node));
             previous_header.next = header.next;
         }
-        self.current = header.previous;
 
         match (header.next, header.previous) {
             (Some(_next), Some(_previous)) => {}
@@ -270,12 +682,93 @@ where
                 self.list.ends = None;
             }
         }
+        self.list.len -= 1;
 
+        Some(node)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Removes the current node and moves to the previous element.
+    ///
+    /// All of the cursor's removal methods, such as [`Self::delete_current`], move to the previous element.
+    /// When removing while iterating forwards, call [`Self::move_next`] after each removal, or use [`Self::remove_current_and_move_next`], so that no elements are skipped.
+    ///
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
+    pub fn remove_current_node(&mut self) -> Option<MaybeUninitNode<U, A>> {
+        self.remove_current_and_move_previous()
+    }
+
+    #[must_use]
+    /// Removes the current node and moves to the previous element.
+    ///
+    /// If the current node was at the front, the cursor moves to the "ghost" element.
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`] and does not move.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::from_iter(0..6);
+    /// let mut cursor = list.cursor_back_mut();
+    /// while cursor.index().is_some() {
+    ///     if *cursor.current().unwrap() % 2 == 1 {
+    ///         // SAFETY: The node's value is initialised, as it was in the list.
+    ///         unsafe { cursor.remove_current_and_move_previous().unwrap().drop_in_place() };
+    ///     } else {
+    ///         cursor.move_previous();
+    ///     }
+    /// }
+    ///
+    /// assert!(list.iter().eq(&[0, 2, 4]));
+    /// ```
+    pub fn remove_current_and_move_previous(&mut self) -> Option<MaybeUninitNode<U, A>> {
+        let node = self.unlink_current()?;
+        // SAFETY:
+        // The node was in the list, so its header is initialised, and it is no longer aliased.
+        self.current = unsafe { node.header_ptr().as_ref() }.previous;
+        self.index = self.index.map(|index| index.wrapping_sub(1));
+
+        // SAFETY:
+        // The node has been unlinked from the list and its header is initialised.
+        Some(unsafe { dynode::new_maybe_uninit(&mut *self.list, node.into()) })
+    }
+
+    #[must_use]
+    /// Removes the current node and moves to the next element.
+    ///
+    /// The next element takes the removed node's index.
+    /// If the current node was at the back, the cursor moves to the "ghost" element.
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`] and does not move.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::from_iter(0..6);
+    /// let mut cursor = list.cursor_front_mut();
+    /// while cursor.index().is_some() {
+    ///     if *cursor.current().unwrap() % 2 == 1 {
+    ///         // SAFETY: The node's value is initialised, as it was in the list.
+    ///         unsafe { cursor.remove_current_and_move_next().unwrap().drop_in_place() };
+    ///     } else {
+    ///         cursor.move_next();
+    ///     }
+    /// }
+    ///
+    /// assert!(list.iter().eq(&[0, 2, 4]));
+    /// ```
+    pub fn remove_current_and_move_next(&mut self) -> Option<MaybeUninitNode<U, A>> {
+        let node = self.unlink_current()?;
+        // SAFETY:
+        // The node was in the list, so its header is initialised, and it is no longer aliased.
+        self.current = unsafe { node.header_ptr().as_ref() }.next;
+
+        // SAFETY:
+        // The node has been unlinked from the list and its header is initialised.
         Some(unsafe { dynode::new_maybe_uninit(&mut *self.list, node.into()) })
     }
 
     #[inline]
-    /// Deletes and drops the current node.
+    /// Deletes and drops the current node and moves to the previous element.
     ///
     /// Returns [`true`] if a node was removed and [`false`] if current element is the "ghost".
     pub fn delete_current(&mut self) -> bool {
@@ -284,6 +777,43 @@ where
             .is_some()
     }
 
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    /// Attempts to remove the current node and return its value in a [`Box`] in the given allocator.
+    ///
+    /// On success, the cursor moves to the previous element.
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
+    /// See [`DynList::try_pop_front_boxed_in`] for more information.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    /// The node will not be removed and the cursor will still point to it.
+    pub fn try_remove_current_boxed_in<A2>(
+        &mut self,
+        allocator: A2,
+    ) -> Option<Result<alloc::Box<U, A2>, AllocateError>>
+    where
+        A2: Allocator,
+    {
+        let node = self.remove_current_node()?;
+        // SAFETY:
+        // As the node was in the list, its value is initialised.
+        let error = match unsafe { node.try_take_boxed_in(allocator) } {
+            Ok(boxed) => return Some(Ok(boxed)),
+            Err(error) => error,
+        };
+
+        // On failure, the value has not been moved out of the node
+        let (node, error) = error.into_parts();
+        // SAFETY:
+        // The node's value is still initialised and its header still points to its old neighbours,
+        // so this puts it back where it was.
+        unsafe { node.insert() };
+        // Removing the node moved the cursor to the previous element
+        self.move_next();
+        Some(Err(error.with_context("remove_current_boxed")))
+    }
+
     #[cfg(feature = "alloc")]
     #[must_use]
     #[inline]
@@ -293,18 +823,13 @@ where
     ///
     /// # Errors
     /// If allocation fails, this will return an [`AllocateError`].
-    /// The node will not be removed.
+    /// The node will not be removed and the cursor will still point to it.
     pub fn try_remove_current_boxed(&mut self) -> Option<Result<alloc::Box<U, A>, AllocateError>>
     where
         A: Clone,
     {
-        self.remove_current_node().map(|node| {
-            unsafe { node.try_take_boxed() }.map_err(|error| {
-                let (node, error) = error.into_parts();
-                unsafe { node.insert() };
-                error
-            })
-        })
+        let allocator = self.list.allocator.clone();
+        self.try_remove_current_boxed_in(allocator)
     }
 
     #[cfg(feature = "alloc")]
@@ -320,6 +845,196 @@ where
         self.try_remove_current_boxed()
             .map(AllocateError::unwrap_result)
     }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    /// Removes the current node and returns its value in a [`Box`] in the given allocator.
+    ///
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
+    pub fn remove_current_boxed_in<A2>(&mut self, allocator: A2) -> Option<alloc::Box<U, A2>>
+    where
+        A2: Allocator,
+    {
+        self.try_remove_current_boxed_in(allocator)
+            .map(AllocateError::unwrap_result)
+    }
+
+    /// Splits the list after the current node, returning the nodes after it.
+    ///
+    /// The current node and those before it stay in the list.
+    /// If the cursor is pointing to the "ghost" element, the whole list is returned.
+    /// The cursor still points to the same element afterwards.
+    #[must_use]
+    pub fn split_after(&mut self) -> DynList<U, A>
+    where
+        A: Clone,
+    {
+        self.sync_index();
+        let Some(current) = self.current else {
+            let allocator = self.list.allocator.clone();
+            return mem::replace(self.list, DynList::new_in(allocator));
+        };
+
+        let len = self.current_index() + 1;
+        // SAFETY:
+        // The current node is in the list and there are `len` nodes up to and including it.
+        unsafe { self.list.split_after_node(current, len) }
+    }
+
+    /// Splits the list before the current node, returning the nodes before it.
+    ///
+    /// The current node and those after it stay in the list.
+    /// If the cursor is pointing to the "ghost" element, the whole list is returned.
+    /// The cursor still points to the same element afterwards.
+    #[must_use]
+    pub fn split_before(&mut self) -> DynList<U, A>
+    where
+        A: Clone,
+    {
+        self.sync_index();
+        let Some(current) = self.current else {
+            let allocator = self.list.allocator.clone();
+            return mem::replace(self.list, DynList::new_in(allocator));
+        };
+
+        // SAFETY:
+        // As the node is in the list, its header must be properly initialised.
+        let Some(previous) = (unsafe { current.header_ptr().as_ref() }).previous else {
+            let allocator = self.list.allocator.clone();
+            return DynList::new_in(allocator);
+        };
+
+        let len = self.current_index();
+        self.index = Some(0);
+        // SAFETY:
+        // The previous node is in the list and there are `len` nodes up to and including it.
+        let mut tail = unsafe { self.list.split_after_node(previous, len) };
+        // The list holds the nodes before the current one, so swap it with the tail
+        mem::swap(self.list, &mut tail);
+        tail
+    }
+
+    /// Consumes the cursor and splits the list at the current node, returning both halves.
+    ///
+    /// The first list holds the nodes before the current one, and the second holds the current node and those after it.
+    /// If the cursor is pointing to the "ghost" element, the first list holds all of the nodes and the second is empty.
+    /// The original list is left empty, and each half has a clone of its allocator.
+    /// No allocation or copying takes place, so this is *O*(1).
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::from_iter([1, 2, 3, 4]);
+    /// let (front, back) = list.cursor_at_mut(1).split();
+    ///
+    /// assert!(front.iter().eq(&[1]));
+    /// assert!(back.iter().eq(&[2, 3, 4]));
+    /// assert!(list.is_empty());
+    /// ```
+    #[must_use]
+    pub fn split(mut self) -> (DynList<U, A>, DynList<U, A>)
+    where
+        A: Clone,
+    {
+        let front = self.split_before();
+        let back = self.list.replace_with_empty();
+        (front, back)
+    }
+
+    /// Moves all nodes from `other` into the list after the current node.
+    ///
+    /// If the cursor is on the "ghost" element, the nodes are appended to the back of the list.
+    /// No allocation or copying takes place, so this is *O*(1).
+    /// The cursor still points to the same element afterwards.
+    ///
+    /// As with [`DynList::append`], the nodes from `other` are deallocated with the list's allocator, as lists with the same allocator type are assumed to be able to deallocate each other's nodes.
+    pub fn splice_after(&mut self, other: DynList<U, A>) {
+        self.sync_index();
+        let Some((ends, len)) = other.take_ends() else {
+            return;
+        };
+
+        let (previous, next) = self.current.map_or_else(
+            || (self.list.ends.map(|Ends { back, .. }| back), None),
+            |current| {
+                // SAFETY:
+                // As the node is in the list, its header must be properly initialised.
+                let header = unsafe { current.header_ptr().as_ref() };
+                (Some(current), header.next)
+            },
+        );
+
+        // SAFETY:
+        // `previous` and `next` are adjacent in the list and `ends` are from another list.
+        unsafe { self.list.splice_between(previous, next, ends, len) };
+    }
+
+    /// Moves all nodes from `other` into the list before the current node.
+    ///
+    /// If the cursor is on the "ghost" element, the nodes are prepended to the front of the list.
+    /// No allocation or copying takes place, so this is *O*(1).
+    /// The cursor still points to the same element afterwards.
+    ///
+    /// As with [`DynList::append`], the nodes from `other` are deallocated with the list's allocator, as lists with the same allocator type are assumed to be able to deallocate each other's nodes.
+    pub fn splice_before(&mut self, other: DynList<U, A>) {
+        self.sync_index();
+        let Some((ends, len)) = other.take_ends() else {
+            return;
+        };
+
+        let (previous, next) = self.current.map_or_else(
+            || (None, self.list.ends.map(|Ends { front, .. }| front)),
+            |current| {
+                // SAFETY:
+                // As the node is in the list, its header must be properly initialised.
+                let header = unsafe { current.header_ptr().as_ref() };
+                (header.previous, Some(current))
+            },
+        );
+
+        // SAFETY:
+        // `previous` and `next` are adjacent in the list and `ends` are from another list.
+        unsafe { self.list.splice_between(previous, next, ends, len) };
+        if self.current.is_some() {
+            self.index = self.index.map(|index| index + len);
+        }
+    }
+}
+
+impl<'a, U, A> CursorMut<'a, U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    #[must_use]
+    /// Creates an iterator over mutable references to the elements from the current one to the back of the list.
+    ///
+    /// The iterator stops at the back of the list rather than wrapping around.
+    /// If the cursor is on the "ghost" element, the iterator is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::from_iter([1, 2, 3]);
+    /// for value in list.cursor_at_mut(1).iter_mut_from_here() {
+    ///     *value *= 10;
+    /// }
+    /// assert!(list.iter().eq(&[1, 20, 30]));
+    /// ```
+    pub fn iter_mut_from_here(self) -> IterMut<'a, U> {
+        let index = self.current_index();
+        IterMut::from_raw(RawIter::from_node_to_back(self.list, self.current, index))
+    }
+
+    /// Creates an iterator over mutable references to the elements from the current one to the front of the list.
+    ///
+    /// The iterator stops at the front of the list rather than wrapping around.
+    /// If the cursor is on the "ghost" element, the iterator is empty.
+    pub fn iter_mut_rev_from_here(self) -> Rev<IterMut<'a, U>> {
+        let index = self.current_index();
+        IterMut::from_raw(RawIter::from_front_to_node(self.list, self.current, index)).rev()
+    }
 }
 
 unsafe impl<U, A> Send for CursorMut<'_, U, A>
@@ -341,6 +1056,406 @@ where
     A: Allocator,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("CursorMut").field(self).finish()
+        self.as_cursor().debug_with_name("CursorMut", f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt::Debug;
+
+    use crate::DynList;
+
+    #[test]
+    fn try_insert_after_unsize() {
+        let mut list = DynList::<dyn Debug>::new();
+        list.push_back_unsize(0_u8);
+        list.push_back_unsize(1_u8);
+        list.push_back_unsize(3_u8);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert!(cursor.try_insert_after_unsize(2_u8).is_ok());
+
+        let mut cursor = cursor.as_cursor();
+        assert_eq!(format!("{:?}", cursor.current()), "Some(1)");
+        cursor.move_next();
+        assert_eq!(format!("{:?}", cursor.current()), "Some(2)");
+        cursor.move_next();
+        assert_eq!(format!("{:?}", cursor.current()), "Some(3)");
+
+        assert_eq!(format!("{list:?}"), "[0, 1, 2, 3]");
+    }
+
+    #[test]
+    fn remove_middle_keeps_ends() {
+        let mut list = DynList::<u8>::new();
+        list.extend([0, 1, 2]);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.current(), Some(&mut 0));
+
+        assert_eq!(list.front(), Some(&0));
+        assert_eq!(list.back(), Some(&2));
+        list.check_debug();
+    }
+
+    #[test]
+    fn remove_every_other() {
+        let mut list = DynList::<usize>::new();
+        list.extend(0..9);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        while let Some(value) = cursor.remove_current_and_move_next().map(|node| {
+            // SAFETY:
+            // The node's value is initialised, as it was in the list.
+            unsafe { node.take() }
+        }) {
+            assert_eq!(value % 2, 1);
+            // The next element takes the removed one's index
+            assert_eq!(cursor.index(), Some(value / 2 + 1));
+            assert_eq!(cursor.current(), Some(&mut (value + 1)));
+            cursor.move_next();
+        }
+        list.check_debug();
+        assert!(list.iter().eq(&[0, 2, 4, 6, 8]));
+
+        let mut cursor = list.cursor_back_mut();
+        while let Some(value) = cursor.remove_current_and_move_previous().map(|node| {
+            // SAFETY:
+            // As above.
+            unsafe { node.take() }
+        }) {
+            assert_eq!(value % 4, 0);
+            // Removing the front moves to the "ghost" element, so stop before wrapping around
+            if cursor.index().is_none() {
+                break;
+            }
+            cursor.move_previous();
+        }
+        list.check_debug();
+        assert!(list.iter().eq(&[2, 6]));
+
+        // The implicit direction is backwards
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.remove_current(), Some(6));
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert!(cursor.remove_current_and_move_next().is_some());
+        assert_eq!(cursor.index(), None);
+        assert!(cursor.remove_current_and_move_next().is_none());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn split() {
+        let mut list = DynList::<u8>::new();
+        list.extend(0..6);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        let tail = cursor.split_after();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        let head = cursor.split_before();
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        assert!(head.iter().copied().eq(0..2));
+        assert!(tail.iter().copied().eq(3..6));
+        head.check_debug();
+        tail.check_debug();
+        list.check_debug();
+        assert!(list.iter().copied().eq(2..3));
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_previous();
+        let whole = cursor.split_before();
+        assert!(cursor.current().is_none());
+        assert!(list.is_empty());
+        assert_eq!(whole.len(), 1);
+    }
+
+    #[test]
+    fn split_consuming() {
+        let mut list = DynList::<usize>::new();
+        let (front, back) = list.cursor_front_mut().split();
+        assert!(front.is_empty() && back.is_empty());
+
+        list.extend(0..4);
+        for index in 0..=4 {
+            let (front, back) = list.cursor_at_mut(index).split();
+            front.check_debug();
+            back.check_debug();
+            assert!(list.is_empty());
+            assert!(front.iter().copied().eq(0..index));
+            assert!(back.iter().copied().eq(index..4));
+
+            let (mut front, mut back) = (front, back);
+            front.append(&mut back);
+            list = front;
+        }
+    }
+
+    #[test]
+    fn splice() {
+        let mut list = DynList::<u8>::new();
+        list.extend([2, 5]);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.splice_after([3, 4].into_iter().collect());
+        cursor.splice_before(DynList::new());
+        assert_eq!(cursor.current(), Some(&mut 2));
+        // On the "ghost" element, `splice_after` appends and `splice_before` prepends
+        cursor.move_previous();
+        cursor.splice_after([6, 7].into_iter().collect());
+        cursor.splice_before([0, 1].into_iter().collect());
+        assert_eq!(cursor.current(), None);
+
+        list.check_debug();
+        assert_eq!(list.len(), 8);
+        assert!(list.iter().copied().eq(0..8));
+    }
+
+    #[test]
+    fn index() {
+        let mut list = DynList::<u8>::new();
+        list.extend([1, 3]);
+
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.insert_before(2);
+        assert_eq!(cursor.index(), Some(2));
+        cursor.insert_after(4);
+        assert_eq!(cursor.index(), Some(2));
+
+        // Dropping a node allocated before the current one does not change the index
+        drop(cursor.allocate_uninit_sized_before());
+        assert_eq!(cursor.index(), Some(2));
+
+        cursor.splice_before(DynList::from([0]));
+        assert_eq!(cursor.index(), Some(3));
+        assert_eq!(cursor.current(), Some(&mut 3));
+
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 0));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        cursor.move_previous();
+        assert_eq!(cursor.index(), Some(3));
+        assert_eq!(cursor.as_cursor().index(), Some(3));
+
+        let tail = cursor.split_before();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(tail.len(), 3);
+    }
+
+    #[test]
+    fn seek() {
+        let mut list = DynList::<u8>::new();
+        list.extend(0..5);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next_n(3);
+        assert_eq!(cursor.current(), Some(&mut 3));
+        // Five elements and the "ghost" make a cycle of six
+        cursor.move_next_n(7);
+        assert_eq!(cursor.current(), Some(&mut 4));
+        cursor.move_next_n(1);
+        assert_eq!(cursor.current(), None);
+        cursor.move_previous_n(2);
+        assert_eq!(cursor.current(), Some(&mut 3));
+        assert_eq!(cursor.index(), Some(3));
+
+        for index in [4, 0, 2, 1, 3] {
+            cursor.seek_to(index);
+            assert_eq!(cursor.index(), Some(index));
+            assert_eq!(
+                cursor.current().map(|value| usize::from(*value)),
+                Some(index)
+            );
+        }
+        cursor.seek_to(5);
+        assert_eq!(cursor.index(), None);
+
+        let mut cursor = list.cursor_back();
+        cursor.move_previous_n(4);
+        assert_eq!(cursor.current(), Some(&0));
+        cursor.seek_to(3);
+        assert_eq!(cursor.current(), Some(&3));
+    }
+
+    #[test]
+    fn index_with_allocated_nodes() {
+        let mut list: DynList<u8> = [0, 2, 4].into_iter().collect();
+        let mut cursor = list.cursor_at_mut(1);
+
+        // Dropping an uninitialised node allocated before leaves the list, and the index, unchanged
+        drop(cursor.allocate_uninit_sized_before());
+        assert_eq!(cursor.index(), Some(1));
+        cursor.allocate_uninit_sized_after().write(3).insert();
+        assert_eq!(cursor.index(), Some(1));
+
+        cursor.allocate_uninit_sized_before().write(1).insert();
+        assert_eq!(cursor.index(), Some(2));
+        drop(cursor.allocate_uninit_sized_before());
+        cursor.allocate_uninit_sized_after().write(2).insert();
+        assert_eq!(cursor.index(), Some(2));
+
+        // A node allocated before and inserted elsewhere does not count as inserted before
+        let mut node = cursor.allocate_uninit_sized_before();
+        node.value_mut().write(5);
+        let node = node.into_raw();
+        // SAFETY:
+        // The node was allocated for this list and initialised above.
+        unsafe { cursor.insert_node_after(node) };
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        list.check_debug();
+        assert!(list.iter().eq(&[0, 1, 2, 5, 2, 3, 4]));
+    }
+
+    #[test]
+    fn move_n_matches_single_steps() {
+        let mut stepped_list: DynList<u8> = (0..4).collect();
+        let mut walked_list = stepped_list.clone();
+
+        for start in 0..=4 {
+            for n in 0..12 {
+                let mut stepped = stepped_list.cursor_at_mut(start);
+                let mut walked = walked_list.cursor_at_mut(start);
+                for _ in 0..n {
+                    stepped.move_next();
+                }
+                walked.move_next_n(n);
+                assert_eq!(walked.current(), stepped.current());
+                assert_eq!(walked.index(), stepped.index());
+
+                let mut stepped = stepped_list.cursor_at_mut(start);
+                let mut walked = walked_list.cursor_at_mut(start);
+                for _ in 0..n {
+                    stepped.move_previous();
+                }
+                walked.move_previous_n(n);
+                assert_eq!(walked.current(), stepped.current());
+                assert_eq!(walked.index(), stepped.index());
+            }
+        }
+    }
+
+    #[test]
+    fn replace_current() {
+        let mut list = DynList::<u8>::new();
+        list.extend([0, 1]);
+
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.replace_current(2), Some(1));
+        cursor.move_next();
+        assert_eq!(cursor.replace_current(3), None);
+        assert!(list.iter().copied().eq([0, 2]));
+    }
+
+    #[test]
+    fn replace_current_unsize() {
+        let mut list = DynList::<dyn Debug>::new();
+        list.push_back_unsize(0_u8);
+        list.push_back_unsize("one");
+
+        let mut cursor = list.cursor_front_mut();
+        assert!(cursor.replace_current_unsize([0_u64; 4]));
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_next();
+        assert!(cursor.replace_current_unsize(1_i8));
+        assert_eq!(format!("{:?}", cursor.current()), "Some(1)");
+        cursor.move_next();
+        assert!(!cursor.replace_current_unsize(2_u8));
+
+        list.check_debug();
+        assert_eq!(list.len(), 2);
+        assert_eq!(format!("{list:?}"), "[[0, 0, 0, 0], 1]");
+        assert_eq!(format!("{:?}", list.back()), "Some(1)");
+    }
+
+    #[test]
+    fn swap_with_next() {
+        let mut list = DynList::<[u8]>::new();
+        list.push_back_copy_array(&[0]);
+        list.push_back_copy_array(&[1, 1]);
+        list.push_back_copy_array(&[2, 2, 2]);
+
+        let mut cursor = list.cursor_front_mut();
+        assert!(cursor.swap_with_next());
+        assert_eq!(cursor.index(), Some(1));
+        assert!(cursor.swap_with_next());
+        assert_eq!(cursor.current().map(|slice| slice.len()), Some(1));
+        assert!(!cursor.swap_with_next());
+        cursor.move_next();
+        assert!(!cursor.swap_with_next());
+
+        list.check_debug();
+        assert!(list.iter().eq([&[1, 1][..], &[2, 2, 2], &[0]]));
+    }
+
+    #[test]
+    fn insert_node() {
+        let mut other = DynList::<str>::new();
+        let mut node = other.allocate_uninit_string_front(1);
+        node.copy_from_str("c");
+        let from_other = node.into_raw();
+        drop(other);
+
+        let mut list = DynList::<str>::new();
+        let mut node = list.allocate_uninit_string_back(1);
+        node.copy_from_str("a");
+        let prepared = node.into_raw();
+
+        list.push_back_copy_string("b");
+        list.push_back_copy_string("d");
+
+        let mut cursor = list.cursor_back_mut();
+        // SAFETY:
+        // Both nodes were allocated for a `DynList<str>` in the global allocator and initialised.
+        unsafe { cursor.insert_node_before(from_other) };
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_next();
+        // SAFETY:
+        // As above.
+        unsafe { cursor.insert_node_after(prepared) };
+        assert_eq!(cursor.index(), None);
+
+        list.check_debug();
+        assert!(list.iter().eq(["a", "b", "c", "d"]));
+    }
+
+    #[test]
+    fn into_cursor() {
+        let mut list = DynList::<u8>::new();
+        list.extend([0, 3]);
+
+        let mut cursor = list.cursor_back_mut();
+        cursor.insert_before(1);
+        let node = cursor.allocate_uninit_sized_before();
+        node.write(2).insert();
+        let mut cursor = cursor.into_cursor();
+
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.index(), Some(3));
+        cursor.move_previous();
+        assert_eq!(cursor.current(), Some(&2));
+        assert!(list.iter().eq(&[0, 1, 2, 3]));
+
+        let cursor = list.cursor_front_mut().into_cursor();
+        assert_eq!(cursor.index(), Some(0));
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_next();
+        assert_eq!(cursor.into_cursor().index(), None);
     }
 }
@@ -8,3 +8,65 @@ mod string;
 
 pub use cursor::Cursor;
 pub use cursor_mut::CursorMut;
+
+use core::alloc::Allocator;
+
+use crate::{node::Node, DynList, Ends};
+
+/// Walks `n` steps towards the back of the list from `current`, wrapping through the "ghost" element.
+///
+/// Returns the node reached and, if the walk started on or passed through the "ghost" element, the last step it was there, where the start is step `0`.
+fn walk_next<U, A>(
+    list: &DynList<U, A>,
+    mut current: Option<Node<U>>,
+    n: usize,
+) -> (Option<Node<U>>, Option<usize>)
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    let mut ghost_at = current.is_none().then_some(0);
+    for step in 1..=n {
+        current = current.map_or_else(
+            || list.ends.map(|Ends { front, .. }| front),
+            |node| {
+                // SAFETY:
+                // As the node is in the list, its header must be properly initialised.
+                unsafe { node.header_ptr().as_ref() }.next
+            },
+        );
+        if current.is_none() {
+            ghost_at = Some(step);
+        }
+    }
+    (current, ghost_at)
+}
+
+/// Walks `n` steps towards the front of the list from `current`, wrapping through the "ghost" element.
+///
+/// Returns the same as [`walk_next`].
+fn walk_previous<U, A>(
+    list: &DynList<U, A>,
+    mut current: Option<Node<U>>,
+    n: usize,
+) -> (Option<Node<U>>, Option<usize>)
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    let mut ghost_at = current.is_none().then_some(0);
+    for step in 1..=n {
+        current = current.map_or_else(
+            || list.ends.map(|Ends { back, .. }| back),
+            |node| {
+                // SAFETY:
+                // As the node is in the list, its header must be properly initialised.
+                unsafe { node.header_ptr().as_ref() }.previous
+            },
+        );
+        if current.is_none() {
+            ghost_at = Some(step);
+        }
+    }
+    (current, ghost_at)
+}
@@ -1,4 +1,4 @@
-use core::alloc::Allocator;
+use core::{alloc::Allocator, mem};
 
 use dynode::AllocateError;
 
@@ -21,6 +21,7 @@ where
         &mut self,
     ) -> Result<MaybeUninitNode<T, A>, AllocateError> {
         unsafe { self.try_allocate_uninit_before(()) }
+            .map_err(|error| error.with_context("allocate_uninit_sized_before"))
     }
 
     #[inline]
@@ -34,6 +35,7 @@ where
         &mut self,
     ) -> Result<MaybeUninitNode<T, A>, AllocateError> {
         unsafe { self.try_allocate_uninit_after(()) }
+            .map_err(|error| error.with_context("allocate_uninit_sized_after"))
     }
 
     #[must_use]
@@ -61,7 +63,7 @@ where
     pub fn try_insert_before(&mut self, value: T) -> Result<(), AllocateError<T>> {
         let node = match self.try_allocate_uninit_sized_before() {
             Ok(node) => node,
-            Err(error) => return Err(error.with_value(value)),
+            Err(error) => return Err(error.with_context("insert_before").with_value(value)),
         };
         unsafe { node.as_ptr().write(value) };
         unsafe { node.insert() };
@@ -75,7 +77,7 @@ where
     pub fn try_insert_after(&mut self, value: T) -> Result<(), AllocateError<T>> {
         let node = match self.try_allocate_uninit_sized_after() {
             Ok(node) => node,
-            Err(error) => return Err(error.with_value(value)),
+            Err(error) => return Err(error.with_context("insert_after").with_value(value)),
         };
         unsafe { node.as_ptr().write(value) };
         unsafe { node.insert() };
@@ -97,11 +99,21 @@ where
     }
 
     #[must_use]
-    /// Removes the current element.
+    /// Removes the current element and moves to the previous element.
     ///
     /// If the cursor is pointing to the "ghost" element, this returns [`None`].
     pub fn remove_current(&mut self) -> Option<T> {
         self.remove_current_node()
             .map(|node| unsafe { node.take() })
     }
+
+    #[must_use]
+    #[inline]
+    /// Replaces the current element with `value`, returning the old one.
+    ///
+    /// The value is written into the same node, so this does not allocate.
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`] and drops `value`.
+    pub fn replace_current(&mut self, value: T) -> Option<T> {
+        self.current().map(|current| mem::replace(current, value))
+    }
 }
@@ -5,12 +5,43 @@ use crate::{
     AllocateError, Ends, MaybeUninitNode,
 };
 
-use super::CursorMut;
+use super::{Cursor, CursorMut};
+
+impl<A> Cursor<'_, str, A>
+where
+    A: Allocator,
+{
+    #[must_use]
+    /// Gets the length of the current string.
+    ///
+    /// This reads the length stored in the node, so the string's bytes are not accessed.
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
+    pub fn current_len(&self) -> Option<usize> {
+        self.current.map(|node| {
+            // SAFETY:
+            // The node is in the list, so it has not been deallocated.
+            unsafe { node.metadata() }
+        })
+    }
+}
 
 impl<A> CursorMut<'_, str, A>
 where
     A: Allocator,
 {
+    #[must_use]
+    /// Gets the length of the current string.
+    ///
+    /// This reads the length stored in the node, so the string's bytes are not accessed.
+    /// If the cursor is pointing to the "ghost" element, this returns [`None`].
+    pub fn current_len(&self) -> Option<usize> {
+        self.current.map(|node| {
+            // SAFETY:
+            // The node is in the list, so it has not been deallocated.
+            unsafe { node.metadata() }
+        })
+    }
+
     /// Attempts to allocate an uninitialised string node before the current node.
     ///
     /// If the cursor is on the "ghost" element, this will allocate the node at the back of the list.
@@ -30,6 +61,7 @@ where
         );
 
         node::try_new_string(&mut *self.list, length, Header { next, previous })
+            .map_err(|error| error.with_context("allocate_uninit_string_before"))
     }
 
     /// Attempts to allocate an uninitialised string node after the current node.
@@ -51,6 +83,7 @@ where
         );
 
         node::try_new_string(&mut *self.list, length, Header { next, previous })
+            .map_err(|error| error.with_context("allocate_uninit_string_after"))
     }
 
     #[must_use]
@@ -74,7 +107,9 @@ where
     /// # Errors
     /// If allocation fails, this will return an [`AllocateError`].
     pub fn try_insert_copy_str_before(&mut self, src: &str) -> Result<(), AllocateError> {
-        let mut node = self.try_allocate_uninit_string_before(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_string_before(src.len())
+            .map_err(|error| error.with_context("insert_copy_str_before"))?;
         node.copy_from_str(src);
         unsafe { node.insert() };
         Ok(())
@@ -85,7 +120,9 @@ where
     /// # Errors
     /// If allocation fails, this will return an [`AllocateError`].
     pub fn try_insert_copy_str_after(&mut self, src: &str) -> Result<(), AllocateError> {
-        let mut node = self.try_allocate_uninit_string_after(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_string_after(src.len())
+            .map_err(|error| error.with_context("insert_copy_str_after"))?;
         node.copy_from_str(src);
         unsafe { node.insert() };
         Ok(())
@@ -0,0 +1,150 @@
+use core::{alloc::Allocator, ptr};
+
+use dynode::AllocateError;
+
+use crate::DynList;
+
+/// Allows lists of trait objects to be cloned.
+///
+/// Trait objects cannot implement [`Clone`], so [`DynList::clone`] is not available for lists such as `DynList<dyn Trait>`.
+/// If `Trait` has `DynClone` as a supertrait, [`DynList::clone_trait_objects`] can be used instead.
+///
+/// This is implemented for all types that implement [`Clone`].
+///
+/// # Safety
+/// [`Self::clone_to_uninit_dyn`] must write a valid clone of `self`, with the same layout and metadata, to `dst`.
+///
+/// # Examples
+/// ```
+/// # use dyn_list::{DynClone, DynList};
+/// trait Shape: DynClone {
+///     fn area(&self) -> f64;
+/// }
+///
+/// #[derive(Clone)]
+/// struct Square(f64);
+/// impl Shape for Square {
+///     fn area(&self) -> f64 {
+///         self.0 * self.0
+///     }
+/// }
+///
+/// #[derive(Clone)]
+/// struct Rectangle(f64, f64);
+/// impl Shape for Rectangle {
+///     fn area(&self) -> f64 {
+///         self.0 * self.1
+///     }
+/// }
+///
+/// let mut list = DynList::<dyn Shape>::new();
+/// list.push_back_unsize(Square(2.0));
+/// list.push_back_unsize(Rectangle(1.0, 3.0));
+///
+/// let clone = list.clone_trait_objects();
+/// assert!(clone.iter().map(Shape::area).eq([4.0, 3.0]));
+/// ```
+pub unsafe trait DynClone {
+    /// Clones `self` into `dst`.
+    ///
+    /// # Safety
+    /// `dst` must be valid for writes of the layout of `self` and must be properly aligned.
+    unsafe fn clone_to_uninit_dyn(&self, dst: *mut u8);
+}
+
+// SAFETY:
+// This writes a clone of `self`, which has the same layout and metadata as `T` is sized.
+unsafe impl<T> DynClone for T
+where
+    T: Clone,
+{
+    #[inline]
+    unsafe fn clone_to_uninit_dyn(&self, dst: *mut u8) {
+        // SAFETY:
+        // `dst` is valid for writes of `T` and is properly aligned (safety condition).
+        unsafe { dst.cast::<T>().write(self.clone()) };
+    }
+}
+
+impl<U, A> DynList<U, A>
+where
+    U: ?Sized + DynClone,
+    A: Allocator,
+{
+    /// Attempts to clone a list of trait objects into another allocator.
+    ///
+    /// See [`DynClone`] for more information.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    pub fn try_clone_trait_objects_in<A2>(
+        &self,
+        allocator: A2,
+    ) -> Result<DynList<U, A2>, AllocateError>
+    where
+        A2: Allocator,
+    {
+        let mut new_list = DynList::new_in(allocator);
+
+        for item in self {
+            // SAFETY:
+            // The metadata is from a valid reference to `U`.
+            let node = unsafe { new_list.try_allocate_uninit_back(ptr::metadata(item)) }
+                .map_err(|error| error.with_context("clone_trait_objects"))?;
+            // SAFETY:
+            // The node was allocated with the metadata of `item`, so it is valid for writes of its layout.
+            unsafe { item.clone_to_uninit_dyn(node.value_ptr().cast().as_ptr()) };
+            // SAFETY:
+            // The value was initialised by `clone_to_uninit_dyn`.
+            unsafe { node.insert() };
+        }
+
+        Ok(new_list)
+    }
+
+    #[must_use]
+    /// Clones a list of trait objects into another allocator.
+    ///
+    /// See [`DynClone`] for more information.
+    pub fn clone_trait_objects_in<A2>(&self, allocator: A2) -> DynList<U, A2>
+    where
+        A2: Allocator,
+    {
+        AllocateError::unwrap_result(self.try_clone_trait_objects_in(allocator))
+    }
+
+    #[must_use]
+    /// Clones a list of trait objects.
+    ///
+    /// See [`DynClone`] for more information.
+    pub fn clone_trait_objects(&self) -> Self
+    where
+        A: Clone,
+    {
+        let allocator = self.allocator.clone();
+        self.clone_trait_objects_in(allocator)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::fmt::Debug;
+
+    use crate::{DynClone, DynList};
+
+    trait CloneDebug: DynClone + Debug {}
+    impl<T> CloneDebug for T where T: Clone + Debug {}
+
+    #[test]
+    fn clone_trait_objects() {
+        let mut list = DynList::<dyn CloneDebug>::new();
+        list.push_back_unsize(String::from("Hello"));
+        list.push_back_unsize(1_u8);
+        list.push_back_unsize([2_u64; 3]);
+
+        let clone = list.clone_trait_objects();
+        drop(list);
+        clone.check_debug();
+        assert_eq!(format!("{clone:?}"), r#"["Hello", 1, [2, 2, 2]]"#);
+    }
+}
@@ -0,0 +1,61 @@
+use core::alloc::Allocator;
+use std::io::{self, Write};
+
+use crate::DynList;
+
+/// Writes bytes to the back of the list.
+///
+/// Each call to [`Write::write`] or [`Write::write_all`] copies the whole buffer into one new node, so the caller's chunk sizes control the size of the nodes.
+/// Flushing does nothing.
+///
+/// If allocation fails, the write returns an [`io::Error`] of kind [`io::ErrorKind::OutOfMemory`] and the list is left unchanged.
+///
+/// # Examples
+/// ```
+/// # use std::io::Write;
+/// # use dyn_list::DynList;
+/// let mut list = DynList::<[u8]>::new();
+/// list.write_all(b"Hello").unwrap();
+/// assert_eq!(list.write(b", World!").unwrap(), 8);
+///
+/// assert_eq!(list.len(), 2);
+/// assert_eq!(list.iter().flatten().copied().collect::<Vec<_>>(), b"Hello, World!");
+/// ```
+impl<A> Write for DynList<[u8], A>
+where
+    A: Allocator,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.try_push_back_copy_array(buf)
+            .map_err(|error| io::Error::new(io::ErrorKind::OutOfMemory, error))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use crate::DynList;
+
+    #[test]
+    fn write() {
+        let mut list = DynList::<[u8]>::new();
+        assert_eq!(list.write(b"abc").unwrap(), 3);
+        assert_eq!(list.write(&[]).unwrap(), 0);
+        list.write_all(b"de").unwrap();
+        list.flush().unwrap();
+
+        list.check_debug();
+        assert!(list.iter().eq([&b"abc"[..], b"", b"de"]));
+    }
+}
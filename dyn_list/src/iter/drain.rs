@@ -0,0 +1,237 @@
+#[cfg(feature = "alloc")]
+use crate::alloc;
+use core::{
+    alloc::Allocator,
+    fmt,
+    iter::{ExactSizeIterator, FusedIterator},
+};
+
+use crate::DynList;
+
+/// An iterator that removes and yields all of the elements of a [`DynList`].
+///
+/// This is created by [`DynList::drain`].
+pub struct Drain<'a, U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    list: &'a mut DynList<U, A>,
+}
+
+impl<'a, U, A> Drain<'a, U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    #[must_use]
+    #[inline]
+    pub(crate) const fn new(list: &'a mut DynList<U, A>) -> Self {
+        Self { list }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the elements that have not been yielded yet.
+    pub const fn remainder(&self) -> &DynList<U, A> {
+        self.list
+    }
+}
+
+impl<T, A> Iterator for Drain<'_, T, A>
+where
+    A: Allocator,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, A> DoubleEndedIterator for Drain<'_, T, A>
+where
+    A: Allocator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, A> ExactSizeIterator for Drain<'_, T, A> where A: Allocator {}
+
+impl<T, A> FusedIterator for Drain<'_, T, A> where A: Allocator {}
+
+impl<U, A> Drop for Drain<'_, U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    fn drop(&mut self) {
+        self.list.clear();
+    }
+}
+
+impl<U, A> fmt::Debug for Drain<'_, U, A>
+where
+    U: ?Sized + fmt::Debug,
+    A: Allocator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(self.list).finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// An iterator that removes and yields all of the elements of a [`DynList`] in boxes.
+///
+/// This is created by [`DynList::drain_boxed`].
+pub struct DrainBoxed<'a, U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    inner: Drain<'a, U, A>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, U, A> DrainBoxed<'a, U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    #[must_use]
+    #[inline]
+    pub(crate) const fn new(list: &'a mut DynList<U, A>) -> Self {
+        Self {
+            inner: Drain::new(list),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the elements that have not been yielded yet.
+    pub const fn remainder(&self) -> &DynList<U, A> {
+        self.inner.remainder()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<U, A> Iterator for DrainBoxed<'_, U, A>
+where
+    U: ?Sized,
+    A: Allocator + Clone,
+{
+    type Item = alloc::Box<U, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.list.pop_front_boxed()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.list.len();
+        (len, Some(len))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<U, A> DoubleEndedIterator for DrainBoxed<'_, U, A>
+where
+    U: ?Sized,
+    A: Allocator + Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.list.pop_back_boxed()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<U, A> ExactSizeIterator for DrainBoxed<'_, U, A>
+where
+    U: ?Sized,
+    A: Allocator + Clone,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<U, A> FusedIterator for DrainBoxed<'_, U, A>
+where
+    U: ?Sized,
+    A: Allocator + Clone,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<U, A> fmt::Debug for DrainBoxed<'_, U, A>
+where
+    U: ?Sized + fmt::Debug,
+    A: Allocator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DrainBoxed").field(self.inner.list).finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use crate::DynList;
+
+    #[test]
+    fn drain() {
+        struct DropCounter<'a>(&'a Cell<usize>);
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let mut list = DynList::<i32>::new();
+        list.extend(0..5);
+        let mut drain = list.drain();
+        assert_eq!(drain.len(), 5);
+        assert_eq!(drain.next(), Some(0));
+        assert_eq!(drain.next_back(), Some(4));
+        assert_eq!(drain.remainder().len(), 3);
+        drop(drain);
+
+        // The list can be reused
+        list.check_debug();
+        assert!(list.is_empty());
+        list.push_back(5);
+        assert!(list.drain().eq([5]));
+        assert!(list.is_empty());
+
+        // Dropping the iterator drops the remaining elements
+        let drops = Cell::new(0);
+        let mut list = DynList::<DropCounter>::new();
+        list.extend([
+            DropCounter(&drops),
+            DropCounter(&drops),
+            DropCounter(&drops),
+        ]);
+        drop(list.drain().next());
+        assert_eq!(drops.get(), 3);
+        assert!(list.is_empty());
+
+        let mut list = DynList::<str>::new();
+        list.push_back_copy_string("a");
+        list.push_back_copy_string("bc");
+        list.push_back_copy_string("def");
+        let mut drain = list.drain_boxed();
+        assert_eq!(drain.next().as_deref(), Some("a"));
+        assert_eq!(drain.next_back().as_deref(), Some("def"));
+        drop(drain);
+        list.check_debug();
+        assert!(list.is_empty());
+    }
+}
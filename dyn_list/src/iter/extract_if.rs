@@ -0,0 +1,233 @@
+#[cfg(feature = "alloc")]
+use crate::alloc;
+use core::{alloc::Allocator, fmt, iter::FusedIterator};
+
+use crate::cursor::CursorMut;
+
+/// An iterator that removes and yields the elements of a [`DynList`](crate::DynList) that match a predicate.
+///
+/// This is created by [`DynList::extract_if`](crate::DynList::extract_if).
+pub struct ExtractIf<'a, U, A, F>
+where
+    U: ?Sized,
+    A: Allocator,
+    F: FnMut(&mut U) -> bool,
+{
+    cursor: CursorMut<'a, U, A>,
+    filter: F,
+    panicked: bool,
+}
+
+impl<'a, U, A, F> ExtractIf<'a, U, A, F>
+where
+    U: ?Sized,
+    A: Allocator,
+    F: FnMut(&mut U) -> bool,
+{
+    #[must_use]
+    #[inline]
+    pub(crate) const fn new(cursor: CursorMut<'a, U, A>, filter: F) -> Self {
+        Self {
+            cursor,
+            filter,
+            panicked: false,
+        }
+    }
+
+    /// Moves the cursor to the next element that matches the filter.
+    ///
+    /// Returns [`false`] if the cursor reached the "ghost" element.
+    fn find_next(&mut self) -> bool {
+        while let Some(current) = self.cursor.current() {
+            // If the filter panics, this stays set and `drop` stops extracting
+            self.panicked = true;
+            let extract = (self.filter)(current);
+            self.panicked = false;
+
+            if extract {
+                return true;
+            }
+            self.cursor.move_next();
+        }
+
+        false
+    }
+
+    #[inline]
+    const fn upper_bound(&self) -> (usize, Option<usize>) {
+        (0, Some(self.cursor.as_list().len()))
+    }
+}
+
+impl<T, A, F> Iterator for ExtractIf<'_, T, A, F>
+where
+    A: Allocator,
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.find_next() {
+            return None;
+        }
+
+        let value = self.cursor.remove_current();
+        debug_assert!(value.is_some());
+        // Removing the node moves the cursor back, so move it on to the next unvisited element
+        self.cursor.move_next();
+        value
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.upper_bound()
+    }
+}
+
+impl<T, A, F> FusedIterator for ExtractIf<'_, T, A, F>
+where
+    A: Allocator,
+    F: FnMut(&mut T) -> bool,
+{
+}
+
+impl<U, A, F> Drop for ExtractIf<'_, U, A, F>
+where
+    U: ?Sized,
+    A: Allocator,
+    F: FnMut(&mut U) -> bool,
+{
+    fn drop(&mut self) {
+        if self.panicked {
+            return;
+        }
+
+        while self.find_next() {
+            self.cursor.delete_current();
+            self.cursor.move_next();
+        }
+    }
+}
+
+impl<U, A, F> fmt::Debug for ExtractIf<'_, U, A, F>
+where
+    U: ?Sized + fmt::Debug,
+    A: Allocator,
+    F: FnMut(&mut U) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExtractIf")
+            .field(self.cursor.as_list())
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// An iterator that removes and yields the elements of a [`DynList`](crate::DynList) that match a predicate in boxes.
+///
+/// This is created by [`DynList::extract_if_boxed`](crate::DynList::extract_if_boxed).
+pub struct ExtractIfBoxed<'a, U, A, F>
+where
+    U: ?Sized,
+    A: Allocator,
+    F: FnMut(&mut U) -> bool,
+{
+    inner: ExtractIf<'a, U, A, F>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, U, A, F> ExtractIfBoxed<'a, U, A, F>
+where
+    U: ?Sized,
+    A: Allocator,
+    F: FnMut(&mut U) -> bool,
+{
+    #[must_use]
+    #[inline]
+    pub(crate) const fn new(cursor: CursorMut<'a, U, A>, filter: F) -> Self {
+        Self {
+            inner: ExtractIf::new(cursor, filter),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<U, A, F> Iterator for ExtractIfBoxed<'_, U, A, F>
+where
+    U: ?Sized,
+    A: Allocator + Clone,
+    F: FnMut(&mut U) -> bool,
+{
+    type Item = alloc::Box<U, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.inner.find_next() {
+            return None;
+        }
+
+        let value = self.inner.cursor.remove_current_boxed();
+        debug_assert!(value.is_some());
+        // Removing the node moves the cursor back, so move it on to the next unvisited element
+        self.inner.cursor.move_next();
+        value
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.upper_bound()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<U, A, F> FusedIterator for ExtractIfBoxed<'_, U, A, F>
+where
+    U: ?Sized,
+    A: Allocator + Clone,
+    F: FnMut(&mut U) -> bool,
+{
+}
+
+#[cfg(feature = "alloc")]
+impl<U, A, F> fmt::Debug for ExtractIfBoxed<'_, U, A, F>
+where
+    U: ?Sized + fmt::Debug,
+    A: Allocator,
+    F: FnMut(&mut U) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ExtractIfBoxed")
+            .field(self.inner.cursor.as_list())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::DynList;
+
+    #[test]
+    fn extract_if() {
+        let mut list = DynList::<i32>::new();
+        list.extend(0..10);
+
+        let mut extracted = list.extract_if(|value| *value % 3 == 0);
+        assert_eq!(extracted.next(), Some(0));
+        assert_eq!(extracted.next(), Some(3));
+        // Dropping the iterator removes the remaining matches
+        drop(extracted);
+
+        list.check_debug();
+        assert!(list.iter().copied().eq([1, 2, 4, 5, 7, 8]));
+
+        let mut list = DynList::<str>::new();
+        list.push_back_copy_string("keep");
+        list.push_back_copy_string("take");
+        list.push_back_copy_string("keep");
+        list.push_back_copy_string("take");
+
+        let extracted = list.extract_if_boxed(|s| s == "take");
+        assert_eq!(extracted.count(), 2);
+        list.check_debug();
+        assert_eq!(list.len(), 2);
+    }
+}
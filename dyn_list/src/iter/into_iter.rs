@@ -1,6 +1,9 @@
 #[cfg(feature = "alloc")]
 use crate::alloc;
-use core::{alloc::Allocator, iter::FusedIterator};
+use core::{
+    alloc::Allocator,
+    iter::{ExactSizeIterator, FusedIterator},
+};
 
 use crate::DynList;
 
@@ -34,6 +37,20 @@ where
         &self.list
     }
 
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the element that will be yielded by [`Iterator::next`], without removing it.
+    pub fn peek_front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the element that will be yielded by [`DoubleEndedIterator::next_back`], without removing it.
+    pub fn peek_back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
     #[must_use]
     #[inline]
     /// Converts the remaining iterator to a [`DynList`].
@@ -59,6 +76,23 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.list.pop_front()
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        // Dropping the iterator drops the remaining elements
+        self.list.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
 impl<T, A> DoubleEndedIterator for IntoIter<T, A>
@@ -70,6 +104,8 @@ where
     }
 }
 
+impl<T, A> ExactSizeIterator for IntoIter<T, A> where A: Allocator {}
+
 impl<T, A> FusedIterator for IntoIter<T, A> where A: Allocator {}
 
 impl<T, A> IntoIterator for DynList<T, A>
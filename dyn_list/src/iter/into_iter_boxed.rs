@@ -1,11 +1,47 @@
 use crate::alloc;
-use core::{alloc::Allocator, iter::FusedIterator};
+use core::{
+    alloc::Allocator,
+    any::Any,
+    fmt::Debug,
+    iter::{ExactSizeIterator, FusedIterator},
+};
 
 use crate::DynList;
 
 /// An iterator over boxed elements of a [`DynList`].
 ///
-/// This is created by [`DynList::into_iter`].
+/// This is created by [`DynList::into_iter_boxed`].
+/// Lists of [`str`], slices, [`dyn Any`](Any) and [`dyn Debug`](Debug) also create this with [`IntoIterator::into_iter`], so they can be iterated over by value.
+///
+/// [`IntoIterator`] cannot be implemented for lists of all unsized types, as that would overlap with the implementation for sized types, which yields the elements themselves.
+/// For lists of other trait objects, use [`DynList::into_iter_boxed`].
+///
+/// # Examples
+/// ```
+/// # use dyn_list::DynList;
+/// let mut list = DynList::<str>::new();
+/// list.push_back_copy_string("Hello");
+/// list.push_back_copy_string("World");
+///
+/// for string in list {
+///     let string: Box<str> = string;
+///     assert_eq!(string.len(), 5);
+/// }
+/// ```
+///
+/// ```
+/// # use core::fmt::Debug;
+/// # use dyn_list::DynList;
+/// let mut list = DynList::<dyn Debug>::new();
+/// list.push_back_unsize(1_u8);
+/// list.push_back_unsize("two");
+///
+/// let debug: Vec<String> = list
+///     .into_iter()
+///     .map(|value: Box<dyn Debug>| format!("{value:?}"))
+///     .collect();
+/// assert_eq!(debug, ["1", "\"two\""]);
+/// ```
 pub struct IntoIterBoxed<U: ?Sized, A: Allocator = alloc::Global> {
     list: DynList<U, A>,
 }
@@ -31,6 +67,20 @@ where
         &self.list
     }
 
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the element that will be yielded by [`Iterator::next`], without removing it.
+    pub fn peek_front(&self) -> Option<&U> {
+        self.list.front()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the element that will be yielded by [`DoubleEndedIterator::next_back`], without removing it.
+    pub fn peek_back(&self) -> Option<&U> {
+        self.list.back()
+    }
+
     #[must_use]
     #[inline]
     /// Converts the remaining iterator to a [`DynList`].
@@ -60,6 +110,12 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.list.pop_front_boxed()
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len, Some(len))
+    }
 }
 
 impl<U, A> DoubleEndedIterator for IntoIterBoxed<U, A>
@@ -72,9 +128,78 @@ where
     }
 }
 
+impl<U, A> ExactSizeIterator for IntoIterBoxed<U, A>
+where
+    U: ?Sized,
+    A: Allocator + Clone,
+{
+}
+
 impl<U, A> FusedIterator for IntoIterBoxed<U, A>
 where
     U: ?Sized,
     A: Allocator + Clone,
 {
 }
+
+// A blanket implementation for all unsized types would overlap with the one for sized types on
+// `IntoIter`, so this is only implemented for common unsized types
+macro_rules! into_iterator_impl {
+    ( $( [ $( $generic:ident ),* ] $unsized:ty ),* $(,)? ) => { $(
+        impl<$( $generic, )* A> IntoIterator for DynList<$unsized, A>
+        where
+            A: Allocator + Clone,
+        {
+            type Item = alloc::Box<$unsized, A>;
+            type IntoIter = IntoIterBoxed<$unsized, A>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.into_iter_boxed()
+            }
+        }
+    )* };
+}
+
+into_iterator_impl! {
+    [] str,
+    [T] [T],
+    [] dyn Any,
+    [] dyn Any + Send,
+    [] dyn Any + Send + Sync,
+    [] dyn Debug,
+    [] dyn Debug + Send,
+    [] dyn Debug + Send + Sync,
+}
+
+#[cfg(test)]
+mod test {
+    use core::{any::Any, fmt::Debug};
+
+    use crate::DynList;
+
+    #[test]
+    fn into_iter_unsized() {
+        let list = DynList::<str>::from_iter(["a", "bc"]);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next().as_deref(), Some("a"));
+        assert_eq!(iter.next_back().as_deref(), Some("bc"));
+        assert!(iter.next().is_none());
+
+        let list = DynList::<[u8]>::from_iter([&[1, 2][..], &[3]]);
+        let lengths: Vec<usize> = list.into_iter().map(|slice| slice.len()).collect();
+        assert_eq!(lengths, [2, 1]);
+
+        let mut list = DynList::<dyn Any>::new();
+        list.push_back_unsize(1_u8);
+        for value in list {
+            assert_eq!(value.downcast_ref(), Some(&1_u8));
+        }
+
+        let mut list = DynList::<dyn Debug + Send>::new();
+        list.push_back_unsize(2_u8);
+        for value in list {
+            assert_eq!(format!("{value:?}"), "2");
+        }
+    }
+}
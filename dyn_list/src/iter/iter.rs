@@ -1,4 +1,8 @@
-use core::{alloc::Allocator, iter::FusedIterator, marker::PhantomData};
+use core::{
+    alloc::Allocator,
+    iter::{ExactSizeIterator, FusedIterator},
+    marker::PhantomData,
+};
 
 use crate::DynList;
 
@@ -8,6 +12,16 @@ use super::RawIter;
 /// An iterator over references to elements of a [`DynList`].
 ///
 /// This is created by [`DynList::iter`].
+///
+/// This is only [`Send`] and [`Sync`] if the elements are [`Sync`]:
+/// ```compile_fail
+/// # use core::cell::Cell;
+/// # use dyn_list::DynList;
+/// fn assert_sync<T: Sync>(_: T) {}
+///
+/// let list = DynList::<Cell<u8>>::new();
+/// assert_sync(list.iter());
+/// ```
 pub struct Iter<'a, U: ?Sized> {
     raw: RawIter<U>,
     _phantom: PhantomData<&'a U>,
@@ -25,6 +39,15 @@ impl<'a, U: ?Sized> Iter<'a, U> {
             _phantom: PhantomData,
         }
     }
+
+    #[must_use]
+    #[inline]
+    pub(crate) const fn from_raw(raw: RawIter<U>) -> Self {
+        Self {
+            raw,
+            _phantom: PhantomData,
+        }
+    }
 }
 
 impl<'a, U: ?Sized> Iterator for Iter<'a, U> {
@@ -35,6 +58,32 @@ impl<'a, U: ?Sized> Iterator for Iter<'a, U> {
         let ptr = unsafe { node.data_ptr() };
         Some(unsafe { ptr.as_ref() })
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.raw.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let node = self.raw.nth(n)?;
+        // SAFETY:
+        // The node is in the list, so it has not been deallocated.
+        let ptr = unsafe { node.data_ptr() };
+        // SAFETY:
+        // The node's value is initialised and there are no mutable references to it, as the iterator borrows the list.
+        Some(unsafe { ptr.as_ref() })
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.raw.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
 impl<U: ?Sized> DoubleEndedIterator for Iter<'_, U> {
@@ -43,22 +92,39 @@ impl<U: ?Sized> DoubleEndedIterator for Iter<'_, U> {
         let ptr = unsafe { node.data_ptr() };
         Some(unsafe { ptr.as_ref() })
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let node = self.raw.nth_back(n)?;
+        // SAFETY:
+        // The node is in the list, so it has not been deallocated.
+        let ptr = unsafe { node.data_ptr() };
+        // SAFETY:
+        // The node's value is initialised and there are no mutable references to it, as the iterator borrows the list.
+        Some(unsafe { ptr.as_ref() })
+    }
 }
 
+impl<U: ?Sized> ExactSizeIterator for Iter<'_, U> {}
+
 impl<U: ?Sized> FusedIterator for Iter<'_, U> {}
 
 impl<U: ?Sized> Clone for Iter<'_, U> {
     fn clone(&self) -> Self {
         Self {
-            raw: RawIter {
-                ends: self.raw.ends,
-            },
+            raw: self.raw.clone(),
             _phantom: PhantomData,
         }
     }
 }
 
+// SAFETY:
+// `Iter` only gives out shared references to the elements, which can be sent between threads if
+// the elements are `Sync`. `RawIter` requires `U: Send` to be `Send` as it may also be used for
+// mutable access, but `Iter` only uses it to read.
+#[expect(clippy::non_send_fields_in_send_ty)]
 unsafe impl<U> Send for Iter<'_, U> where U: ?Sized + Sync {}
+// SAFETY:
+// As above.
 unsafe impl<U> Sync for Iter<'_, U> where U: ?Sized + Sync {}
 
 impl<'a, U: ?Sized, A> IntoIterator for &'a DynList<U, A>
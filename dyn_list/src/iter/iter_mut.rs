@@ -1,4 +1,8 @@
-use core::{alloc::Allocator, iter::FusedIterator, marker::PhantomData};
+use core::{
+    alloc::Allocator,
+    iter::{ExactSizeIterator, FusedIterator},
+    marker::PhantomData,
+};
 
 use crate::DynList;
 
@@ -8,6 +12,16 @@ use super::RawIter;
 /// An iterator over mutable references to elements of a [`DynList`].
 ///
 /// This is created by [`DynList::iter_mut`].
+///
+/// This is only [`Send`] if the elements are [`Send`]:
+/// ```compile_fail
+/// # use std::rc::Rc;
+/// # use dyn_list::DynList;
+/// fn assert_send<T: Send>(_: T) {}
+///
+/// let mut list = DynList::<Rc<u8>>::new();
+/// assert_send(list.iter_mut());
+/// ```
 pub struct IterMut<'a, U: ?Sized> {
     raw: RawIter<U>,
     _phantom: PhantomData<&'a mut U>,
@@ -26,6 +40,15 @@ impl<'a, U: ?Sized> IterMut<'a, U> {
             _phantom: PhantomData,
         }
     }
+
+    #[must_use]
+    #[inline]
+    pub(crate) const fn from_raw(raw: RawIter<U>) -> Self {
+        Self {
+            raw,
+            _phantom: PhantomData,
+        }
+    }
 }
 
 impl<'a, U: ?Sized> Iterator for IterMut<'a, U> {
@@ -36,6 +59,32 @@ impl<'a, U: ?Sized> Iterator for IterMut<'a, U> {
         let mut ptr = unsafe { node.data_ptr() };
         Some(unsafe { ptr.as_mut() })
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.raw.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let node = self.raw.nth(n)?;
+        // SAFETY:
+        // The node is in the list, so it has not been deallocated.
+        let mut ptr = unsafe { node.data_ptr() };
+        // SAFETY:
+        // The node's value is initialised and the iterator mutably borrows the list and yields each node at most once.
+        Some(unsafe { ptr.as_mut() })
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.raw.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
 }
 
 impl<U: ?Sized> DoubleEndedIterator for IterMut<'_, U> {
@@ -44,11 +93,29 @@ impl<U: ?Sized> DoubleEndedIterator for IterMut<'_, U> {
         let mut ptr = unsafe { node.data_ptr() };
         Some(unsafe { ptr.as_mut() })
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let node = self.raw.nth_back(n)?;
+        // SAFETY:
+        // The node is in the list, so it has not been deallocated.
+        let mut ptr = unsafe { node.data_ptr() };
+        // SAFETY:
+        // The node's value is initialised and the iterator mutably borrows the list and yields each node at most once.
+        Some(unsafe { ptr.as_mut() })
+    }
 }
 
+impl<U: ?Sized> ExactSizeIterator for IterMut<'_, U> {}
+
 impl<U: ?Sized> FusedIterator for IterMut<'_, U> {}
 
+// SAFETY:
+// `IterMut` gives out mutable references to the elements, which can be sent between threads if the
+// elements are `Send`.
 unsafe impl<U> Send for IterMut<'_, U> where U: ?Sized + Send {}
+// SAFETY:
+// A shared `IterMut` cannot give out any references to the elements, but requiring `Sync` matches
+// `&mut U`.
 unsafe impl<U> Sync for IterMut<'_, U> where U: ?Sized + Sync {}
 
 impl<'a, U: ?Sized, A> IntoIterator for &'a mut DynList<U, A>
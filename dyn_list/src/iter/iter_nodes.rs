@@ -0,0 +1,93 @@
+use core::{
+    alloc::Allocator,
+    iter::{ExactSizeIterator, FusedIterator},
+    marker::PhantomData,
+};
+
+use dynode::HeaderOpaqueNodePtr;
+
+use crate::DynList;
+
+use super::RawIter;
+
+#[derive(Default)]
+/// An iterator over pointers to the nodes of a [`DynList`].
+///
+/// This is created by [`DynList::iter_nodes`].
+///
+/// The pointers identify the nodes, so they can be recorded to build indexes over a list or for other per-node logic.
+/// They are only valid while their nodes are in the list: once a node is removed, by this list or any of its cursors and iterators, its pointer dangles, and once the list is dropped, all of them do.
+/// Using a pointer to a node that is no longer in the list is undefined behaviour.
+pub struct IterNodes<'a, U: ?Sized> {
+    raw: RawIter<U>,
+    _phantom: PhantomData<&'a U>,
+}
+
+impl<'a, U: ?Sized> IterNodes<'a, U> {
+    #[must_use]
+    #[inline]
+    pub(crate) const fn new<A>(list: &'a DynList<U, A>) -> Self
+    where
+        A: Allocator,
+    {
+        Self {
+            raw: RawIter::from_list(list),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<U: ?Sized> Iterator for IterNodes<'_, U> {
+    type Item = HeaderOpaqueNodePtr<U>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.raw.next().map(HeaderOpaqueNodePtr::from)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.raw.len();
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.raw.nth(n).map(HeaderOpaqueNodePtr::from)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.raw.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<U: ?Sized> DoubleEndedIterator for IterNodes<'_, U> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.raw.next_back().map(HeaderOpaqueNodePtr::from)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.raw.nth_back(n).map(HeaderOpaqueNodePtr::from)
+    }
+}
+
+impl<U: ?Sized> ExactSizeIterator for IterNodes<'_, U> {}
+
+impl<U: ?Sized> FusedIterator for IterNodes<'_, U> {}
+
+impl<U: ?Sized> Clone for IterNodes<'_, U> {
+    fn clone(&self) -> Self {
+        Self {
+            raw: self.raw.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -1,27 +1,56 @@
 use core::{alloc::Allocator, marker::Unsize};
 
+mod drain;
+mod extract_if;
 mod into_iter;
 #[cfg(feature = "alloc")]
 mod into_iter_boxed;
 #[expect(clippy::module_inception)]
 mod iter;
 mod iter_mut;
+mod iter_nodes;
+mod pairs;
+#[cfg(feature = "rayon")]
+mod par_iter;
 
+pub use drain::Drain;
+#[cfg(feature = "alloc")]
+pub use drain::DrainBoxed;
+pub use extract_if::ExtractIf;
+#[cfg(feature = "alloc")]
+pub use extract_if::ExtractIfBoxed;
 pub use into_iter::IntoIter;
 #[cfg(feature = "alloc")]
 pub use into_iter_boxed::IntoIterBoxed;
 pub use iter::Iter;
 pub use iter_mut::IterMut;
+pub use iter_nodes::IterNodes;
+pub use pairs::Pairs;
+#[cfg(feature = "rayon")]
+pub use par_iter::{ParIter, ParIterMut};
 
 use crate::{node::Node, DynList, Ends};
 
 #[derive(Default)]
-#[repr(transparent)]
 pub(crate) struct RawIter<U>
 where
     U: ?Sized,
 {
     ends: Option<Ends<U>>,
+    len: usize,
+}
+
+impl<U> Clone for RawIter<U>
+where
+    U: ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            ends: self.ends,
+            len: self.len,
+        }
+    }
 }
 
 impl<U> RawIter<U>
@@ -34,7 +63,58 @@ where
     where
         A: Allocator,
     {
-        Self { ends: list.ends }
+        Self {
+            ends: list.ends,
+            len: list.len,
+        }
+    }
+
+    /// Creates an iterator over the nodes from `current`, which is at `index`, to the back of the list.
+    ///
+    /// If `current` is [`None`], the iterator is empty.
+    #[must_use]
+    pub const fn from_node_to_back<A>(
+        list: &DynList<U, A>,
+        current: Option<Node<U>>,
+        index: usize,
+    ) -> Self
+    where
+        A: Allocator,
+    {
+        match (current, list.ends) {
+            (Some(front), Some(Ends { back, .. })) => Self {
+                ends: Some(Ends { front, back }),
+                len: list.len - index,
+            },
+            _ => Self { ends: None, len: 0 },
+        }
+    }
+
+    /// Creates an iterator over the nodes from the front of the list to `current`, which is at `index`.
+    ///
+    /// If `current` is [`None`], the iterator is empty.
+    #[must_use]
+    pub const fn from_front_to_node<A>(
+        list: &DynList<U, A>,
+        current: Option<Node<U>>,
+        index: usize,
+    ) -> Self
+    where
+        A: Allocator,
+    {
+        match (current, list.ends) {
+            (Some(back), Some(Ends { front, .. })) => Self {
+                ends: Some(Ends { front, back }),
+                len: index + 1,
+            },
+            _ => Self { ends: None, len: 0 },
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
     }
 
     #[must_use]
@@ -52,6 +132,7 @@ where
             debug_assert!(header.next.is_some());
             *front = unsafe { header.next.unwrap_unchecked() };
         }
+        self.len -= 1;
 
         Some(node)
     }
@@ -71,15 +152,108 @@ where
             debug_assert!(header.previous.is_some());
             *back = unsafe { header.previous.unwrap_unchecked() };
         }
+        self.len -= 1;
 
         Some(node)
     }
+
+    /// Walks `n` nodes forwards from `node` by following the header links.
+    ///
+    /// # Safety
+    /// There must be at least `n` nodes after `node`.
+    unsafe fn walk_next(mut node: Node<U>, n: usize) -> Node<U> {
+        for _ in 0..n {
+            // SAFETY:
+            // The nodes in the iterator are allocated and their headers are not mutably aliased.
+            let header = unsafe { node.header_ptr().as_ref() };
+            debug_assert!(header.next.is_some());
+            // SAFETY:
+            // There are at least `n` nodes after the original node (safety condition).
+            node = unsafe { header.next.unwrap_unchecked() };
+        }
+        node
+    }
+
+    /// Walks `n` nodes backwards from `node` by following the header links.
+    ///
+    /// # Safety
+    /// There must be at least `n` nodes before `node`.
+    unsafe fn walk_previous(mut node: Node<U>, n: usize) -> Node<U> {
+        for _ in 0..n {
+            // SAFETY:
+            // The nodes in the iterator are allocated and their headers are not mutably aliased.
+            let header = unsafe { node.header_ptr().as_ref() };
+            debug_assert!(header.previous.is_some());
+            // SAFETY:
+            // There are at least `n` nodes before the original node (safety condition).
+            node = unsafe { header.previous.unwrap_unchecked() };
+        }
+        node
+    }
+
+    /// Skips `n` nodes from the front, then returns the next one.
+    ///
+    /// This walks from whichever end of the iterator is closer to the node.
+    #[must_use]
+    pub fn nth(&mut self, n: usize) -> Option<Node<U>> {
+        let len = self.len;
+        if n >= len {
+            self.ends = None;
+            self.len = 0;
+            return None;
+        }
+
+        let Ends { front, back } = self.ends.as_mut()?;
+        *front = if n <= len / 2 {
+            // SAFETY:
+            // There are `len - 1` nodes after the front node, and `n < len`.
+            unsafe { Self::walk_next(*front, n) }
+        } else {
+            // SAFETY:
+            // There are `len - 1` nodes before the back node, and `len - 1 - n < len - 1`.
+            unsafe { Self::walk_previous(*back, len - 1 - n) }
+        };
+        self.len -= n;
+
+        self.next()
+    }
+
+    /// Skips `n` nodes from the back, then returns the next one from the back.
+    ///
+    /// This walks from whichever end of the iterator is closer to the node.
+    #[must_use]
+    pub fn nth_back(&mut self, n: usize) -> Option<Node<U>> {
+        let len = self.len;
+        if n >= len {
+            self.ends = None;
+            self.len = 0;
+            return None;
+        }
+
+        let Ends { front, back } = self.ends.as_mut()?;
+        *back = if n <= len / 2 {
+            // SAFETY:
+            // There are `len - 1` nodes before the back node, and `n < len`.
+            unsafe { Self::walk_previous(*back, n) }
+        } else {
+            // SAFETY:
+            // There are `len - 1` nodes after the front node, and `len - 1 - n < len - 1`.
+            unsafe { Self::walk_next(*front, len - 1 - n) }
+        };
+        self.len -= n;
+
+        self.next_back()
+    }
 }
 
-// TODO: check if this impl is correct. Even if it isn't, it is not exposed
-//       so can't be abused anyway
-unsafe impl<U> Send for RawIter<U> where U: ?Sized {}
-unsafe impl<U> Sync for RawIter<U> where U: ?Sized {}
+// SAFETY:
+// A `RawIter` holds pointers to nodes that alias the list's elements, so it is only `Send` if the
+// elements are.
+unsafe impl<U> Send for RawIter<U> where U: ?Sized + Send {}
+// SAFETY:
+// A `RawIter` holds pointers to nodes that alias the list's elements, so it is only `Sync` if the
+// elements are.
+unsafe impl<U> Sync for RawIter<U> where U: ?Sized + Sync {}
 
 impl<Item, A> Extend<Item> for DynList<Item, A>
 where
@@ -106,8 +280,53 @@ where
 
 #[cfg(feature = "alloc")]
 impl<Item> FromIterator<Item> for DynList<Item> {
+    #[inline]
     fn from_iter<T: IntoIterator<Item = Item>>(iter: T) -> Self {
-        let mut list = Self::new();
+        Self::from_iter_in(iter, crate::alloc::Global)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Item> From<crate::alloc::Vec<Item>> for DynList<Item> {
+    /// Moves the elements of `vec` into a new list, keeping their order.
+    ///
+    /// If allocation fails, this calls [`handle_alloc_error`](std::alloc::handle_alloc_error), as with [`DynList::push_back`].
+    fn from(vec: crate::alloc::Vec<Item>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Item, const N: usize> From<[Item; N]> for DynList<Item> {
+    /// Moves the elements of `array` into a new list, keeping their order.
+    ///
+    /// If allocation fails, this calls [`handle_alloc_error`](std::alloc::handle_alloc_error), as with [`DynList::push_back`].
+    fn from(array: [Item; N]) -> Self {
+        array.into_iter().collect()
+    }
+}
+
+impl<T, A> DynList<T, A>
+where
+    A: Allocator,
+{
+    /// Creates a [`DynList`] from the contents of `iter` in `allocator`.
+    ///
+    /// This is the same as [`FromIterator::from_iter`], but with a custom allocator, so it can be used to collect into a list in an arena or pool.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use std::alloc::Global;
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<u8, Global>::from_iter_in(1..=3, Global);
+    /// assert!(list.iter().copied().eq(1..=3));
+    /// ```
+    pub fn from_iter_in<I>(iter: I, allocator: A) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut list = Self::new_in(allocator);
         list.extend(iter);
         list
     }
@@ -152,15 +371,17 @@ where
         T: IntoIterator,
         T::Item: Unsize<U>,
     {
-        let mut list = Self::new();
-        list.extend_unsize(iter);
-        list
+        Self::from_iter_unsize_in(iter, crate::alloc::Global)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::DynList;
+    use core::ptr::NonNull;
+
+    use dynode::HeaderOpaqueNodePtr;
+
+    use crate::{alloc, DynList};
 
     #[test]
     fn sized_extend() {
@@ -168,4 +389,190 @@ mod test {
 
         list.extend([1_u8, 2, 3]);
     }
+
+    #[test]
+    fn from_collections() {
+        let list = DynList::from(vec![String::from("a"), String::from("b")]);
+        assert!(list.iter().eq(["a", "b"]));
+
+        let list = DynList::from([1, 2, 3]);
+        list.check_debug();
+        assert!(list.iter().eq(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn from_iter_in() {
+        let list = DynList::<i32, _>::from_iter_in([], alloc::Global);
+        list.check_debug();
+        assert!(list.is_empty());
+
+        let list = DynList::from_iter_in((0..5).map(|value| value * 2), alloc::Global);
+        list.check_debug();
+        assert!(list.iter().eq(&[0, 2, 4, 6, 8]));
+    }
+
+    #[test]
+    fn unsized_from_iter() {
+        let list = ["a", "bc"].into_iter().collect::<DynList<str>>();
+        assert!(list.iter().eq(["a", "bc"]));
+
+        let mut list = [&[1, 2][..], &[]].into_iter().collect::<DynList<[u8]>>();
+        list.extend([&[3][..]]);
+        list.check_debug();
+        assert!(list.iter().eq([&[1, 2][..], &[], &[3]]));
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut list = DynList::<u8>::new();
+        assert_eq!(list.iter().size_hint(), (0, Some(0)));
+
+        list.extend([1, 2, 3, 4]);
+        list.push_front(0);
+        assert_eq!(list.iter().len(), 5);
+        assert_eq!(list.iter_mut().len(), 5);
+
+        assert_eq!(list.pop_back(), Some(4));
+        let mut iter = list.iter();
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.count(), 2);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.collect::<Vec<_>>().len(), 3);
+
+        let mut list = DynList::<str>::new();
+        list.push_back_copy_string("a");
+        list.push_back_copy_string("b");
+        let mut iter = list.into_iter_boxed();
+        assert_eq!(iter.len(), 2);
+        iter.next_back();
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+    }
+
+    #[test]
+    fn nth() {
+        let list = (0..10).collect::<DynList<u16>>();
+        let vec: Vec<u16> = (0..10).collect();
+
+        for n in 0..12 {
+            assert_eq!(list.iter().nth(n), vec.get(n));
+            assert_eq!(list.iter().nth_back(n), vec.iter().nth_back(n));
+        }
+
+        let mut iter = list.iter();
+        let mut vec_iter = vec.iter();
+        for n in [1, 0, 3, 1] {
+            assert_eq!(iter.nth(n), vec_iter.nth(n));
+            assert_eq!(iter.nth_back(n), vec_iter.nth_back(n));
+            assert_eq!(iter.len(), vec_iter.len());
+        }
+        assert_eq!(iter.nth(1), None);
+        assert_eq!(iter.count(), 0);
+
+        let mut list = list;
+        let mut iter = list.iter_mut();
+        *iter.nth(6).unwrap() = 60;
+        assert_eq!(iter.count(), 3);
+        assert_eq!(list.iter().nth_back(3), Some(&60));
+        assert_eq!(list.into_iter().count(), 10);
+    }
+
+    #[test]
+    fn interleaved() {
+        for len in [0, 1, 2, 5, 6] {
+            let mut list = (0..len).collect::<DynList<u8>>();
+            let mut iter = list.iter();
+            let mut yielded = Vec::new();
+            while let Some(&front) = iter.next() {
+                yielded.push(front);
+                let Some(&back) = iter.next_back() else {
+                    break;
+                };
+                yielded.push(back);
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+            yielded.sort_unstable();
+            assert!(yielded.into_iter().eq(0..len));
+
+            let mut iter = list.iter_mut_rev();
+            for _ in 0..len / 2 {
+                assert!(iter.next().is_some());
+                assert!(iter.next_back().is_some());
+            }
+            assert_eq!(iter.len(), usize::from(len % 2));
+            assert_eq!(iter.next().is_some(), len % 2 == 1);
+            assert!(iter.next_back().is_none());
+
+            assert!(list.iter_rev().copied().eq((0..len).rev()));
+        }
+    }
+
+    #[test]
+    fn peek() {
+        let mut iter = DynList::<u8>::from_iter([1, 2, 3]).into_iter();
+        assert_eq!(iter.peek_front(), Some(&1));
+        assert_eq!(iter.peek_back(), Some(&3));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.peek_front(), iter.peek_back());
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.peek_front(), None);
+        assert_eq!(iter.peek_back(), None);
+
+        let mut iter = DynList::<str>::from_iter(["a", "b"]).into_iter();
+        assert_eq!(iter.peek_front(), Some("a"));
+        assert_eq!(iter.peek_back(), Some("b"));
+        assert_eq!(iter.next().as_deref(), Some("a"));
+        assert_eq!(iter.peek_front(), Some("b"));
+        assert_eq!(iter.next_back().as_deref(), Some("b"));
+        assert_eq!(iter.peek_back(), None);
+    }
+
+    #[test]
+    fn iter_nodes() {
+        let list = DynList::<str>::new();
+        assert_eq!(list.iter_nodes().next(), None);
+
+        let list = ["a", "bc", "def"].into_iter().collect::<DynList<str>>();
+        let nodes = list.iter_nodes();
+        assert_eq!(nodes.len(), 3);
+        // The value pointers are the addresses of the elements
+        let values = list.iter().map(|value| NonNull::from(value).cast());
+        assert!(nodes.map(HeaderOpaqueNodePtr::value_ptr).eq(values));
+
+        let mut nodes = list.iter_nodes();
+        assert!(nodes.next_back().is_some());
+        assert_eq!(nodes.nth(1), list.iter_nodes().nth(1));
+        assert_eq!(nodes.next(), None);
+    }
+
+    #[test]
+    fn pairs() {
+        for len in 0..6 {
+            let values = (0..len).map(|value| value * 3).collect::<Vec<i32>>();
+            let list = values.iter().copied().collect::<DynList<i32>>();
+            let windows = values.windows(2).map(|window| (&window[0], &window[1]));
+
+            assert_eq!(list.pairs().len(), windows.len());
+            assert!(list.pairs().eq(windows.clone()));
+            assert!(list.pairs().rev().eq(windows.clone().rev()));
+            assert_eq!(list.pairs().nth(2), windows.clone().nth(2));
+            assert_eq!(list.pairs().nth_back(1), windows.clone().nth_back(1));
+            assert_eq!(list.pairs().last(), windows.clone().next_back());
+        }
+
+        let mut list = DynList::<str>::new();
+        list.extend(["a", "b", "c", "d"]);
+        let mut pairs = list.pairs();
+        assert_eq!(pairs.next(), Some(("a", "b")));
+        assert_eq!(pairs.next_back(), Some(("c", "d")));
+        assert_eq!(pairs.next(), Some(("b", "c")));
+        assert_eq!(pairs.next_back(), None);
+    }
 }
@@ -0,0 +1,126 @@
+use core::{
+    alloc::Allocator,
+    iter::{ExactSizeIterator, FusedIterator},
+    marker::PhantomData,
+};
+
+use crate::{node::Node, DynList, Ends};
+
+use super::RawIter;
+
+#[derive(Default)]
+/// An iterator over references to each pair of adjacent elements of a [`DynList`].
+///
+/// This is created by [`DynList::pairs`].
+pub struct Pairs<'a, U: ?Sized> {
+    // The first node of each pair
+    raw: RawIter<U>,
+    _phantom: PhantomData<&'a U>,
+}
+
+impl<'a, U: ?Sized> Pairs<'a, U> {
+    #[must_use]
+    pub(crate) fn new<A>(list: &'a DynList<U, A>) -> Self
+    where
+        A: Allocator,
+    {
+        let second_last = list.ends.and_then(|Ends { back, .. }| {
+            // SAFETY:
+            // As the node is in the list, its header must be properly initialised.
+            unsafe { back.header_ptr().as_ref() }.previous
+        });
+
+        Self {
+            raw: RawIter::from_front_to_node(list, second_last, list.len.saturating_sub(2)),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Gets the pair starting at `node`.
+    ///
+    /// # Safety
+    /// `node` must be one of the nodes from `self.raw`.
+    unsafe fn pair(node: Node<U>) -> (&'a U, &'a U) {
+        // SAFETY:
+        // As the node is in the list, its header must be properly initialised.
+        let next = unsafe { node.header_ptr().as_ref() }.next;
+        debug_assert!(next.is_some());
+        // SAFETY:
+        // `self.raw` does not include the back node, so every node in it has a next node.
+        let next = unsafe { next.unwrap_unchecked() };
+
+        // SAFETY:
+        // The nodes are in the list, so they have not been deallocated.
+        let first = unsafe { node.data_ptr() };
+        // SAFETY:
+        // As above.
+        let second = unsafe { next.data_ptr() };
+        // SAFETY:
+        // The nodes' values are initialised and there are no mutable references to them, as the
+        // iterator borrows the list.
+        (unsafe { first.as_ref() }, unsafe { second.as_ref() })
+    }
+}
+
+impl<'a, U: ?Sized> Iterator for Pairs<'a, U> {
+    type Item = (&'a U, &'a U);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.raw.next()?;
+        // SAFETY:
+        // The node is from `self.raw`.
+        Some(unsafe { Self::pair(node) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.raw.len();
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let node = self.raw.nth(n)?;
+        // SAFETY:
+        // The node is from `self.raw`.
+        Some(unsafe { Self::pair(node) })
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.raw.len()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<U: ?Sized> DoubleEndedIterator for Pairs<'_, U> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let node = self.raw.next_back()?;
+        // SAFETY:
+        // The node is from `self.raw`.
+        Some(unsafe { Self::pair(node) })
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let node = self.raw.nth_back(n)?;
+        // SAFETY:
+        // The node is from `self.raw`.
+        Some(unsafe { Self::pair(node) })
+    }
+}
+
+impl<U: ?Sized> ExactSizeIterator for Pairs<'_, U> {}
+
+impl<U: ?Sized> FusedIterator for Pairs<'_, U> {}
+
+impl<U: ?Sized> Clone for Pairs<'_, U> {
+    fn clone(&self) -> Self {
+        Self {
+            raw: self.raw.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use core::alloc::Allocator;
+
+use rayon::{
+    iter::{
+        plumbing::{Consumer, ProducerCallback, UnindexedConsumer},
+        IndexedParallelIterator, IntoParallelIterator, ParallelIterator,
+    },
+    vec,
+};
+
+use crate::{alloc::Vec, DynList};
+
+/// A parallel iterator over references to elements of a [`DynList`].
+///
+/// This is created by [`DynList::par_iter`].
+pub struct ParIter<'a, U>
+where
+    U: ?Sized + Sync,
+{
+    inner: vec::IntoIter<&'a U>,
+}
+
+/// A parallel iterator over mutable references to elements of a [`DynList`].
+///
+/// This is created by [`DynList::par_iter_mut`].
+pub struct ParIterMut<'a, U>
+where
+    U: ?Sized + Send,
+{
+    inner: vec::IntoIter<&'a mut U>,
+}
+
+impl<U, A> DynList<U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    #[must_use]
+    /// Creates a parallel iterator over references to the items in the list.
+    ///
+    /// As a linked list cannot be split without walking it, this collects a reference to each item into a temporary [`Vec`] first.
+    /// This takes *O*(n) time and memory up front, before any work is shared between threads.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut list = DynList::<u32>::new();
+    /// list.extend(1..=100);
+    ///
+    /// assert_eq!(list.par_iter().sum::<u32>(), 5050);
+    /// ```
+    pub fn par_iter(&self) -> ParIter<'_, U>
+    where
+        U: Sync,
+    {
+        let items: Vec<&U> = self.iter().collect();
+        ParIter {
+            inner: items.into_par_iter(),
+        }
+    }
+
+    #[must_use]
+    /// Creates a parallel iterator over mutable references to the items in the list.
+    ///
+    /// As a linked list cannot be split without walking it, this collects a reference to each item into a temporary [`Vec`] first.
+    /// This takes *O*(n) time and memory up front, before any work is shared between threads.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut list = DynList::<u32>::new();
+    /// list.extend(1..=3);
+    ///
+    /// list.par_iter_mut().for_each(|value| *value *= 2);
+    /// assert!(list.iter().eq(&[2, 4, 6]));
+    /// ```
+    pub fn par_iter_mut(&mut self) -> ParIterMut<'_, U>
+    where
+        U: Send,
+    {
+        let items: Vec<&mut U> = self.iter_mut().collect();
+        ParIterMut {
+            inner: items.into_par_iter(),
+        }
+    }
+}
+
+impl<'a, U> ParallelIterator for ParIter<'a, U>
+where
+    U: ?Sized + Sync,
+{
+    type Item = &'a U;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner.drive_unindexed(consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        self.inner.opt_len()
+    }
+}
+
+impl<U> IndexedParallelIterator for ParIter<'_, U>
+where
+    U: ?Sized + Sync,
+{
+    #[inline]
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        self.inner.drive(consumer)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        self.inner.with_producer(callback)
+    }
+}
+
+impl<'a, U> ParallelIterator for ParIterMut<'a, U>
+where
+    U: ?Sized + Send,
+{
+    type Item = &'a mut U;
+
+    #[inline]
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        self.inner.drive_unindexed(consumer)
+    }
+
+    #[inline]
+    fn opt_len(&self) -> Option<usize> {
+        self.inner.opt_len()
+    }
+}
+
+impl<U> IndexedParallelIterator for ParIterMut<'_, U>
+where
+    U: ?Sized + Send,
+{
+    #[inline]
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        self.inner.drive(consumer)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    #[inline]
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        self.inner.with_producer(callback)
+    }
+}
+
+impl<'a, U, A> IntoParallelIterator for &'a DynList<U, A>
+where
+    U: ?Sized + Sync,
+    A: Allocator,
+{
+    type Item = &'a U;
+    type Iter = ParIter<'a, U>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+impl<'a, U, A> IntoParallelIterator for &'a mut DynList<U, A>
+where
+    U: ?Sized + Send,
+    A: Allocator,
+{
+    type Item = &'a mut U;
+    type Iter = ParIterMut<'a, U>;
+
+    #[inline]
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rayon::prelude::*;
+
+    use crate::DynList;
+
+    #[test]
+    fn par_iter() {
+        let mut list = DynList::<str>::new();
+        list.push_back_copy_string("Hello");
+        list.push_back_copy_string(", ");
+        list.push_back_copy_string("World!");
+
+        let lengths: Vec<usize> = list.par_iter().map(str::len).collect();
+        assert_eq!(lengths, [5, 2, 6]);
+
+        let mut list = DynList::<u64>::new();
+        list.extend(0..1000);
+        (&mut list).into_par_iter().for_each(|value| *value += 1);
+        assert!(list.iter().copied().eq(1..1001));
+        assert_eq!(list.par_iter().len(), 1000);
+    }
+}
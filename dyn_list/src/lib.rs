@@ -16,13 +16,15 @@
 #[cfg(feature = "alloc")]
 mod alloc {
     extern crate alloc;
-    pub use alloc::{alloc::Global, boxed::Box};
+    pub use alloc::{alloc::Global, boxed::Box, string::String, vec::Vec};
 }
 
 use core::{
     alloc::Allocator,
     clone::CloneToUninit,
+    cmp::Ordering,
     fmt,
+    iter::Rev,
     marker::{PhantomData, Unsize},
     mem::{self, ManuallyDrop},
     ptr::{self, NonNull, Pointee},
@@ -31,18 +33,26 @@ use core::{
 mod any;
 mod array;
 pub mod cursor;
+mod dyn_clone;
+#[cfg(feature = "std")]
+mod io;
 pub mod iter;
 mod node;
+#[cfg(feature = "serde")]
+mod serde;
 mod sized;
+mod sort;
 mod string;
 
 use cursor::{Cursor, CursorMut};
-use dynode::AllocateError;
+pub use dyn_clone::DynClone;
+use dynode::{AllocateError, HeaderOpaqueNodePtr};
+use iter::{Drain, ExtractIf, Iter, IterMut, IterNodes, Pairs, RawIter};
 #[cfg(feature = "alloc")]
-use iter::IntoIterBoxed;
-use iter::{Iter, IterMut};
-pub use node::MaybeUninitNode;
+use iter::{DrainBoxed, ExtractIfBoxed, IntoIterBoxed};
 use node::{Header, Node};
+pub use node::{InitNode, MaybeUninitNode};
+pub use string::FromUtf8Error;
 
 struct Ends<U>
 where
@@ -61,6 +71,11 @@ where
 }
 impl<U> Copy for Ends<U> where U: ?Sized {}
 
+/// Pointers to the values of the front and back nodes of a [`DynList`], or [`None`] if it is empty.
+///
+/// This is returned by [`DynList::into_raw_parts`] and taken by [`DynList::from_raw_parts`].
+pub type RawEnds = Option<(NonNull<()>, NonNull<()>)>;
+
 /// A doubly-linked list that allows nodes with dynamically sized types.
 pub struct DynList<U, #[cfg(feature = "alloc")] A = alloc::Global, #[cfg(not(feature = "alloc"))] A>
 where
@@ -68,6 +83,7 @@ where
     A: Allocator,
 {
     ends: Option<Ends<U>>,
+    len: usize,
     allocator: A,
     _phantom: PhantomData<U>,
 }
@@ -83,34 +99,64 @@ where
     pub const fn new_in(allocator: A) -> Self {
         Self {
             ends: None,
+            len: 0,
             allocator,
             _phantom: PhantomData,
         }
     }
 
     #[must_use]
-    /// Decomposes the [`DynList`] into pointers to the front and back (if not empty), and the allocator.
-    pub fn into_raw_parts(self) -> (Option<(NonNull<()>, NonNull<()>)>, A) {
+    #[inline]
+    /// Returns the number of elements in the list.
+    ///
+    /// # Examples
+    /// ```
+    /// # use core::fmt::Debug;
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<dyn Debug>::new();
+    /// assert_eq!(list.len(), 0);
+    ///
+    /// list.push_back_unsize("Hello, World!");
+    /// list.push_front_unsize(5);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns [`true`] if the list contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        debug_assert!((self.len == 0) == self.ends.is_none());
+        self.len == 0
+    }
+
+    #[must_use]
+    /// Decomposes the [`DynList`] into pointers to the front and back (if not empty), the length and the allocator.
+    pub fn into_raw_parts(self) -> (RawEnds, usize, A) {
         let ends = self
             .ends
             .map(|Ends { front, back }| (front.value_ptr(), back.value_ptr()));
+        let len = self.len;
 
         let allocator = {
             let me = ManuallyDrop::new(self);
             unsafe { ptr::read(&me.allocator) }
         };
 
-        (ends, allocator)
+        (ends, len, allocator)
     }
 
     #[must_use]
     #[inline]
-    /// Creates a [`DynList`] from pointers to the front and back (if not empty), and an allocator.
+    /// Creates a [`DynList`] from pointers to the front and back (if not empty), the length and an allocator.
     ///
     /// # Safety
     /// - If the `ends` are not [`None`], they must have come from a call to [`Self::into_raw_parts`] with a `U` with the same layout and invariants.
+    /// - `len` must be the length returned alongside `ends` by [`Self::into_raw_parts`].
     /// - `allocator` must be valid for the nodes in the list.
-    pub unsafe fn from_raw_parts(ends: Option<(NonNull<()>, NonNull<()>)>, allocator: A) -> Self {
+    pub unsafe fn from_raw_parts(ends: RawEnds, len: usize, allocator: A) -> Self {
         let ends = ends.map(|(front, back)| Ends {
             front: unsafe { Node::from_value_ptr(front) },
             back: unsafe { Node::from_value_ptr(back) },
@@ -118,6 +164,7 @@ where
 
         Self {
             ends,
+            len,
             allocator,
             _phantom: PhantomData,
         }
@@ -140,6 +187,7 @@ where
         };
 
         unsafe { node::try_new(self, metadata, header) }
+            .map_err(|error| error.with_context("allocate_uninit_front"))
     }
 
     /// Attempts to allocate an uninitialised node at the back of the list.
@@ -159,6 +207,7 @@ where
         };
 
         unsafe { node::try_new(self, metadata, header) }
+            .map_err(|error| error.with_context("allocate_uninit_back"))
     }
 
     #[must_use]
@@ -196,10 +245,11 @@ where
         let metadata = ptr::metadata(&value as &U);
         let node = match unsafe { self.try_allocate_uninit_front(metadata) } {
             Ok(node) => node,
-            Err(error) => return Err(error.with_value(value)),
+            Err(error) => return Err(error.with_context("push_front_unsize").with_value(value)),
         };
-        unsafe { node.value_ptr().cast().write(value) };
-        unsafe { node.insert() };
+        // SAFETY:
+        // The node was allocated with the metadata of `value` as `U`.
+        unsafe { node.write_unsize(value) }.insert();
         Ok(())
     }
 
@@ -214,10 +264,11 @@ where
         let metadata = ptr::metadata(&value as &U);
         let node = match unsafe { self.try_allocate_uninit_back(metadata) } {
             Ok(node) => node,
-            Err(error) => return Err(error.with_value(value)),
+            Err(error) => return Err(error.with_context("push_back_unsize").with_value(value)),
         };
-        unsafe { node.value_ptr().cast().write(value) };
-        unsafe { node.insert() };
+        // SAFETY:
+        // The node was allocated with the metadata of `value` as `U`.
+        unsafe { node.write_unsize(value) }.insert();
         Ok(())
     }
 
@@ -236,8 +287,9 @@ where
     {
         let metadata = ptr::metadata(&value as &U);
         let node = unsafe { self.allocate_uninit_front(metadata) };
-        unsafe { node.value_ptr().cast().write(value) };
-        unsafe { node.insert() };
+        // SAFETY:
+        // The node was allocated with the metadata of `value` as `U`.
+        unsafe { node.write_unsize(value) }.insert();
     }
 
     /// Pushes `value` to the back of the list and unsizes it to `U`.
@@ -255,8 +307,87 @@ where
     {
         let metadata = ptr::metadata(&value as &U);
         let node = unsafe { self.allocate_uninit_back(metadata) };
-        unsafe { node.value_ptr().cast().write(value) };
+        // SAFETY:
+        // The node was allocated with the metadata of `value` as `U`.
+        unsafe { node.write_unsize(value) }.insert();
+    }
+
+    /// Attempts to push a clone of `value` to the front of the list.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    pub fn try_push_front_clone(&mut self, value: &U) -> Result<(), AllocateError>
+    where
+        U: CloneToUninit,
+    {
+        // SAFETY:
+        // The metadata is from a valid reference to `U`.
+        let node = unsafe { self.try_allocate_uninit_front(ptr::metadata(value)) }
+            .map_err(|error| error.with_context("push_front_clone"))?;
+        // SAFETY:
+        // The node was allocated with the metadata of `value`, so it is valid for writes of its layout.
+        unsafe { value.clone_to_uninit(node.value_ptr().cast().as_ptr()) };
+        // SAFETY:
+        // The value was initialised by `clone_to_uninit`.
+        unsafe { node.insert() };
+        Ok(())
+    }
+
+    /// Attempts to push a clone of `value` to the back of the list.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    pub fn try_push_back_clone(&mut self, value: &U) -> Result<(), AllocateError>
+    where
+        U: CloneToUninit,
+    {
+        // SAFETY:
+        // The metadata is from a valid reference to `U`.
+        let node = unsafe { self.try_allocate_uninit_back(ptr::metadata(value)) }
+            .map_err(|error| error.with_context("push_back_clone"))?;
+        // SAFETY:
+        // The node was allocated with the metadata of `value`, so it is valid for writes of its layout.
+        unsafe { value.clone_to_uninit(node.value_ptr().cast().as_ptr()) };
+        // SAFETY:
+        // The value was initialised by `clone_to_uninit`.
         unsafe { node.insert() };
+        Ok(())
+    }
+
+    /// Pushes a clone of `value` to the front of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_front_clone("Hello");
+    /// list.push_front_clone("World");
+    ///
+    /// assert!(list.iter().eq(["World", "Hello"]));
+    /// ```
+    pub fn push_front_clone(&mut self, value: &U)
+    where
+        U: CloneToUninit,
+    {
+        AllocateError::unwrap_result(self.try_push_front_clone(value));
+    }
+
+    /// Pushes a clone of `value` to the back of the list.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_clone("Hello");
+    /// list.push_back_clone("World");
+    ///
+    /// assert!(list.iter().eq(["Hello", "World"]));
+    /// ```
+    pub fn push_back_clone(&mut self, value: &U)
+    where
+        U: CloneToUninit,
+    {
+        AllocateError::unwrap_result(self.try_push_back_clone(value));
     }
 
     #[must_use]
@@ -299,6 +430,123 @@ where
         Some(unsafe { ptr.as_mut() })
     }
 
+    #[must_use]
+    /// Gets a reference to the element at `index`.
+    ///
+    /// This walks from whichever end of the list is nearer, so it is *O*(min(`index`, `len - index`)).
+    /// If `index` is out of bounds, this returns [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_copy_string("Hello,");
+    /// list.push_back_copy_string(" World!");
+    ///
+    /// assert_eq!(list.get(1), Some(" World!"));
+    /// assert_eq!(list.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&U> {
+        let node = self.node_at(index)?;
+        // SAFETY:
+        // As the node is in the list, its metadata must be properly initialised.
+        let ptr = unsafe { node.data_ptr() };
+        // SAFETY:
+        // As the node is in the list, its value must be properly initialised.
+        Some(unsafe { ptr.as_ref() })
+    }
+
+    #[must_use]
+    /// Gets a mutable reference to the element at `index`.
+    ///
+    /// This walks from whichever end of the list is nearer, so it is *O*(min(`index`, `len - index`)).
+    /// If `index` is out of bounds, this returns [`None`].
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut U> {
+        let node = self.node_at(index)?;
+        // SAFETY:
+        // As the node is in the list, its metadata must be properly initialised.
+        let mut ptr = unsafe { node.data_ptr() };
+        // SAFETY:
+        // As the node is in the list, its value must be properly initialised.
+        // We have a mutable reference to the list, so there are no other references to the value.
+        Some(unsafe { ptr.as_mut() })
+    }
+
+    #[must_use]
+    /// Returns [`true`] if the list contains an element equal to `value`.
+    ///
+    /// This stops at the first match, but is still *O*(n) in the worst case.
+    /// As `value` can be of a different type, a `DynList<str>` can be searched with a `&str`, or a `DynList<[u8]>` with a `&[u8]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<i32>::new();
+    /// list.extend([1, 2, 3]);
+    ///
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&4));
+    ///
+    /// let mut list = DynList::<dyn PartialEq<u8>>::new();
+    /// list.push_back_unsize(1u8);
+    /// list.push_back_unsize(2u8);
+    ///
+    /// assert!(list.contains(&1));
+    /// assert!(!list.contains(&3));
+    /// ```
+    pub fn contains<X>(&self, value: &X) -> bool
+    where
+        U: PartialEq<X>,
+        X: ?Sized,
+    {
+        self.iter().any(|element| element == value)
+    }
+
+    /// Finds the index of the first element for which `predicate` returns [`true`].
+    ///
+    /// This scans from the front and stops at the first match, so it takes *O*(n) time in the worst case.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<str>::from_iter(["a", "bc", "de"]);
+    /// assert_eq!(list.position(|s| s.len() == 2), Some(1));
+    /// assert_eq!(list.position(str::is_empty), None);
+    /// ```
+    pub fn position<F>(&self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&U) -> bool,
+    {
+        self.iter().position(predicate)
+    }
+
+    /// Finds the first element for which `predicate` returns [`true`].
+    ///
+    /// This scans from the front and stops at the first match, so it takes *O*(n) time in the worst case.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<str>::from_iter(["a", "bc", "de"]);
+    /// assert_eq!(list.find(|s| s.len() == 2), Some("bc"));
+    /// ```
+    pub fn find<F>(&self, mut predicate: F) -> Option<&U>
+    where
+        F: FnMut(&U) -> bool,
+    {
+        self.iter().find(|element| predicate(element))
+    }
+
+    /// Finds the first element for which `predicate` returns [`true`] and returns a mutable reference to it.
+    ///
+    /// See [`Self::find`] for more information.
+    pub fn find_mut<F>(&mut self, mut predicate: F) -> Option<&mut U>
+    where
+        F: FnMut(&U) -> bool,
+    {
+        self.iter_mut().find(|element| predicate(element))
+    }
+
     #[must_use]
     /// Removes the front node of the list.
     /// If you do not want a [`MaybeUninitNode`], this is the wrong function!
@@ -320,6 +568,7 @@ where
             debug_assert_eq!(*back, node);
             self.ends = None;
         }
+        self.len -= 1;
 
         Some(unsafe { dynode::new_maybe_uninit(self, node.into()) })
     }
@@ -345,6 +594,7 @@ where
             debug_assert_eq!(*front, node);
             self.ends = None;
         }
+        self.len -= 1;
 
         Some(unsafe { dynode::new_maybe_uninit(self, node.into()) })
     }
@@ -391,125 +641,1326 @@ where
             .is_some()
     }
 
-    #[cfg(feature = "alloc")]
-    #[must_use]
-    #[inline]
-    /// Attempts to remove the front node and return it in a [`Box`].
+    /// Deletes and drops all nodes in the list.
     ///
-    /// # Errors
-    /// If allocation fails, this will return an [`AllocateError`].
-    /// The node will be deleted.
-    pub fn try_pop_front_boxed(&mut self) -> Option<Result<alloc::Box<U, A>, AllocateError>>
-    where
-        A: Clone,
-    {
-        self.pop_front_node().map(|front| {
-            unsafe { front.try_take_boxed() }.map_err(|error| {
-                let (front, error) = error.into_parts();
-                unsafe { front.insert() };
-                error
-            })
-        })
+    /// The elements are dropped from front to back, as they are when the list is dropped.
+    /// See [`Self::clear_back_to_front`] to drop them in the opposite order.
+    /// The list remains usable afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// # use core::fmt::Debug;
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<dyn Debug>::new();
+    /// list.push_back_unsize("Hello, World!");
+    /// list.push_back_unsize(5);
+    ///
+    /// list.clear();
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.delete_until(0, Self::delete_front);
     }
 
-    #[cfg(feature = "alloc")]
-    #[must_use]
-    #[inline]
-    /// Attempts to remove the back node and return it in a [`Box`].
+    /// Deletes and drops all nodes in the list, from back to front.
     ///
-    /// # Errors
-    /// If allocation fails, this will return an [`AllocateError`].
-    /// The node will be deleted.
-    pub fn try_pop_back_boxed(&mut self) -> Option<Result<alloc::Box<U, A>, AllocateError>>
-    where
-        A: Clone,
-    {
-        self.pop_back_node().map(|front| {
-            unsafe { front.try_take_boxed() }.map_err(|error| {
-                let (front, error) = error.into_parts();
-                unsafe { front.insert() };
-                error
-            })
-        })
+    /// This is useful when elements depend on the ones before them, such as handles that must be released in the opposite order to which they were acquired.
+    /// Dropping the list still drops its elements from front to back, so call this first if the order matters.
+    /// The list remains usable afterwards.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// use std::cell::RefCell;
+    ///
+    /// struct Handle<'a>(u8, &'a RefCell<Vec<u8>>);
+    /// impl Drop for Handle<'_> {
+    ///     fn drop(&mut self) {
+    ///         self.1.borrow_mut().push(self.0);
+    ///     }
+    /// }
+    ///
+    /// let released = RefCell::new(Vec::new());
+    /// let mut list = DynList::new();
+    /// list.extend((1..=3).map(|id| Handle(id, &released)));
+    ///
+    /// list.clear_back_to_front();
+    /// assert_eq!(*released.borrow(), [3, 2, 1]);
+    /// ```
+    pub fn clear_back_to_front(&mut self) {
+        self.delete_until(0, Self::delete_back);
+    }
+
+    /// Calls `delete` until the list has `len` elements or it returns [`false`], continuing if a destructor panics.
+    fn delete_until(&mut self, len: usize, delete: fn(&mut Self) -> bool) {
+        // Based on https://doc.rust-lang.org/1.82.0/src/alloc/collections/linked_list.rs.html#1169-1186
+        struct DropGuard<'a, U: ?Sized, A: Allocator> {
+            list: &'a mut DynList<U, A>,
+            len: usize,
+            delete: fn(&mut DynList<U, A>) -> bool,
+        }
+
+        impl<U: ?Sized, A: Allocator> Drop for DropGuard<'_, U, A> {
+            // https://doc.rust-lang.org/1.82.0/src/alloc/collections/linked_list.rs.html#1175-1176
+            // Continue the same loop we do below. This only runs when a destructor has
+            // panicked. If another one panics this will abort.
+            fn drop(&mut self) {
+                while self.list.len > self.len && (self.delete)(self.list) {}
+            }
+        }
+
+        // https://doc.rust-lang.org/1.82.0/src/alloc/collections/linked_list.rs.html#1181
+        // Wrap self so that if a destructor panics, we can try to keep looping
+        let guard = DropGuard {
+            list: self,
+            len,
+            delete,
+        };
+        while guard.list.len > guard.len && (guard.delete)(guard.list) {}
+        mem::forget(guard);
     }
 
-    #[cfg(feature = "alloc")]
     #[must_use]
     #[inline]
-    /// Removes the front node and returns it in a [`Box`].
+    /// Takes all of the elements out of the list, leaving it empty with a default allocator.
+    ///
+    /// No elements are moved or copied, so this is *O*(1).
+    /// For lists in the global allocator, this is the same as [`mem::take`](core::mem::take), and [`mem::swap`](core::mem::swap) can be used to exchange two lists.
     ///
+    /// # Examples
     /// ```
-    /// # use core::cmp::PartialEq;
     /// # use dyn_list::DynList;
-    /// let mut list = DynList::<dyn PartialEq<u8>>::new();
-    /// list.push_back_unsize(5);
-    /// assert!(&*list.pop_front_boxed().unwrap() == &5_u8);
+    /// let mut list = DynList::<u8>::from_iter([1, 2, 3]);
+    /// let taken = list.take();
+    ///
+    /// assert!(list.is_empty());
+    /// assert!(taken.iter().eq(&[1, 2, 3]));
     /// ```
-    pub fn pop_front_boxed(&mut self) -> Option<alloc::Box<U, A>>
+    pub fn take(&mut self) -> Self
     where
-        A: Clone,
+        A: Default,
     {
-        self.try_pop_front_boxed().map(AllocateError::unwrap_result)
+        mem::replace(self, Self::new_in(A::default()))
     }
 
-    #[cfg(feature = "alloc")]
     #[must_use]
     #[inline]
-    /// Removes the back node and returns it in a [`Box`].
+    /// Takes all of the elements out of the list, leaving it empty with a clone of its allocator.
     ///
-    /// ```
-    /// # use core::cmp::PartialEq;
-    /// # use dyn_list::DynList;
-    /// let mut list = DynList::<dyn PartialEq<u8>>::new();
-    /// list.push_back_unsize(5);
-    /// assert!(&*list.pop_back_boxed().unwrap() == &5_u8);
-    /// ```
-    pub fn pop_back_boxed(&mut self) -> Option<alloc::Box<U, A>>
+    /// The returned list keeps the original allocator, which owns the nodes.
+    /// No elements are moved or copied, so this is *O*(1).
+    pub fn replace_with_empty(&mut self) -> Self
     where
         A: Clone,
     {
-        self.try_pop_back_boxed().map(AllocateError::unwrap_result)
+        let allocator = self.allocator.clone();
+        mem::replace(self, Self::new_in(allocator))
     }
 
-    #[must_use]
-    #[inline]
-    /// Creates a [`Cursor`] at the front of the list.
+    /// Shortens the list, keeping the first `len` elements and dropping the rest.
     ///
-    /// If the list is empty, this will point to the "ghost" element.
-    pub const fn cursor_front(&self) -> Cursor<U, A> {
-        // Using match rather than map to allow function to be const
-        let current = match self.ends {
-            Some(Ends { front, .. }) => Some(front),
-            None => None,
-        };
-
-        Cursor {
-            current,
-            list: self,
-        }
+    /// The elements are dropped from the back of the list and their nodes are deallocated.
+    /// If `len` is greater than or equal to the list's length, this does nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<i32>::new();
+    /// list.extend([1, 2, 3, 4]);
+    ///
+    /// list.truncate(2);
+    /// assert!(list.iter().eq(&[1, 2]));
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        self.delete_until(len, Self::delete_back);
     }
 
-    #[must_use]
-    #[inline]
-    /// Creates a [`Cursor`] at the back of the list.
+    /// Moves all nodes from `other` to the back of the list.
     ///
-    /// If the list is empty, this will point to the "ghost" element.
-    pub const fn cursor_back(&self) -> Cursor<U, A> {
-        // Using match rather than map to allow function to be const
-        let current = match self.ends {
-            Some(Ends { back, .. }) => Some(back),
-            None => None,
+    /// This reuses the nodes from `other`, leaving it empty.
+    /// No allocation or copying takes place, so this is *O*(1).
+    ///
+    /// The nodes from `other` are deallocated with `self`'s allocator once they are removed.
+    /// As with [`LinkedList::append`](https://doc.rust-lang.org/std/collections/struct.LinkedList.html#method.append), both lists have the same allocator type, which is assumed to mean that either allocator can deallocate the other's nodes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_copy_string("Hello,");
+    ///
+    /// let mut other = DynList::<str>::new();
+    /// other.push_back_copy_string(" World!");
+    ///
+    /// list.append(&mut other);
+    /// assert!(other.is_empty());
+    /// assert_eq!(list.len(), 2);
+    /// assert_eq!(list.back(), Some(" World!"));
+    /// ```
+    pub fn append(&mut self, other: &mut Self) {
+        let Some(other_ends) = other.ends.take() else {
+            return;
         };
+        let other_len = mem::take(&mut other.len);
 
-        Cursor {
-            current,
-            list: self,
-        }
+        let back = self.ends.map(|Ends { back, .. }| back);
+        // SAFETY:
+        // `back` is the back of the list and `other_ends` are the ends of a different list.
+        unsafe { self.splice_between(back, None, other_ends, other_len) };
+
+        #[cfg(test)]
+        self.check_debug();
     }
 
-    #[must_use]
-    #[inline]
-    /// Creates a [`CursorMut`] at the front of the list that can mutate the list.
+    /// Moves all elements from a list of sized values to the back of the list, unsizing them.
+    ///
+    /// Unlike [`Self::append`], this cannot reuse `other`'s nodes, as unsized nodes store the value's metadata before it.
+    /// Instead, each element is moved into a newly allocated node and `other`'s node is deallocated, so this takes *O*(n) time and allocates once per element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// use core::fmt::Display;
+    ///
+    /// let mut list = DynList::<dyn Display>::new();
+    /// list.push_back_unsize(1);
+    ///
+    /// let strings = DynList::<String>::from_iter([String::from("a"), String::from("b")]);
+    /// list.append_unsize(strings);
+    ///
+    /// assert!(list.iter().map(ToString::to_string).eq(["1", "a", "b"]));
+    /// ```
+    pub fn append_unsize<T, A2>(&mut self, other: DynList<T, A2>)
+    where
+        T: Unsize<U>,
+        A2: Allocator,
+    {
+        self.extend_unsize(other);
+    }
+
+    /// Retains only the elements for which `f` returns [`true`].
+    ///
+    /// The other elements are dropped and their nodes are deallocated.
+    /// The elements are visited once each, in order from front to back.
+    ///
+    /// If `f` or an element's destructor panics, the list is left in a valid state, containing the
+    /// elements that were retained or not yet visited.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_copy_string("Hello");
+    /// list.push_back_copy_string("");
+    /// list.push_back_copy_string("World");
+    ///
+    /// list.retain(|s| !s.is_empty());
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&U) -> bool,
+    {
+        self.retain_mut(|value| f(value));
+    }
+
+    /// Retains only the elements for which `f` returns [`true`], passing a mutable reference to each element.
+    ///
+    /// See [`Self::retain`] for more information.
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut U) -> bool,
+    {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(current) = cursor.current() {
+            if !f(current) {
+                // This moves the cursor to the previous element, so the next move visits the
+                // element after the deleted one
+                cursor.delete_current();
+            }
+            cursor.move_next();
+        }
+    }
+
+    /// Removes every element that is equal to `value`, returning how many were removed.
+    ///
+    /// The removed elements are dropped and their nodes are deallocated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::from_iter(["a", "b", "a", "c"]);
+    ///
+    /// assert_eq!(list.remove_all("a"), 2);
+    /// assert!(list.iter().eq(["b", "c"]));
+    /// ```
+    pub fn remove_all<X>(&mut self, value: &X) -> usize
+    where
+        U: PartialEq<X>,
+        X: ?Sized,
+    {
+        let mut removed = 0;
+        let mut cursor = self.cursor_front_mut();
+        while let Some(current) = cursor.current() {
+            if *current == *value {
+                // This moves the cursor to the previous element, so the next move visits the
+                // element after the deleted one
+                cursor.delete_current();
+                removed += 1;
+            }
+            cursor.move_next();
+        }
+
+        removed
+    }
+
+    /// Removes consecutive repeated elements, keeping the first of each run.
+    ///
+    /// The removed elements are dropped and their nodes are deallocated.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::from_iter(["a", "a", "b", "a", "a", "a"]);
+    ///
+    /// list.dedup();
+    /// assert!(list.iter().eq(["a", "b", "a"]));
+    /// ```
+    pub fn dedup(&mut self)
+    where
+        U: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns [`true`], keeping the first of each run.
+    ///
+    /// `same_bucket` is passed each element and the last element that was kept before it, in that order.
+    /// If it returns [`true`], the element is dropped and its node is deallocated.
+    ///
+    /// If `same_bucket` or an element's destructor panics, the list is left in a valid state.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::from_iter(["foo", "FOO", "bar", "Bar", "foo"]);
+    ///
+    /// list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert!(list.iter().eq(["foo", "bar", "foo"]));
+    /// ```
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut U, &mut U) -> bool,
+    {
+        let mut cursor = self.cursor_front_mut();
+        cursor.move_next();
+
+        while let Some(current) = cursor.current {
+            // SAFETY:
+            // As the node is in the list, its header must be properly initialised.
+            let previous = unsafe { current.header_ptr().as_ref() }.previous;
+            debug_assert!(previous.is_some());
+            // SAFETY:
+            // The cursor started after the front, so there is a node before the current one.
+            let previous = unsafe { previous.unwrap_unchecked() };
+
+            // SAFETY:
+            // As the nodes are in the list, their metadata must be properly initialised.
+            let mut current_ptr = unsafe { current.data_ptr() };
+            // SAFETY:
+            // As above.
+            let mut previous_ptr = unsafe { previous.data_ptr() };
+            // SAFETY:
+            // As the node is in the list, its value must be properly initialised.
+            let current_value = unsafe { current_ptr.as_mut() };
+            // SAFETY:
+            // As above, and as it is a different node, the references do not alias.
+            let previous_value = unsafe { previous_ptr.as_mut() };
+            let same = same_bucket(current_value, previous_value);
+
+            if same {
+                // This moves the cursor to the previous element, so the next move visits the
+                // element after the deleted one
+                cursor.delete_current();
+            }
+            cursor.move_next();
+        }
+    }
+
+    /// Creates an iterator that removes and yields all of the elements, from front to back.
+    ///
+    /// Unlike [`IntoIterator::into_iter`], this leaves the list in place, so it can be reused.
+    /// If the iterator is dropped before it is exhausted, the remaining elements are removed and dropped.
+    /// If the iterator is leaked, the remaining elements are left in the list.
+    ///
+    /// The iterator only yields elements for sized `U`; see [`Self::drain_boxed`] for unsized elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::from_iter([1, 2, 3]);
+    ///
+    /// assert_eq!(list.drain().collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert!(list.is_empty());
+    ///
+    /// list.push_back(4);
+    /// assert_eq!(list.drain().next_back(), Some(4));
+    /// ```
+    pub const fn drain(&mut self) -> Drain<'_, U, A> {
+        Drain::new(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Creates an iterator that removes all of the elements and yields them in boxes.
+    ///
+    /// See [`Self::drain`] for more information.
+    pub const fn drain_boxed(&mut self) -> DrainBoxed<'_, U, A>
+    where
+        A: Clone,
+    {
+        DrainBoxed::new(self)
+    }
+
+    /// Creates an iterator that removes and yields the elements for which `f` returns [`true`].
+    ///
+    /// The elements are visited once each, in order from front to back, and `f` may mutate them.
+    /// If the iterator is dropped before it is exhausted, the remaining matching elements are removed and dropped.
+    /// If `f` panics, no more elements are removed.
+    ///
+    /// The iterator only yields elements for sized `U`; see [`Self::extract_if_boxed`] for unsized elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::new();
+    /// list.extend([1, 2, 3, 4, 5]);
+    ///
+    /// let evens = list.extract_if(|value| *value % 2 == 0).collect::<Vec<_>>();
+    /// assert_eq!(evens, [2, 4]);
+    /// assert_eq!(list.len(), 3);
+    /// ```
+    pub const fn extract_if<F>(&mut self, f: F) -> ExtractIf<'_, U, A, F>
+    where
+        F: FnMut(&mut U) -> bool,
+    {
+        ExtractIf::new(self.cursor_front_mut(), f)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Creates an iterator that removes the elements for which `f` returns [`true`] and yields them in boxes.
+    ///
+    /// See [`Self::extract_if`] for more information.
+    pub const fn extract_if_boxed<F>(&mut self, f: F) -> ExtractIfBoxed<'_, U, A, F>
+    where
+        A: Clone,
+        F: FnMut(&mut U) -> bool,
+    {
+        ExtractIfBoxed::new(self.cursor_front_mut(), f)
+    }
+
+    #[must_use]
+    /// Splits the list into two at the given index.
+    ///
+    /// Returns a list containing the elements from `at` onwards, leaving the elements before `at` in `self`.
+    /// The nodes are moved into the returned list without allocating or copying.
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<[u8]>::new();
+    /// list.push_back_unsize([0]);
+    /// list.push_back_unsize([1, 1]);
+    /// list.push_back_unsize([2, 2, 2]);
+    ///
+    /// let tail = list.split_off(1);
+    /// assert_eq!(list.len(), 1);
+    /// assert_eq!(tail.len(), 2);
+    /// assert_eq!(tail.front(), Some([1, 1].as_slice()));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self
+    where
+        A: Clone,
+    {
+        let len = self.len;
+        assert!(
+            at <= len,
+            "cannot split off at index {at} in a list of length {len}"
+        );
+
+        if at == 0 {
+            let allocator = self.allocator.clone();
+            return mem::replace(self, Self::new_in(allocator));
+        }
+
+        let node = self.node_at(at - 1);
+        debug_assert!(node.is_some());
+        // SAFETY:
+        // `at - 1` is less than the length, so there is a node at that index.
+        let node = unsafe { node.unwrap_unchecked() };
+        // SAFETY:
+        // `node` is in the list and there are `at` nodes up to and including it.
+        unsafe { self.split_after_node(node, at) }
+    }
+
+    /// Attempts to split the list into two at the given index, cloning the elements from `at` onwards into a new list in the given allocator.
+    ///
+    /// See [`Self::split_off_clone_in`] for more information.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`] with the partially built list in it, and `self` is left unchanged.
+    /// This contains clones of the elements from `at` up to the one that failed, in order; use [`AllocateError::into_value`] to get it.
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`.
+    pub fn try_split_off_clone_in<A2>(
+        &mut self,
+        at: usize,
+        allocator: A2,
+    ) -> Result<DynList<U, A2>, AllocateError<DynList<U, A2>>>
+    where
+        U: CloneToUninit,
+        A2: Allocator,
+    {
+        let len = self.len;
+        assert!(
+            at <= len,
+            "cannot split off at index {at} in a list of length {len}"
+        );
+
+        let mut tail = DynList::new_in(allocator);
+        for item in self.iter().skip(at) {
+            if let Err(error) = tail.try_push_back_clone(item) {
+                return Err(error.with_context("split_off_clone_in").with_value(tail));
+            }
+        }
+        self.truncate(at);
+        Ok(tail)
+    }
+
+    #[must_use]
+    /// Splits the list into two at the given index, cloning the elements from `at` onwards into a new list in the given allocator.
+    ///
+    /// Nodes cannot be moved between allocators, so unlike [`Self::split_off`], which relinks the nodes in *O*(1) after finding the split point, this allocates a new node for each element, clones the element into it and then drops the original.
+    /// This works for unsized elements; for sized elements, [`DynList::split_off_in`] moves them instead of cloning.
+    /// If cloning panics, `self` is left unchanged and the partly built list is dropped.
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use std::alloc::Global;
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_copy_string("a");
+    /// list.push_back_copy_string("bc");
+    /// list.push_back_copy_string("def");
+    ///
+    /// let tail: DynList<str, Global> = list.split_off_clone_in(1, Global);
+    /// assert!(list.iter().eq(["a"]));
+    /// assert!(tail.iter().eq(["bc", "def"]));
+    /// ```
+    pub fn split_off_clone_in<A2>(&mut self, at: usize, allocator: A2) -> DynList<U, A2>
+    where
+        U: CloneToUninit,
+        A2: Allocator,
+    {
+        AllocateError::unwrap_result(self.try_split_off_clone_in(at, allocator))
+    }
+
+    #[must_use]
+    /// Splits the list before the first element for which `pred` returns [`true`].
+    ///
+    /// Returns a list starting with that element, leaving the elements before it in `self`.
+    /// If no element matches, this returns an empty list and `self` is unchanged.
+    /// As with [`Self::split_off`], the nodes are moved into the returned list without allocating or copying.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_copy_string("a");
+    /// list.push_back_copy_string("b");
+    /// list.push_back_copy_string("");
+    /// list.push_back_copy_string("c");
+    ///
+    /// let tail = list.split_when(str::is_empty);
+    /// assert!(list.iter().eq(["a", "b"]));
+    /// assert!(tail.iter().eq(["", "c"]));
+    /// ```
+    pub fn split_when<F>(&mut self, mut pred: F) -> Self
+    where
+        A: Clone,
+        F: FnMut(&U) -> bool,
+    {
+        let mut nodes = RawIter::from_list(self);
+        let mut previous = None;
+        let mut len = 0;
+
+        while let Some(node) = nodes.next() {
+            // SAFETY:
+            // As the node is in the list, its metadata must be properly initialised.
+            let ptr = unsafe { node.data_ptr() };
+            // SAFETY:
+            // As the node is in the list, its value must be properly initialised.
+            if pred(unsafe { ptr.as_ref() }) {
+                return match previous {
+                    None => {
+                        let allocator = self.allocator.clone();
+                        mem::replace(self, Self::new_in(allocator))
+                    }
+                    // SAFETY:
+                    // `previous` is in the list and there are `len` nodes up to and including it.
+                    Some(previous) => unsafe { self.split_after_node(previous, len) },
+                };
+            }
+
+            previous = Some(node);
+            len += 1;
+        }
+
+        Self::new_in(self.allocator.clone())
+    }
+
+    #[must_use]
+    /// Removes the elements from the front of the list for as long as `pred` returns [`true`], returning them as a new list.
+    ///
+    /// This stops at the first element for which `pred` returns [`false`], which is left in `self`.
+    /// As with [`Self::split_off`], the nodes are moved into the returned list without allocating or copying.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut queue = DynList::<i32>::new();
+    /// queue.extend([1, 2, 5, 3]);
+    ///
+    /// let ready = queue.pop_front_while(|&value| value < 4);
+    /// assert!(ready.iter().eq(&[1, 2]));
+    /// assert!(queue.iter().eq(&[5, 3]));
+    /// ```
+    pub fn pop_front_while<F>(&mut self, mut pred: F) -> Self
+    where
+        A: Clone,
+        F: FnMut(&U) -> bool,
+    {
+        let mut nodes = RawIter::from_list(self);
+        let mut previous = None;
+        let mut len = 0;
+
+        while let Some(node) = nodes.next() {
+            // SAFETY:
+            // As the node is in the list, its metadata must be properly initialised.
+            let ptr = unsafe { node.data_ptr() };
+            // SAFETY:
+            // As the node is in the list, its value must be properly initialised.
+            if !pred(unsafe { ptr.as_ref() }) {
+                let Some(previous) = previous else {
+                    return Self::new_in(self.allocator.clone());
+                };
+                // SAFETY:
+                // `previous` is in the list and there are `len` nodes up to and including it.
+                let tail = unsafe { self.split_after_node(previous, len) };
+                return mem::replace(self, tail);
+            }
+
+            previous = Some(node);
+            len += 1;
+        }
+
+        let allocator = self.allocator.clone();
+        mem::replace(self, Self::new_in(allocator))
+    }
+
+    #[must_use]
+    /// Removes the elements from the back of the list for as long as `pred` returns [`true`], returning them as a new list.
+    ///
+    /// This stops at the first element from the back for which `pred` returns [`false`], which is left in `self`.
+    /// The returned list keeps the elements in the same order as they were in `self`.
+    /// As with [`Self::split_off`], the nodes are moved into the returned list without allocating or copying.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_copy_string("a");
+    /// list.push_back_copy_string("");
+    /// list.push_back_copy_string("b");
+    /// list.push_back_copy_string("c");
+    ///
+    /// let tail = list.pop_back_while(|s| !s.is_empty());
+    /// assert!(list.iter().eq(["a", ""]));
+    /// assert!(tail.iter().eq(["b", "c"]));
+    /// ```
+    pub fn pop_back_while<F>(&mut self, mut pred: F) -> Self
+    where
+        A: Clone,
+        F: FnMut(&U) -> bool,
+    {
+        let mut nodes = RawIter::from_list(self);
+        let mut len = self.len;
+
+        while let Some(node) = nodes.next_back() {
+            // SAFETY:
+            // As the node is in the list, its metadata must be properly initialised.
+            let ptr = unsafe { node.data_ptr() };
+            // SAFETY:
+            // As the node is in the list, its value must be properly initialised.
+            if !pred(unsafe { ptr.as_ref() }) {
+                // SAFETY:
+                // `node` is in the list and there are `len` nodes up to and including it.
+                return unsafe { self.split_after_node(node, len) };
+            }
+
+            len -= 1;
+        }
+
+        let allocator = self.allocator.clone();
+        mem::replace(self, Self::new_in(allocator))
+    }
+
+    /// Takes the ends and length out of the list without dropping the nodes.
+    ///
+    /// If the list is empty, this returns [`None`].
+    fn take_ends(mut self) -> Option<(Ends<U>, usize)> {
+        let ends = self.ends.take()?;
+        Some((ends, mem::take(&mut self.len)))
+    }
+
+    /// Rotates the list to the left by `n` elements.
+    ///
+    /// The first `n` elements are moved to the back of the list, keeping their order.
+    /// If `n` is greater than the length of the list, it wraps around, so this is the same as rotating by `n % len`.
+    /// If the list is empty, this does nothing.
+    ///
+    /// No allocation or copying takes place, but finding the new front takes *O*(min(`n`, `len - n`)).
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::new();
+    /// list.extend([0, 1, 2, 3, 4]);
+    ///
+    /// list.rotate_left(2);
+    /// assert!(list.iter().eq(&[2, 3, 4, 0, 1]));
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+
+        self.rotate_to_front(n % self.len);
+    }
+
+    /// Rotates the list to the right by `n` elements.
+    ///
+    /// The last `n` elements are moved to the front of the list, keeping their order.
+    /// If `n` is greater than the length of the list, it wraps around, so this is the same as rotating by `n % len`.
+    /// If the list is empty, this does nothing.
+    ///
+    /// No allocation or copying takes place, but finding the new front takes *O*(min(`n`, `len - n`)).
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::new();
+    /// list.extend([0, 1, 2, 3, 4]);
+    ///
+    /// list.rotate_right(2);
+    /// assert!(list.iter().eq(&[3, 4, 0, 1, 2]));
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.len == 0 {
+            return;
+        }
+
+        let n = n % self.len;
+        if n != 0 {
+            self.rotate_to_front(self.len - n);
+        }
+    }
+
+    /// Swaps the elements at indices `a` and `b`.
+    ///
+    /// This swaps the positions of the nodes in the list rather than the bytes of their values, so elements with different layouts, like slices of different lengths, can be swapped.
+    /// No allocation or copying takes place, but finding the nodes takes *O*(n).
+    /// To swap neighbouring elements in *O*(1), use [`CursorMut::swap_with_next`].
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` are out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<[u8]>::new();
+    /// list.push_back_copy_array(&[1]);
+    /// list.push_back_copy_array(&[2, 3]);
+    /// list.push_back_copy_array(&[4, 5, 6]);
+    ///
+    /// list.swap(0, 2);
+    /// assert!(list.iter().eq([&[4, 5, 6][..], &[2, 3], &[1]]));
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let (Some(first), Some(second)) = (self.node_at(a.min(b)), self.node_at(a.max(b))) else {
+            panic!(
+                "swap indices (is {a} and {b}) should be < len (is {})",
+                self.len
+            );
+        };
+
+        if a != b {
+            // SAFETY:
+            // Both nodes are in the list, and `first` is before `second`.
+            unsafe { self.swap_nodes(first, second) };
+        }
+    }
+
+    /// Relinks the list so that the node at `index` becomes the front.
+    fn rotate_to_front(&mut self, index: usize) {
+        if index == 0 {
+            return;
+        }
+        let Some(new_front) = self.node_at(index) else {
+            return;
+        };
+        debug_assert!(self.ends.is_some());
+        // SAFETY:
+        // There is a node at `index`, so the list is not empty.
+        let Ends { front, back } = unsafe { self.ends.as_mut().unwrap_unchecked() };
+
+        // SAFETY:
+        // As we have a mutable reference to the list, the node's header is not aliased and is
+        // valid for reads and writes.
+        let new_front_header = unsafe { new_front.header_ptr().as_mut() };
+        debug_assert!(new_front_header.previous.is_some());
+        // SAFETY:
+        // The new front is not at index 0, so there is a node before it.
+        let new_back = unsafe { new_front_header.previous.take().unwrap_unchecked() };
+
+        // SAFETY:
+        // As above.
+        let new_back_header = unsafe { new_back.header_ptr().as_mut() };
+        new_back_header.next = None;
+
+        // SAFETY:
+        // As above.
+        let back_header = unsafe { back.header_ptr().as_mut() };
+        debug_assert!(back_header.next.is_none());
+        back_header.next = Some(*front);
+
+        // SAFETY:
+        // As above.
+        let front_header = unsafe { front.header_ptr().as_mut() };
+        debug_assert!(front_header.previous.is_none());
+        front_header.previous = Some(*back);
+
+        *front = new_front;
+        *back = new_back;
+    }
+
+    /// Gets the node at `index`, walking from the nearer end of the list.
+    ///
+    /// If `index` is out of bounds, this returns [`None`].
+    fn node_at(&self, index: usize) -> Option<Node<U>> {
+        if index >= self.len {
+            return None;
+        }
+        let Ends { front, back } = self.ends?;
+
+        let node = if index < self.len / 2 {
+            let mut node = front;
+            for _ in 0..index {
+                // SAFETY:
+                // As the node is in the list, its header must be properly initialised.
+                let header = unsafe { node.header_ptr().as_ref() };
+                debug_assert!(header.next.is_some());
+                // SAFETY:
+                // `index` is in bounds, so there are at least `index` nodes after the front.
+                node = unsafe { header.next.unwrap_unchecked() };
+            }
+            node
+        } else {
+            let mut node = back;
+            for _ in index + 1..self.len {
+                // SAFETY:
+                // As the node is in the list, its header must be properly initialised.
+                let header = unsafe { node.header_ptr().as_ref() };
+                debug_assert!(header.previous.is_some());
+                // SAFETY:
+                // `index` is in bounds, so there are at least `len - index - 1` nodes before the
+                // back.
+                node = unsafe { header.previous.unwrap_unchecked() };
+            }
+            node
+        };
+
+        Some(node)
+    }
+
+    /// Splits the list after `node`, returning a list of the nodes after it.
+    ///
+    /// # Safety
+    /// - `node` must be in the list.
+    /// - `len` must be the number of nodes from the front up to and including `node`.
+    unsafe fn split_after_node(&mut self, node: Node<U>, len: usize) -> Self
+    where
+        A: Clone,
+    {
+        let allocator = self.allocator.clone();
+
+        // SAFETY:
+        // As we have a mutable reference to the list, the node's header is not aliased and is
+        // valid for reads and writes.
+        let header = unsafe { node.header_ptr().as_mut() };
+        let Some(next) = header.next.take() else {
+            return Self::new_in(allocator);
+        };
+
+        // SAFETY:
+        // As above, `next`'s header is not aliased and is valid for reads and writes.
+        let next_header = unsafe { next.header_ptr().as_mut() };
+        debug_assert_eq!(next_header.previous, Some(node));
+        next_header.previous = None;
+
+        debug_assert!(self.ends.is_some());
+        // SAFETY:
+        // `node` is in the list (safety condition), so it is not empty.
+        let ends = unsafe { self.ends.as_mut().unwrap_unchecked() };
+        let tail = Ends {
+            front: next,
+            back: ends.back,
+        };
+        ends.back = node;
+
+        debug_assert!(len <= self.len);
+        let tail_len = self.len - len;
+        self.len = len;
+
+        Self {
+            ends: Some(tail),
+            len: tail_len,
+            allocator,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Links the nodes from another list between `previous` and `next`.
+    ///
+    /// If `previous` or `next` is [`None`], the nodes become the front or back of the list respectively.
+    ///
+    /// # Safety
+    /// - `previous` and `next` must be adjacent nodes in the list, or [`None`] for the ends.
+    /// - `other` must be the ends of a list of `other_len` nodes that is not linked to this list.
+    /// - The nodes in `other` must be compatible with this list's allocator.
+    unsafe fn splice_between(
+        &mut self,
+        previous: Option<Node<U>>,
+        next: Option<Node<U>>,
+        other: Ends<U>,
+        other_len: usize,
+    ) {
+        if let Some(previous) = previous {
+            // SAFETY:
+            // As we have a mutable reference to the list, the node's header is not aliased and is
+            // valid for reads and writes.
+            let previous_header = unsafe { previous.header_ptr().as_mut() };
+            debug_assert_eq!(previous_header.next, next);
+            previous_header.next = Some(other.front);
+        }
+
+        // SAFETY:
+        // The other list's nodes are not linked anywhere else (safety condition), so their headers
+        // are not aliased and are valid for reads and writes.
+        let front_header = unsafe { other.front.header_ptr().as_mut() };
+        debug_assert!(front_header.previous.is_none());
+        front_header.previous = previous;
+
+        // SAFETY:
+        // As above.
+        let back_header = unsafe { other.back.header_ptr().as_mut() };
+        debug_assert!(back_header.next.is_none());
+        back_header.next = next;
+
+        if let Some(next) = next {
+            // SAFETY:
+            // As we have a mutable reference to the list, the node's header is not aliased and is
+            // valid for reads and writes.
+            let next_header = unsafe { next.header_ptr().as_mut() };
+            debug_assert_eq!(next_header.previous, previous);
+            next_header.previous = Some(other.back);
+        }
+
+        match self.ends.as_mut() {
+            None => {
+                debug_assert!(previous.is_none() && next.is_none());
+                self.ends = Some(other);
+            }
+            Some(Ends { front, back }) => {
+                if previous.is_none() {
+                    *front = other.front;
+                }
+                if next.is_none() {
+                    *back = other.back;
+                }
+            }
+        }
+        self.len += other_len;
+    }
+
+    /// Swaps the positions of two nodes in the list.
+    ///
+    /// # Safety
+    /// `first` and `second` must be different nodes in the list, with `first` before `second`.
+    unsafe fn swap_nodes(&mut self, first: Node<U>, second: Node<U>) {
+        // SAFETY:
+        // As the node is in the list, its header must be properly initialised.
+        let first_header = unsafe { first.header_ptr().as_ref() };
+        let (before_first, mut after_first) = (first_header.previous, first_header.next);
+        // SAFETY:
+        // As above.
+        let second_header = unsafe { second.header_ptr().as_ref() };
+        let (mut before_second, after_second) = (second_header.previous, second_header.next);
+
+        // If the nodes are neighbours, they become each other's neighbours the other way around
+        if after_first == Some(second) {
+            after_first = Some(first);
+            before_second = Some(second);
+        }
+
+        // SAFETY:
+        // As we have a mutable reference to the list, the node's header is not aliased and is
+        // valid for reads and writes.
+        let second_header = unsafe { second.header_ptr().as_mut() };
+        second_header.previous = before_first;
+        second_header.next = after_first;
+        // SAFETY:
+        // As above.
+        let first_header = unsafe { first.header_ptr().as_mut() };
+        first_header.previous = before_second;
+        first_header.next = after_second;
+
+        if let Some(next) = after_first {
+            // SAFETY:
+            // As above.
+            unsafe { next.header_ptr().as_mut() }.previous = Some(second);
+        }
+        if let Some(previous) = before_second {
+            // SAFETY:
+            // As above.
+            unsafe { previous.header_ptr().as_mut() }.next = Some(first);
+        }
+
+        debug_assert!(self.ends.is_some());
+        // SAFETY:
+        // The nodes are in the list (safety condition), so it is not empty.
+        let Ends { front, back } = unsafe { self.ends.as_mut().unwrap_unchecked() };
+        match before_first {
+            // SAFETY:
+            // As above.
+            Some(previous) => unsafe { previous.header_ptr().as_mut() }.next = Some(second),
+            None => *front = second,
+        }
+        match after_second {
+            // SAFETY:
+            // As above.
+            Some(next) => unsafe { next.header_ptr().as_mut() }.previous = Some(first),
+            None => *back = first,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    /// Attempts to remove the front node and return it in a [`Box`] in the given allocator.
+    ///
+    /// The value is copied into the box's allocation and the node is deallocated, so this can be used to move a value out of a list in one allocator, such as an arena, into another.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    /// The node and its value are left at the front of the list.
+    pub fn try_pop_front_boxed_in<A2>(
+        &mut self,
+        allocator: A2,
+    ) -> Option<Result<alloc::Box<U, A2>, AllocateError>>
+    where
+        A2: Allocator,
+    {
+        self.pop_front_node().map(|front| {
+            // SAFETY:
+            // As the node was in the list, its value is initialised.
+            unsafe { front.try_take_boxed_in(allocator) }.map_err(|error| {
+                // On failure, the value has not been moved out of the node
+                let (front, error) = error.into_parts();
+                // SAFETY:
+                // The node's value is still initialised and its header still points to its old
+                // neighbours, so this puts it back where it was.
+                unsafe { front.insert() };
+                error.with_context("pop_front_boxed")
+            })
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    /// Attempts to remove the front node and return it in a [`Box`].
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    /// The node and its value are left at the front of the list.
+    pub fn try_pop_front_boxed(&mut self) -> Option<Result<alloc::Box<U, A>, AllocateError>>
+    where
+        A: Clone,
+    {
+        let allocator = self.allocator.clone();
+        self.try_pop_front_boxed_in(allocator)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    /// Attempts to remove the back node and return it in a [`Box`] in the given allocator.
+    ///
+    /// The value is copied into the box's allocation and the node is deallocated, so this can be used to move a value out of a list in one allocator, such as an arena, into another.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    /// The node and its value are left at the back of the list.
+    pub fn try_pop_back_boxed_in<A2>(
+        &mut self,
+        allocator: A2,
+    ) -> Option<Result<alloc::Box<U, A2>, AllocateError>>
+    where
+        A2: Allocator,
+    {
+        self.pop_back_node().map(|back| {
+            // SAFETY:
+            // As the node was in the list, its value is initialised.
+            unsafe { back.try_take_boxed_in(allocator) }.map_err(|error| {
+                // On failure, the value has not been moved out of the node
+                let (back, error) = error.into_parts();
+                // SAFETY:
+                // The node's value is still initialised and its header still points to its old
+                // neighbours, so this puts it back where it was.
+                unsafe { back.insert() };
+                error.with_context("pop_back_boxed")
+            })
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    /// Attempts to remove the back node and return it in a [`Box`].
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    /// The node and its value are left at the back of the list.
+    pub fn try_pop_back_boxed(&mut self) -> Option<Result<alloc::Box<U, A>, AllocateError>>
+    where
+        A: Clone,
+    {
+        let allocator = self.allocator.clone();
+        self.try_pop_back_boxed_in(allocator)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    /// Removes the front node and returns it in a [`Box`].
+    ///
+    /// ```
+    /// # use core::cmp::PartialEq;
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<dyn PartialEq<u8>>::new();
+    /// list.push_back_unsize(5);
+    /// assert!(&*list.pop_front_boxed().unwrap() == &5_u8);
+    /// ```
+    pub fn pop_front_boxed(&mut self) -> Option<alloc::Box<U, A>>
+    where
+        A: Clone,
+    {
+        self.try_pop_front_boxed().map(AllocateError::unwrap_result)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    /// Removes the back node and returns it in a [`Box`].
+    ///
+    /// ```
+    /// # use core::cmp::PartialEq;
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<dyn PartialEq<u8>>::new();
+    /// list.push_back_unsize(5);
+    /// assert!(&*list.pop_back_boxed().unwrap() == &5_u8);
+    /// ```
+    pub fn pop_back_boxed(&mut self) -> Option<alloc::Box<U, A>>
+    where
+        A: Clone,
+    {
+        self.try_pop_back_boxed().map(AllocateError::unwrap_result)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    /// Removes the front node and returns it in a [`Box`] in the given allocator.
+    ///
+    /// See [`Self::try_pop_front_boxed_in`] for more information.
+    ///
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use std::alloc::Global;
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str, Global>::new_in(Global);
+    /// list.push_back_copy_string("Hello");
+    ///
+    /// let boxed: Box<str> = list.pop_front_boxed_in(Global).unwrap();
+    /// assert_eq!(&*boxed, "Hello");
+    /// ```
+    pub fn pop_front_boxed_in<A2>(&mut self, allocator: A2) -> Option<alloc::Box<U, A2>>
+    where
+        A2: Allocator,
+    {
+        self.try_pop_front_boxed_in(allocator)
+            .map(AllocateError::unwrap_result)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    /// Removes the back node and returns it in a [`Box`] in the given allocator.
+    ///
+    /// See [`Self::try_pop_front_boxed_in`] for more information.
+    pub fn pop_back_boxed_in<A2>(&mut self, allocator: A2) -> Option<alloc::Box<U, A2>>
+    where
+        A2: Allocator,
+    {
+        self.try_pop_back_boxed_in(allocator)
+            .map(AllocateError::unwrap_result)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    /// Clones each element into its own [`Box`] in a clone of the list's allocator, collecting them into a [`Vec`](alloc::Vec).
+    ///
+    /// The boxes are separate allocations that do not contain the nodes' headers or metadata, so they can be stored elsewhere after the list has been dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_copy_string("Hello");
+    /// list.push_back_copy_string("World");
+    ///
+    /// let boxes: Vec<Box<str>> = list.to_boxed_vec();
+    /// drop(list);
+    /// assert_eq!(boxes, ["Hello".into(), "World".into()]);
+    /// ```
+    pub fn to_boxed_vec(&self) -> alloc::Vec<alloc::Box<U, A>>
+    where
+        U: CloneToUninit,
+        A: Clone,
+    {
+        use core::alloc::Layout;
+
+        /// Deallocates a box's allocation if cloning its value panics.
+        struct DeallocateGuard<'a, A>
+        where
+            A: Allocator,
+        {
+            ptr: NonNull<u8>,
+            layout: Layout,
+            allocator: &'a A,
+        }
+
+        impl<A> Drop for DeallocateGuard<'_, A>
+        where
+            A: Allocator,
+        {
+            fn drop(&mut self) {
+                // SAFETY:
+                // The pointer was allocated by `self.allocator` with `self.layout` and the value
+                // was not initialised.
+                unsafe { self.allocator.deallocate(self.ptr, self.layout) };
+            }
+        }
+
+        let mut boxes = alloc::Vec::with_capacity(self.len);
+        for item in self {
+            let layout = Layout::for_value(item);
+            let ptr = match self.allocator.allocate(layout) {
+                Ok(ptr) => ptr.cast(),
+                Err(error) => AllocateError::new_alloc(error, layout)
+                    .with_context("to_boxed_vec")
+                    .handle(),
+            };
+
+            let guard = DeallocateGuard {
+                ptr,
+                layout,
+                allocator: &self.allocator,
+            };
+            // SAFETY:
+            // `ptr` is valid for writes of `item`'s layout.
+            unsafe { item.clone_to_uninit(ptr.as_ptr()) };
+            mem::forget(guard);
+
+            let ptr = NonNull::<U>::from_raw_parts(ptr.cast::<()>(), ptr::metadata(item));
+            boxes.push(
+                // SAFETY:
+                // - `ptr` is not aliased
+                // - `ptr` was allocated with the list's allocator using the value's layout
+                // - the value was initialised by `clone_to_uninit`
+                unsafe { alloc::Box::from_raw_in(ptr.as_ptr(), self.allocator.clone()) },
+            );
+        }
+        boxes
+    }
+
+    #[must_use]
+    #[inline]
+    /// Creates a [`Cursor`] at the front of the list.
+    ///
+    /// If the list is empty, this will point to the "ghost" element.
+    pub const fn cursor_front(&self) -> Cursor<U, A> {
+        // Using match rather than map to allow function to be const
+        let current = match self.ends {
+            Some(Ends { front, .. }) => Some(front),
+            None => None,
+        };
+
+        Cursor {
+            current,
+            list: self,
+            index: 0,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Creates a [`Cursor`] at the back of the list.
+    ///
+    /// If the list is empty, this will point to the "ghost" element.
+    pub const fn cursor_back(&self) -> Cursor<U, A> {
+        // Using match rather than map to allow function to be const
+        let current = match self.ends {
+            Some(Ends { back, .. }) => Some(back),
+            None => None,
+        };
+
+        Cursor {
+            current,
+            list: self,
+            index: self.len.saturating_sub(1),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Creates a [`CursorMut`] at the front of the list that can mutate the list.
     ///
     /// If the list is empty, this will point to the "ghost" element.
     pub const fn cursor_front_mut(&mut self) -> CursorMut<U, A> {
@@ -522,6 +1973,8 @@ where
         CursorMut {
             current,
             list: self,
+            index: Some(0),
+            insert_before_len: None,
         }
     }
 
@@ -537,24 +1990,207 @@ where
             None => None,
         };
 
-        CursorMut {
-            current,
-            list: self,
-        }
+        let index = self.len.saturating_sub(1);
+        CursorMut {
+            current,
+            list: self,
+            index: Some(index),
+            insert_before_len: None,
+        }
+    }
+
+    #[must_use]
+    /// Creates a [`Cursor`] at the element at `index`.
+    ///
+    /// This walks from the front or the back, whichever is nearest.
+    /// If `index` is out of bounds, including when it is equal to the length, this will point to the "ghost" element.
+    /// See [`Cursor::seek_to`] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<u8>::from_iter([1, 2, 3]);
+    ///
+    /// let cursor = list.cursor_at(1);
+    /// assert_eq!(cursor.index(), Some(1));
+    /// assert_eq!(cursor.current(), Some(&2));
+    ///
+    /// assert_eq!(list.cursor_at(3).current(), None);
+    /// ```
+    pub fn cursor_at(&self, index: usize) -> Cursor<U, A> {
+        let mut cursor = self.cursor_front();
+        cursor.seek_to(index);
+        cursor
+    }
+
+    #[must_use]
+    /// Creates a [`CursorMut`] at the element at `index` that can mutate the list.
+    ///
+    /// This walks from the front or the back, whichever is nearest.
+    /// If `index` is out of bounds, including when it is equal to the length, this will point to the "ghost" element.
+    /// See [`CursorMut::seek_to`] for more information.
+    pub fn cursor_at_mut(&mut self, index: usize) -> CursorMut<U, A> {
+        let mut cursor = self.cursor_front_mut();
+        cursor.seek_to(index);
+        cursor
+    }
+
+    #[must_use]
+    /// Creates a [`CursorMut`] at a node in the list, such as one recorded with [`Self::iter_nodes`].
+    ///
+    /// This is *O*(1), as moving to the node does not compare any values or walk the list.
+    /// The cursor does not know the node's index, so [`CursorMut::index`] and other methods that need it walk from the node to the nearest end of the list until the index is known again; see [`CursorMut::index`].
+    ///
+    /// # Safety
+    /// `node` must currently be in this list.
+    /// Once a node has been removed, including by a cursor or an iterator, it must not be passed to this, even if it has since been inserted again.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::from_iter([1, 2, 3]);
+    /// let node = list.iter_nodes().nth(1).unwrap();
+    ///
+    /// // SAFETY: The node is still in the list.
+    /// let mut cursor = unsafe { list.cursor_mut_at_node(node) };
+    /// assert_eq!(cursor.index(), Some(1));
+    /// assert_eq!(cursor.remove_current(), Some(2));
+    /// ```
+    pub unsafe fn cursor_mut_at_node(&mut self, node: HeaderOpaqueNodePtr<U>) -> CursorMut<U, A> {
+        // SAFETY:
+        // The node is in this list (safety condition), so it has a `Header<U>`.
+        let node = unsafe { node.to_transparent::<Header<U>>() };
+
+        CursorMut {
+            current: Some(node),
+            list: self,
+            index: None,
+            insert_before_len: None,
+        }
+    }
+
+    /// Finds the index of a node by walking from it to the nearest end of the list.
+    ///
+    /// # Safety
+    /// `node` must be in this list.
+    unsafe fn node_index(&self, node: Node<U>) -> usize {
+        // Walk towards both ends at once, stopping at whichever is reached first
+        let (mut previous, mut next) = (node, node);
+        let mut steps = 0;
+        loop {
+            // SAFETY:
+            // The nodes are in the list (safety condition), so their headers are initialised.
+            let Some(before) = (unsafe { previous.header_ptr().as_ref() }).previous else {
+                return steps;
+            };
+            // SAFETY:
+            // As above.
+            let Some(after) = (unsafe { next.header_ptr().as_ref() }).next else {
+                return self.len - 1 - steps;
+            };
+            previous = before;
+            next = after;
+            steps += 1;
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Creates an iterator over references to the items in the list.
+    pub const fn iter(&self) -> Iter<U> {
+        Iter::new(self)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Creates an iterator over mutable references to the items in the list.
+    pub const fn iter_mut(&mut self) -> IterMut<U> {
+        IterMut::new(self)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Creates an iterator over pointers to the nodes in the list.
+    ///
+    /// This is for advanced uses, such as recording nodes to return to later with [`Self::cursor_mut_at_node`].
+    /// The pointers are only valid while their nodes are in the list; see [`IterNodes`] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<u8>::from_iter([1, 2, 3]);
+    /// let nodes: Vec<_> = list.iter_nodes().collect();
+    ///
+    /// // SAFETY: The node is still in the list.
+    /// let value = unsafe { nodes[1].data_ptr().as_ref() };
+    /// assert_eq!(*value, 2);
+    /// ```
+    pub const fn iter_nodes(&self) -> IterNodes<U> {
+        IterNodes::new(self)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Creates an iterator over each pair of adjacent elements in the list.
+    ///
+    /// This yields one fewer pair than there are elements, and nothing if there are fewer than two.
+    /// It is useful for finding transitions, such as where the list is out of order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.extend(["a", "b", "c"]);
+    ///
+    /// assert!(list.pairs().eq([("a", "b"), ("b", "c")]));
+    /// ```
+    pub fn pairs(&self) -> Pairs<U> {
+        Pairs::new(self)
+    }
+
+    #[inline]
+    /// Creates an iterator over references to the items in the list, from back to front.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<u8>::from_iter([1, 2, 3]);
+    /// assert!(list.iter_rev().eq(&[3, 2, 1]));
+    /// ```
+    pub fn iter_rev(&self) -> Rev<Iter<U>> {
+        self.iter().rev()
     }
 
-    #[must_use]
     #[inline]
-    /// Creates an iterator over references to the items in the list.
-    pub const fn iter(&self) -> Iter<U> {
-        Iter::new(self)
+    /// Creates an iterator over mutable references to the items in the list, from back to front.
+    pub fn iter_mut_rev(&mut self) -> Rev<IterMut<U>> {
+        self.iter_mut().rev()
     }
 
     #[must_use]
-    #[inline]
-    /// Creates an iterator over mutable references to the items in the list.
-    pub const fn iter_mut(&mut self) -> IterMut<U> {
-        IterMut::new(self)
+    /// Leaks the list, returning an iterator over mutable references to its elements that live for `'a`.
+    ///
+    /// This intentionally leaks all of the nodes and the allocator, so they are never dropped or deallocated.
+    /// This is analogous to [`Box::leak`](https://doc.rust-lang.org/std/boxed/struct.Box.html#method.leak), and is useful for building `'static` structures at runtime.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_copy_string("Hello");
+    /// list.push_back_copy_string("World");
+    ///
+    /// let strings: Vec<&'static mut str> = list.leak().collect();
+    /// assert_eq!(strings, ["Hello", "World"]);
+    /// ```
+    pub const fn leak<'a>(self) -> IterMut<'a, U>
+    where
+        U: 'a,
+        A: 'a,
+    {
+        let raw = RawIter::from_list(&self);
+        mem::forget(self);
+        IterMut::from_raw(raw)
     }
 
     #[cfg(feature = "alloc")]
@@ -571,8 +2207,12 @@ where
     /// Attempts to clone the list into another allocator.
     ///
     /// # Errors
-    /// If allocation fails, this will return an [`AllocateError`].
-    pub fn try_clone_in<A2>(&self, allocator: A2) -> Result<DynList<U, A2>, AllocateError>
+    /// If allocation fails, this will return an [`AllocateError`] with the partially cloned list in it.
+    /// This contains clones of the elements before the one that failed, in order, so the caller can decide whether to keep them; use [`AllocateError::into_value`] to get it.
+    pub fn try_clone_in<A2>(
+        &self,
+        allocator: A2,
+    ) -> Result<DynList<U, A2>, AllocateError<DynList<U, A2>>>
     where
         U: CloneToUninit,
         A2: Allocator,
@@ -580,9 +2220,22 @@ where
         let mut new_list = DynList::new_in(allocator);
 
         for item in self {
-            let node = unsafe { new_list.try_allocate_uninit_back(ptr::metadata(item)) }?;
-            unsafe { item.clone_to_uninit(node.value_ptr().cast().as_ptr()) };
-            unsafe { node.insert() };
+            // SAFETY:
+            // The metadata is from a valid reference to `U`.
+            let error = match unsafe { new_list.try_allocate_uninit_back(ptr::metadata(item)) } {
+                Ok(node) => {
+                    // SAFETY:
+                    // The node was allocated with `item`'s metadata, so its value pointer is valid
+                    // for writes of a clone of `item`.
+                    unsafe { item.clone_to_uninit(node.value_ptr().cast().as_ptr()) };
+                    // SAFETY:
+                    // The value has been initialised above.
+                    unsafe { node.insert() };
+                    continue;
+                }
+                Err(error) => error.with_context("clone"),
+            };
+            return Err(error.with_value(new_list));
         }
 
         Ok(new_list)
@@ -598,6 +2251,121 @@ where
         AllocateError::unwrap_result(self.try_clone_in(allocator))
     }
 
+    /// Attempts to overwrite `target` with a clone of the list, reusing `target`'s nodes where possible.
+    ///
+    /// This follows [`ToOwned::clone_into`](https://doc.rust-lang.org/std/borrow/trait.ToOwned.html#method.clone_into): `target`'s elements are dropped and replaced with clones of the list's, and `target` keeps its allocator.
+    /// Each of `target`'s nodes is reused for the clone at the same position if they have the same metadata, and so the same layout; otherwise, it is deallocated and a new node is allocated.
+    /// For lists of sized types, every node can be reused, so only the difference in length is allocated or deallocated.
+    ///
+    /// If a clone panics, `target` is left valid, but with an unspecified selection of the old elements and the clones.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    /// `target` is left with clones of the elements before the one that failed, in order.
+    pub fn try_clone_into<A2>(&self, target: &mut DynList<U, A2>) -> Result<(), AllocateError>
+    where
+        U: CloneToUninit,
+        A2: Allocator,
+    {
+        // The old nodes stay at the front of `target`, while the clones are moved or allocated onto
+        // the back
+        let mut old_len = target.len;
+
+        for item in self {
+            let metadata = ptr::metadata(item);
+
+            if old_len > 0 {
+                old_len -= 1;
+                debug_assert!(target.ends.is_some());
+                // SAFETY:
+                // There are old nodes left, so the list is not empty.
+                let Ends { front, back } = unsafe { target.ends.unwrap_unchecked() };
+
+                // SAFETY:
+                // The front node is in the list, so it has not been deallocated.
+                if unsafe { front.metadata() } == metadata {
+                    // The back node will be before the clone, unless the front node is the only one
+                    let previous = (back != front).then_some(back);
+
+                    let node = target.pop_front_node();
+                    debug_assert!(node.is_some());
+                    // SAFETY:
+                    // The list is not empty.
+                    let mut node = unsafe { node.unwrap_unchecked() };
+                    // SAFETY:
+                    // The node's value is initialised, as it was in the list.
+                    unsafe { node.drop_in_place() };
+                    // SAFETY:
+                    // The node's metadata is the same as `item`'s, so its value pointer is valid
+                    // for writes of a clone of `item`.
+                    unsafe { item.clone_to_uninit(node.value_ptr().cast().as_ptr()) };
+                    // SAFETY:
+                    // The node has been unlinked from the list, so its header is not aliased.
+                    unsafe {
+                        front.header_ptr().write(Header {
+                            next: None,
+                            previous,
+                        });
+                    };
+                    // SAFETY:
+                    // The value has been initialised above and the header points to the back of
+                    // the list.
+                    unsafe { node.insert() };
+                    continue;
+                }
+
+                target.delete_front();
+            }
+
+            // SAFETY:
+            // The metadata is from a valid reference to `U`.
+            let error = match unsafe { target.try_allocate_uninit_back(metadata) } {
+                Ok(node) => {
+                    // SAFETY:
+                    // The node was allocated with `item`'s metadata, so its value pointer is valid
+                    // for writes of a clone of `item`.
+                    unsafe { item.clone_to_uninit(node.value_ptr().cast().as_ptr()) };
+                    // SAFETY:
+                    // The value has been initialised above.
+                    unsafe { node.insert() };
+                    continue;
+                }
+                Err(error) => error.with_context("clone_into"),
+            };
+
+            for _ in 0..old_len {
+                target.delete_front();
+            }
+            return Err(error);
+        }
+
+        for _ in 0..old_len {
+            target.delete_front();
+        }
+        Ok(())
+    }
+
+    /// Overwrites `target` with a clone of the list, reusing `target`'s nodes where possible.
+    ///
+    /// See [`Self::try_clone_into`] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<u32>::from_iter([1, 2, 3]);
+    /// let mut target = DynList::from_iter([4, 5, 6, 7]);
+    ///
+    /// list.clone_into(&mut target);
+    /// assert_eq!(target, list);
+    /// ```
+    pub fn clone_into<A2>(&self, target: &mut DynList<U, A2>)
+    where
+        U: CloneToUninit,
+        A2: Allocator,
+    {
+        AllocateError::unwrap_result(self.try_clone_into(target));
+    }
+
     #[cfg(test)]
     fn check_debug(&self) {
         let Some(Ends { front, back }) = self.ends else {
@@ -635,6 +2403,7 @@ where
         assert_eq!(node.value_ptr(), front.value_ptr());
 
         assert_eq!(forward_len, backward_len);
+        assert_eq!(forward_len, self.len);
     }
 }
 
@@ -667,26 +2436,9 @@ where
     U: ?Sized,
     A: Allocator,
 {
+    #[inline]
     fn drop(&mut self) {
-        // Based on https://doc.rust-lang.org/1.82.0/src/alloc/collections/linked_list.rs.html#1169-1186
-        struct DropGuard<'a, U: ?Sized, A: Allocator> {
-            list: &'a mut DynList<U, A>,
-        }
-
-        impl<U: ?Sized, A: Allocator> Drop for DropGuard<'_, U, A> {
-            // https://doc.rust-lang.org/1.82.0/src/alloc/collections/linked_list.rs.html#1175-1176
-            // Continue the same loop we do below. This only runs when a destructor has
-            // panicked. If another one panics this will abort.
-            fn drop(&mut self) {
-                while self.list.delete_front() {}
-            }
-        }
-
-        // https://doc.rust-lang.org/1.82.0/src/alloc/collections/linked_list.rs.html#1181
-        // Wrap self so that if a destructor panics, we can try to keep looping
-        let guard = DropGuard { list: self };
-        while guard.list.delete_front() {}
-        mem::forget(guard);
+        self.clear();
     }
 }
 
@@ -699,6 +2451,13 @@ where
         let allocator = self.allocator.clone();
         self.clone_in(allocator)
     }
+
+    /// Overwrites the list with a clone of `source`, reusing the list's nodes where possible.
+    ///
+    /// See [`Self::try_clone_into`] for more information.
+    fn clone_from(&mut self, source: &Self) {
+        source.clone_into(self);
+    }
 }
 
 impl<U, A> fmt::Debug for DynList<U, A>
@@ -711,6 +2470,47 @@ where
     }
 }
 
+impl<U, A, A2> PartialEq<DynList<U, A2>> for DynList<U, A>
+where
+    U: ?Sized + PartialEq,
+    A: Allocator,
+    A2: Allocator,
+{
+    fn eq(&self, other: &DynList<U, A2>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<U, A> Eq for DynList<U, A>
+where
+    U: ?Sized + Eq,
+    A: Allocator,
+{
+}
+
+impl<U, A, A2> PartialOrd<DynList<U, A2>> for DynList<U, A>
+where
+    U: ?Sized + PartialOrd,
+    A: Allocator,
+    A2: Allocator,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &DynList<U, A2>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<U, A> Ord for DynList<U, A>
+where
+    U: ?Sized + Ord,
+    A: Allocator,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
 unsafe impl<U, A> Send for DynList<U, A>
 where
     U: ?Sized + Send,
@@ -724,3 +2524,996 @@ where
     A: Allocator + Sync,
 {
 }
+
+#[cfg(test)]
+mod test {
+    use core::{
+        alloc::{AllocError, Allocator, Layout},
+        cell::Cell,
+        cmp::Ordering,
+        ptr::NonNull,
+    };
+
+    use dynode::HeaderOpaqueNodePtr;
+
+    use crate::{alloc::Global, DynList};
+
+    /// An allocator that fails once `budget` allocations have been made.
+    #[derive(Clone, Copy)]
+    pub struct FailingAllocator<'a> {
+        pub budget: &'a Cell<usize>,
+    }
+
+    // SAFETY:
+    // All allocation is done by the global allocator.
+    unsafe impl Allocator for FailingAllocator<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let Some(budget) = self.budget.get().checked_sub(1) else {
+                return Err(AllocError);
+            };
+            self.budget.set(budget);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            // SAFETY:
+            // `ptr` was allocated by the global allocator with `layout` (safety condition).
+            unsafe { Global.deallocate(ptr, layout) };
+        }
+    }
+
+    #[test]
+    fn len() {
+        let mut list = DynList::<[u8]>::new();
+        assert!(list.is_empty());
+
+        list.push_back_unsize([1]);
+        list.push_back_copy_array(&[2, 2]);
+        list.push_front_unsize([0; 3]);
+        assert_eq!(list.len(), 3);
+        list.check_debug();
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert!(cursor.delete_current());
+        assert_eq!(list.len(), 2);
+        list.check_debug();
+
+        let clone = list.clone_in(crate::alloc::Global);
+        assert_eq!(clone.len(), 2);
+        assert_eq!(clone.iter().len(), 2);
+
+        assert!(list.delete_back());
+        assert!(list.delete_front());
+        assert!(list.is_empty());
+        list.check_debug();
+    }
+
+    #[test]
+    fn append() {
+        let mut list = DynList::<u8>::new();
+        let mut other = DynList::<u8>::new();
+
+        list.append(&mut other);
+        assert!(list.is_empty());
+
+        other.extend([0, 1]);
+        list.append(&mut other);
+        assert!(other.is_empty());
+        assert_eq!(list.len(), 2);
+
+        other.extend([2, 3, 4]);
+        list.append(&mut other);
+        list.append(&mut other);
+        assert!(other.is_empty());
+        other.check_debug();
+
+        assert_eq!(list.len(), 5);
+        assert!(list.iter().copied().eq(0..5));
+    }
+
+    #[test]
+    fn append_unsize() {
+        use core::fmt::Display;
+
+        let mut list = DynList::<dyn Display>::new();
+        list.append_unsize(DynList::<String>::new());
+        assert!(list.is_empty());
+
+        list.push_back_unsize('!');
+        let strings = ["Hello", "World"].map(String::from);
+        list.append_unsize(DynList::from(strings));
+        list.check_debug();
+        assert_eq!(list.len(), 3);
+        assert!(list
+            .iter()
+            .map(ToString::to_string)
+            .eq(["!", "Hello", "World"]));
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list = DynList::<u8>::new();
+        list.extend(0..5);
+
+        let mut tail = list.split_off(2);
+        list.check_debug();
+        tail.check_debug();
+        assert!(list.iter().copied().eq(0..2));
+        assert!(tail.iter().copied().eq(2..5));
+
+        let empty = tail.split_off(3);
+        assert!(empty.is_empty());
+        assert_eq!(tail.len(), 3);
+
+        let whole = tail.split_off(0);
+        assert!(tail.is_empty());
+        tail.check_debug();
+        assert!(whole.iter().copied().eq(2..5));
+    }
+
+    #[test]
+    fn split_off_clone_in() {
+        let budget = Cell::new(usize::MAX);
+        let mut list = DynList::<[u8], _>::new_in(FailingAllocator { budget: &budget });
+        list.extend([&[0][..], &[1, 1], &[2, 2, 2]]);
+
+        // The clones are allocated by the new list's allocator
+        budget.set(0);
+        let mut tail = list.split_off_clone_in(1, Global);
+        list.check_debug();
+        tail.check_debug();
+        assert!(list.iter().eq([&[0][..]]));
+        assert!(tail.iter().eq([&[1, 1][..], &[2, 2, 2]]));
+
+        let empty = tail.split_off_clone_in(2, Global);
+        assert!(empty.is_empty());
+        assert_eq!(tail.len(), 2);
+
+        let whole = list.split_off_clone_in(0, Global);
+        list.check_debug();
+        assert!(list.is_empty());
+        assert!(whole.iter().eq([&[0][..]]));
+
+        // On failure, `self` is unchanged and the clones made so far are returned in the error
+        budget.set(usize::MAX);
+        list.push_back_unsize([1, 1]);
+        list.push_back_unsize([2, 2, 2]);
+        budget.set(1);
+        let partial = list
+            .try_split_off_clone_in(0, FailingAllocator { budget: &budget })
+            .unwrap_err()
+            .into_value();
+        list.check_debug();
+        partial.check_debug();
+        assert!(list.iter().eq([&[1, 1][..], &[2, 2, 2]]));
+        assert!(partial.iter().eq([&[1, 1][..]]));
+    }
+
+    #[test]
+    fn split_when() {
+        let mut list = DynList::<str>::new();
+        assert!(list.split_when(str::is_empty).is_empty());
+
+        list.push_back_copy_string("");
+        list.push_back_copy_string("a");
+        list.push_back_copy_string("");
+
+        let mut tail = list.split_when(str::is_empty);
+        assert!(list.is_empty());
+        tail.check_debug();
+        assert_eq!(tail.len(), 3);
+
+        let mut rest = tail.split_when(|s| s == "a");
+        tail.check_debug();
+        rest.check_debug();
+        assert!(tail.iter().eq([""]));
+        assert!(rest.iter().eq(["a", ""]));
+
+        let none = rest.split_when(|s| s == "b");
+        assert!(none.is_empty());
+        assert_eq!(rest.len(), 2);
+
+        let empty = rest.split_when(str::is_empty);
+        rest.check_debug();
+        empty.check_debug();
+        assert!(rest.iter().eq(["a"]));
+        assert!(empty.iter().eq([""]));
+    }
+
+    #[test]
+    fn pop_while() {
+        let mut list = DynList::<i32>::new();
+        assert!(list.pop_front_while(|_| true).is_empty());
+        assert!(list.pop_back_while(|_| true).is_empty());
+
+        list.extend([1, 3, 2, 7, 4, 8, 9]);
+
+        let none = list.pop_front_while(|&value| value > 5);
+        assert!(none.is_empty());
+        assert_eq!(list.len(), 7);
+
+        let mut below = list.pop_front_while(|&value| value < 5);
+        list.check_debug();
+        below.check_debug();
+        assert!(below.iter().eq(&[1, 3, 2]));
+        assert!(list.iter().eq(&[7, 4, 8, 9]));
+
+        let mut above = list.pop_back_while(|&value| value > 5);
+        list.check_debug();
+        above.check_debug();
+        assert!(above.iter().eq(&[8, 9]));
+        assert!(list.iter().eq(&[7, 4]));
+
+        let none = list.pop_back_while(|&value| value > 5);
+        assert!(none.is_empty());
+
+        let all = list.pop_back_while(|_| true);
+        list.check_debug();
+        assert!(list.is_empty());
+        assert!(all.iter().eq(&[7, 4]));
+
+        let all = above.pop_front_while(|_| true);
+        above.check_debug();
+        assert!(above.is_empty());
+        assert!(all.iter().eq(&[8, 9]));
+        assert_eq!(below.pop_back_while(|_| false).len(), 0);
+    }
+
+    #[test]
+    fn retain() {
+        let mut list = DynList::<i32>::new();
+        list.extend(0..10);
+        list.retain(|value| value % 2 != 0);
+        list.check_debug();
+        assert!(list
+            .iter()
+            .copied()
+            .eq((0..10).filter(|value| value % 2 != 0)));
+
+        let mut list = DynList::<[u8]>::new();
+        list.push_back_unsize([0, 0]);
+        list.push_back_unsize([1]);
+        list.push_back_unsize([2, 2]);
+        list.push_back_unsize([3]);
+        list.retain_mut(|slice| {
+            slice.reverse();
+            slice.len() == 2
+        });
+        list.check_debug();
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some([0, 0].as_slice()));
+        assert_eq!(list.back(), Some([2, 2].as_slice()));
+
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        list.check_debug();
+    }
+
+    #[test]
+    fn remove_all() {
+        let mut list = DynList::<i32>::new();
+        assert_eq!(list.remove_all(&0), 0);
+
+        list.extend([1, 1, 2, 1, 3, 1, 1]);
+        assert_eq!(list.remove_all(&4), 0);
+        assert_eq!(list.len(), 7);
+
+        // Including consecutive elements at both ends
+        assert_eq!(list.remove_all(&1), 5);
+        list.check_debug();
+        assert!(list.iter().eq(&[2, 3]));
+        assert_eq!(list.front(), Some(&2));
+        assert_eq!(list.back(), Some(&3));
+
+        assert_eq!(list.remove_all(&3), 1);
+        assert_eq!(list.remove_all(&2), 1);
+        list.check_debug();
+        assert!(list.is_empty());
+
+        let mut list = DynList::<str>::from_iter(["x", "y", "x"]);
+        assert_eq!(list.remove_all("x"), 2);
+        list.check_debug();
+        assert!(list.iter().eq(["y"]));
+    }
+
+    #[test]
+    fn get() {
+        let mut list = DynList::<usize>::new();
+        assert_eq!(list.get(0), None);
+
+        list.extend(0..7);
+        for index in 0..7 {
+            assert_eq!(list.get(index), Some(&index));
+        }
+        assert_eq!(list.get(7), None);
+
+        *list.get_mut(5).unwrap() = 10;
+        assert!(list.iter().copied().eq([0, 1, 2, 3, 4, 10, 6]));
+    }
+
+    #[test]
+    fn eq() {
+        let mut a = DynList::<str>::new();
+        let mut b = DynList::<str>::new();
+        assert_eq!(a, b);
+
+        a.push_back_copy_string("Hello");
+        assert_ne!(a, b);
+        b.push_back_copy_string("Hello");
+        assert_eq!(a, b);
+
+        b.push_back_copy_string("World");
+        assert_ne!(a, b);
+        a.push_back_copy_string("world");
+        assert_ne!(a, b);
+
+        let mut c = DynList::<[u8]>::new();
+        c.push_back_unsize([1, 2]);
+        let mut d = DynList::<[u8]>::new();
+        d.push_back_copy_array(&[1, 2]);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn cmp() {
+        let mut a = DynList::<str>::new();
+        let mut b = DynList::<str>::new();
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+
+        a.push_back_copy_string("apple");
+        assert!(a > b);
+        b.push_back_copy_string("banana");
+        assert!(a < b);
+
+        assert!(b.delete_back());
+        b.push_back_copy_string("apple");
+        b.push_back_copy_string("cherry");
+        assert!(a < b);
+        a.push_back_copy_string("date");
+        assert!(a > b);
+    }
+
+    #[test]
+    fn rotate() {
+        let mut list = DynList::<u8>::new();
+        list.rotate_left(3);
+        list.rotate_right(3);
+        assert!(list.is_empty());
+
+        list.extend(0..6);
+        list.rotate_left(0);
+        assert!(list.iter().copied().eq(0..6));
+        list.rotate_left(1);
+        list.check_debug();
+        assert!(list.iter().copied().eq([1, 2, 3, 4, 5, 0]));
+        list.rotate_right(1);
+        list.check_debug();
+        assert!(list.iter().copied().eq(0..6));
+        list.rotate_left(10);
+        list.check_debug();
+        assert!(list.iter().copied().eq([4, 5, 0, 1, 2, 3]));
+        list.rotate_right(6);
+        assert!(list.iter().copied().eq([4, 5, 0, 1, 2, 3]));
+
+        let mut single = DynList::<u8>::new();
+        single.push_back(0);
+        single.rotate_left(5);
+        single.check_debug();
+    }
+
+    #[test]
+    fn swap() {
+        let mut list = DynList::<[u8]>::new();
+        list.push_back_copy_array(&[0]);
+        list.push_back_copy_array(&[1, 1]);
+        list.push_back_copy_array(&[2, 2, 2]);
+        list.push_back_copy_array(&[]);
+
+        list.swap(0, 3);
+        list.check_debug();
+        assert!(list.iter().eq([&[][..], &[1, 1], &[2, 2, 2], &[0]]));
+
+        list.swap(2, 1);
+        list.check_debug();
+        assert!(list.iter().eq([&[][..], &[2, 2, 2], &[1, 1], &[0]]));
+
+        list.swap(3, 2);
+        list.swap(1, 1);
+        list.check_debug();
+        assert!(list.iter().eq([&[][..], &[2, 2, 2], &[0], &[1, 1]]));
+
+        let mut pair = DynList::<str>::new();
+        pair.push_back_copy_string("a");
+        pair.push_back_copy_string("bc");
+        pair.swap(0, 1);
+        pair.check_debug();
+        assert!(pair.iter().eq(["bc", "a"]));
+    }
+
+    #[test]
+    #[should_panic = "swap indices (is 0 and 2) should be < len (is 2)"]
+    fn swap_out_of_bounds() {
+        let mut list = DynList::<u8>::from_iter([0, 1]);
+        list.swap(0, 2);
+    }
+
+    #[test]
+    fn contains() {
+        let mut list = DynList::<str>::new();
+        assert!(!list.contains("a"));
+
+        list.push_back_copy_string("a");
+        list.push_back_copy_string("bc");
+        assert!(list.contains("bc"));
+        assert!(!list.contains("b"));
+
+        let mut list = DynList::<[u8]>::new();
+        list.push_back_copy_array(&[1, 2]);
+        assert!(list.contains(&[1, 2][..]));
+        assert!(!list.contains(&[1][..]));
+    }
+
+    #[test]
+    fn find() {
+        let mut list = DynList::<[u8]>::new();
+        assert_eq!(list.position(|_| true), None);
+        assert_eq!(list.find(|_| true), None);
+
+        list.push_back_copy_array(&[1]);
+        list.push_back_copy_array(&[2, 3]);
+        list.push_back_copy_array(&[4, 5]);
+
+        // Stops at the first match
+        let mut calls = 0;
+        let position = list.position(|slice| {
+            calls += 1;
+            slice.len() == 2
+        });
+        assert_eq!(position, Some(1));
+        assert_eq!(calls, 2);
+        assert_eq!(list.position(<[u8]>::is_empty), None);
+
+        assert_eq!(list.find(|slice| slice.len() == 2), Some(&[2, 3][..]));
+        assert_eq!(list.find(<[u8]>::is_empty), None);
+
+        list.find_mut(|slice| slice.contains(&4)).unwrap()[0] = 6;
+        assert!(list.find_mut(<[u8]>::is_empty).is_none());
+        assert_eq!(list.back(), Some(&[6, 5][..]));
+    }
+
+    #[test]
+    fn init_node() {
+        let mut list = DynList::<u8>::new();
+
+        let mut node = list.allocate_uninit_sized_back().write(1);
+        *node += 1;
+        node.insert();
+
+        // Dropping an initialised node does not insert it
+        drop(list.allocate_uninit_sized_back().write(3));
+        assert!(list.iter().eq(&[2]));
+
+        let node = list.allocate_uninit_sized_front().write(4);
+        assert_eq!(node.take(), 4);
+        list.check_debug();
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn node_into_raw() {
+        use crate::MaybeUninitNode;
+
+        let mut list = DynList::<str>::new();
+        list.push_back_copy_string("a");
+
+        let mut node = list.allocate_uninit_string_back(1);
+        node.copy_from_str("b");
+        let raw = node.into_raw();
+        // The list is unchanged, so the node can still be inserted
+        assert_eq!(list.len(), 1);
+
+        // SAFETY:
+        // `raw` is from a node for this list, which has not been modified since, so the neighbours
+        // stored in its header are still valid.
+        let node = unsafe { MaybeUninitNode::from_raw(&mut list, raw) };
+        // SAFETY:
+        // The value was initialised above.
+        unsafe { node.insert() };
+        list.check_debug();
+        assert!(list.iter().eq(["a", "b"]));
+
+        // Dropping a reconstructed node deallocates it
+        let raw = list.allocate_uninit_string_front(3).into_raw();
+        // SAFETY:
+        // `raw` is from a node for this list, which has not been modified since.
+        drop(unsafe { MaybeUninitNode::from_raw(&mut list, raw) });
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn insert_and_get() {
+        let mut list = DynList::<str>::new();
+        list.push_back_copy_string("a");
+
+        let mut node = list.allocate_uninit_string_front(3);
+        node.copy_from_str("bcd");
+        // SAFETY:
+        // The value was initialised above, and the list keeps its nodes while it is borrowed.
+        let value = unsafe { node.insert_and_get() };
+        value.make_ascii_uppercase();
+        list.check_debug();
+        assert!(list.iter().eq(["BCD", "a"]));
+
+        let mut list = DynList::<u32>::new();
+        let node = list.allocate_uninit_sized_back().write(4);
+        // SAFETY:
+        // The list keeps its nodes while it is borrowed.
+        *unsafe { node.insert_and_get() } += 1;
+        assert_eq!(list.back(), Some(&5));
+    }
+
+    #[test]
+    fn fill_node() {
+        let mut list = DynList::<[u8]>::new();
+
+        let mut node = list.allocate_uninit_array_back(3);
+        node.fill(7);
+        // SAFETY:
+        // The node has been fully initialised above.
+        unsafe { node.insert() };
+
+        let mut node = list.allocate_uninit_array_back(4);
+        let mut next = 0;
+        node.fill_with(|| {
+            next += 1;
+            next
+        });
+        // SAFETY:
+        // The node has been fully initialised above.
+        unsafe { node.insert() };
+
+        let mut node = list.allocate_uninit_array_back(2);
+        assert_eq!(node.write_from_iter(5..), 2);
+        // SAFETY:
+        // The node has been fully initialised above.
+        unsafe { node.insert() };
+
+        assert!(list.iter().eq([&[7, 7, 7][..], &[1, 2, 3, 4], &[5, 6]]));
+
+        let mut list = DynList::<str>::new();
+
+        let mut node = list.allocate_uninit_string_back(3);
+        assert!(!node.try_copy_from_str("abcd"));
+        assert!(node.try_copy_from_str("abc"));
+        // SAFETY:
+        // The node has been fully initialised above.
+        unsafe { node.insert() };
+
+        let mut node = list.allocate_uninit_string_back(3);
+        // 'é' is two bytes long, so only "aa" fits
+        assert!(!node.copy_from_str_truncating("aaé"));
+        node.as_bytes_mut()[2].write(b'!');
+        // SAFETY:
+        // The node has been fully initialised above.
+        unsafe { node.insert() };
+
+        let mut node = list.allocate_uninit_string_back(2);
+        assert!(node.copy_from_str_truncating("é"));
+        // SAFETY:
+        // The node has been fully initialised above.
+        unsafe { node.insert() };
+
+        assert!(list.iter().eq(["abc", "aa!", "é"]));
+    }
+
+    #[test]
+    fn clear_order() {
+        use core::cell::RefCell;
+        use std::panic;
+
+        struct Recorder<'a>(u8, &'a RefCell<Vec<u8>>);
+        impl Drop for Recorder<'_> {
+            fn drop(&mut self) {
+                self.1.borrow_mut().push(self.0);
+                assert_ne!(self.0, 12, "drop panicked");
+            }
+        }
+
+        let order = RefCell::new(Vec::new());
+        let mut list = (0..5)
+            .map(|id| Recorder(id * 2 + 1, &order))
+            .collect::<DynList<_>>();
+        list.clear_back_to_front();
+        assert!(list.is_empty());
+        assert_eq!(*order.borrow(), [9, 7, 5, 3, 1]);
+
+        order.borrow_mut().clear();
+        list.extend((0..3).map(|id| Recorder(id, &order)));
+        drop(list);
+        assert_eq!(*order.borrow(), [0, 1, 2]);
+
+        // The rest of the elements are still dropped if one panics
+        order.borrow_mut().clear();
+        let mut list = (10..15)
+            .map(|id| Recorder(id, &order))
+            .collect::<DynList<_>>();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| list.clear_back_to_front()));
+        assert!(result.is_err());
+        list.check_debug();
+        assert!(list.is_empty());
+        assert_eq!(*order.borrow(), [14, 13, 12, 11, 10]);
+
+        // Truncating still stops at the new length if one panics
+        order.borrow_mut().clear();
+        let mut list = (10..15)
+            .map(|id| Recorder(id, &order))
+            .collect::<DynList<_>>();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| list.truncate(1)));
+        assert!(result.is_err());
+        list.check_debug();
+        assert_eq!(list.len(), 1);
+        assert_eq!(*order.borrow(), [14, 13, 12, 11]);
+    }
+
+    #[test]
+    fn write_from_iter() {
+        use core::cell::Cell;
+        use std::panic;
+
+        struct DropCounter<'a>(&'a Cell<usize>);
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let mut list = DynList::<[u8]>::new();
+
+        // The iterator is not advanced past the end of the node
+        let mut iter = 0..10;
+        let mut node = list.allocate_uninit_array_back(3);
+        assert_eq!(node.write_from_iter(&mut iter), 3);
+        assert_eq!(iter.next(), Some(3));
+        // SAFETY:
+        // The node has been fully initialised above.
+        unsafe { node.insert() };
+
+        let mut node = list.allocate_uninit_array_back(3);
+        assert_eq!(node.write_from_iter([4, 5]), 2);
+        // The node is not fully initialised, so it must not be inserted
+        drop(node);
+        assert!(list.iter().eq([&[0, 1, 2][..]]));
+
+        // Values that have already been written are dropped if the iterator panics
+        let drops = Cell::new(0);
+        let mut list = DynList::<[DropCounter]>::new();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            let mut node = list.allocate_uninit_array_back(3);
+            let mut count = 0;
+            node.write_from_iter(core::iter::from_fn(|| {
+                count += 1;
+                assert!(count < 3, "iterator panicked");
+                Some(DropCounter(&drops))
+            }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(drops.get(), 2);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn push_clone() {
+        let mut list = DynList::<[String]>::new();
+        let strings = [String::from("a"), String::from("b")];
+        list.push_back_clone(&strings);
+        list.push_front_clone(&strings[1..]);
+        assert!(list.try_push_back_clone(&[]).is_ok());
+
+        list.check_debug();
+        assert!(list.iter().eq([&strings[1..], &strings, &[]]));
+    }
+
+    #[test]
+    fn leak() {
+        let mut list = DynList::<[u8]>::new();
+        list.push_back_copy_array(&[1, 2]);
+        list.push_back_copy_array(&[3]);
+
+        let mut leaked = list.leak();
+        let first: &'static mut [u8] = leaked.next().unwrap();
+        first[0] = 0;
+        assert_eq!(leaked.next_back(), Some(&mut [3][..]));
+        assert_eq!(leaked.next(), None);
+        assert_eq!(first, [0, 2]);
+    }
+
+    #[test]
+    fn truncate() {
+        let mut list = DynList::<i32>::new();
+        list.extend(0..5);
+
+        list.truncate(5);
+        assert_eq!(list.len(), 5);
+        list.truncate(3);
+        list.check_debug();
+        assert!(list.iter().copied().eq(0..3));
+        assert_eq!(list.back(), Some(&2));
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        list.truncate(0);
+        list.check_debug();
+    }
+
+    #[test]
+    fn dedup() {
+        let mut list = DynList::<i32>::new();
+        list.extend([0, 0, 0, 1, 2, 2, 3, 4, 4]);
+        list.dedup();
+        list.check_debug();
+        assert!(list.iter().copied().eq(0..5));
+
+        let mut list = DynList::<str>::new();
+        list.dedup();
+        list.push_back_copy_string("a");
+        list.dedup();
+        assert_eq!(list.len(), 1);
+
+        list.extend(["a", "b", "b", "c"]);
+        list.dedup();
+        list.check_debug();
+        assert!(list.iter().eq(["a", "b", "c"]));
+
+        let mut list = DynList::<i32>::new();
+        list.extend([1, 2, 4, 5, 7]);
+        // Removes elements that are one more than the last kept element
+        list.dedup_by(|a, b| *a == *b + 1);
+        assert!(list.iter().copied().eq([1, 4, 7]));
+    }
+
+    #[test]
+    fn pop_boxed_failed_allocation() {
+        let budget = Cell::new(usize::MAX);
+        let mut list = DynList::<str, _>::new_in(FailingAllocator { budget: &budget });
+        for string in ["a", "bc", "def"] {
+            list.push_back_copy_string(string);
+        }
+
+        budget.set(0);
+        assert!(list.try_pop_front_boxed().unwrap().is_err());
+        assert!(list.try_pop_back_boxed().unwrap().is_err());
+        list.check_debug();
+        assert!(list.iter().eq(["a", "bc", "def"]));
+
+        for n in 0..3 {
+            let mut cursor = list.cursor_front_mut();
+            cursor.move_next_n(n);
+            assert!(cursor.try_remove_current_boxed().unwrap().is_err());
+            assert_eq!(cursor.index(), Some(n));
+            assert_eq!(cursor.current().map(|value| value.len()), Some(n + 1));
+            list.check_debug();
+        }
+        assert!(list.iter().eq(["a", "bc", "def"]));
+
+        budget.set(usize::MAX);
+        assert_eq!(&*list.pop_front_boxed().unwrap(), "a");
+        assert_eq!(&*list.pop_back_boxed().unwrap(), "def");
+        list.check_debug();
+        assert!(list.iter().eq(["bc"]));
+    }
+
+    #[test]
+    fn try_clone_in_partial() {
+        let mut list = DynList::<str>::new();
+        list.extend(["a", "bc", "def"]);
+
+        let budget = Cell::new(2);
+        let Err(error) = list.try_clone_in(FailingAllocator { budget: &budget }) else {
+            panic!("the third allocation should fail");
+        };
+        let partial = error.into_value();
+        partial.check_debug();
+        assert!(partial.iter().eq(["a", "bc"]));
+
+        budget.set(usize::MAX);
+        let Ok(clone) = list.try_clone_in(FailingAllocator { budget: &budget }) else {
+            panic!("allocation failed");
+        };
+        assert!(clone.iter().eq(["a", "bc", "def"]));
+    }
+
+    #[test]
+    fn clone_into() {
+        let list = (0..4).collect::<DynList<u64>>();
+
+        // Cloning into a longer list reuses its nodes without allocating
+        let budget = Cell::new(usize::MAX);
+        let mut target = DynList::new_in(FailingAllocator { budget: &budget });
+        target.extend(10..16);
+        let nodes = target
+            .iter_nodes()
+            .map(HeaderOpaqueNodePtr::value_ptr)
+            .collect::<Vec<_>>();
+        budget.set(0);
+        list.clone_into(&mut target);
+        target.check_debug();
+        assert!(target.iter().copied().eq(0..4));
+        assert!(target
+            .iter_nodes()
+            .map(HeaderOpaqueNodePtr::value_ptr)
+            .eq(nodes[..4].iter().copied()));
+
+        // Cloning into a shorter list only allocates the extra nodes
+        let list = (0..6).collect::<DynList<u64>>();
+        budget.set(2);
+        list.clone_into(&mut target);
+        target.check_debug();
+        assert_eq!(budget.get(), 0);
+        assert!(target.iter().copied().eq(0..6));
+        assert!(target
+            .iter_nodes()
+            .take(4)
+            .map(HeaderOpaqueNodePtr::value_ptr)
+            .eq(nodes[..4].iter().copied()));
+
+        DynList::new().clone_into(&mut target);
+        assert!(target.is_empty());
+    }
+
+    #[test]
+    fn clone_into_unsized() {
+        let mut list = DynList::<str>::new();
+        list.extend(["ab", "c", "def"]);
+
+        let budget = Cell::new(usize::MAX);
+        let mut target = DynList::<str, _>::new_in(FailingAllocator { budget: &budget });
+        target.extend(["xy", "zzz", "w", "v"]);
+
+        // Only "ab" can reuse a node
+        budget.set(2);
+        list.try_clone_into(&mut target).unwrap();
+        target.check_debug();
+        assert!(target.iter().eq(["ab", "c", "def"]));
+
+        let mut list = DynList::<str>::new();
+        list.extend(["ab", "gh", "i"]);
+        budget.set(0);
+        assert!(list.try_clone_into(&mut target).is_err());
+        target.check_debug();
+        assert!(target.iter().eq(["ab"]));
+
+        let mut target = list.clone();
+        target.push_back_copy_string("j");
+        target.clone_from(&list);
+        assert_eq!(target, list);
+    }
+
+    #[test]
+    fn take() {
+        let mut list = DynList::<str>::new();
+        list.extend(["a", "b", "c"]);
+
+        let taken = list.take();
+        list.check_debug();
+        taken.check_debug();
+        assert!(list.is_empty());
+        assert!(taken.iter().eq(["a", "b", "c"]));
+
+        // The original list is still usable
+        list.push_back_copy_string("d");
+        assert!(list.iter().eq(["d"]));
+
+        let budget = Cell::new(usize::MAX);
+        let mut list = DynList::<u8, _>::new_in(FailingAllocator { budget: &budget });
+        list.extend([1, 2]);
+        let mut taken = list.replace_with_empty();
+        list.check_debug();
+        taken.check_debug();
+        assert!(list.is_empty());
+        assert!(taken.iter().eq(&[1, 2]));
+
+        // Both lists share the allocator
+        budget.set(0);
+        assert!(list.try_push_back(3).is_err());
+        assert!(taken.try_push_back(3).is_err());
+    }
+
+    #[test]
+    fn pop_boxed_in() {
+        let budget = Cell::new(usize::MAX);
+        let mut list = DynList::<str, _>::new_in(FailingAllocator { budget: &budget });
+        list.extend(["a", "bc", "def"]);
+
+        // The boxes are allocated by the global allocator, not the list's
+        budget.set(0);
+        let front: crate::alloc::Box<str> = list.pop_front_boxed_in(Global).unwrap();
+        assert_eq!(&*front, "a");
+        assert_eq!(list.pop_back_boxed_in(Global).as_deref(), Some("def"));
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(
+            cursor.remove_current_boxed_in(Global).as_deref(),
+            Some("bc")
+        );
+        assert!(list.is_empty());
+
+        let mut list = DynList::<str>::new();
+        list.push_back_copy_string("a");
+        let Some(Err(_)) = list.try_pop_front_boxed_in(FailingAllocator { budget: &budget }) else {
+            panic!("allocation should fail");
+        };
+        list.check_debug();
+        assert!(list.iter().eq(["a"]));
+    }
+
+    #[test]
+    fn over_aligned() {
+        #[repr(align(64))]
+        #[derive(Debug, PartialEq, Eq)]
+        struct Aligned(u8);
+
+        let mut list = DynList::<Aligned>::new();
+        list.push_back(Aligned(1));
+        list.push_back(Aligned(2));
+        list.push_front(Aligned(0));
+        list.check_debug();
+
+        for value in &list {
+            assert!((&raw const *value).is_aligned());
+        }
+        assert!(list.iter().eq(&[Aligned(0), Aligned(1), Aligned(2)]));
+        assert_eq!(list.pop_back(), Some(Aligned(2)));
+    }
+
+    #[test]
+    fn allocate_error_context() {
+        let budget = Cell::new(0);
+        let mut list = DynList::<str, _>::new_in(FailingAllocator { budget: &budget });
+
+        let error = list.try_push_back_copy_string("a").unwrap_err();
+        assert_eq!(error.context(), Some("push_back_copy_string"));
+        assert!(error
+            .to_string()
+            .ends_with("(while: push_back_copy_string)"));
+        assert!(format!("{error:?}").ends_with(r#"context: "push_back_copy_string")"#));
+        let error = list.try_push_front_clone("a").unwrap_err();
+        assert_eq!(error.context(), Some("push_front_clone"));
+
+        budget.set(usize::MAX);
+        list.push_back_copy_string("a");
+        budget.set(0);
+        let error = list.try_clone_in(FailingAllocator { budget: &budget });
+        assert_eq!(error.unwrap_err().context(), Some("clone"));
+        let error = list.try_pop_back_boxed().unwrap().unwrap_err();
+        assert_eq!(error.context(), Some("pop_back_boxed"));
+
+        let mut cursor = list.cursor_front_mut();
+        let error = cursor.try_insert_copy_str_after("b").unwrap_err();
+        assert_eq!(error.context(), Some("insert_copy_str_after"));
+        list.check_debug();
+
+        let mut list = DynList::<u8, _>::new_in(FailingAllocator { budget: &budget });
+        let error = list.try_push_back(1).unwrap_err();
+        assert_eq!(error.context(), Some("push_back"));
+        assert_eq!(error.into_value(), 1);
+    }
+
+    #[test]
+    fn to_boxed_vec() {
+        let mut list = DynList::<str>::new();
+        list.push_back_copy_string("a");
+        list.push_back_copy_string("");
+        list.push_back_copy_string("bc");
+
+        let boxes = list.to_boxed_vec();
+        assert!(boxes.iter().map(|boxed| &**boxed).eq(["a", "", "bc"]));
+        drop(list);
+        assert_eq!(&*boxes[2], "bc");
+
+        let list: DynList<[u8]> = [&[1, 2][..], &[], &[3]].into_iter().collect();
+        let boxes = list.to_boxed_vec();
+        assert!(boxes.iter().map(|boxed| &**boxed).eq(list.iter()));
+
+        assert!(DynList::<[u8]>::new().to_boxed_vec().is_empty());
+    }
+}
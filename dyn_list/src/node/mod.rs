@@ -13,7 +13,15 @@ where
 }
 
 pub type Node<U> = NodePtr<Header<U>, U>;
+/// A node for a [`DynList`] with a possibly uninitialised value.
+///
+/// The list keeps inserted nodes allocated and does not access their values while it is mutably borrowed, so [`insert_and_get`](dynode::MaybeUninitNode::insert_and_get) can be used on these nodes.
+///
+/// The header stores the node's neighbours, so a node converted with [`into_raw`](dynode::MaybeUninitNode::into_raw) may only be reconstructed with [`from_raw`](dynode::MaybeUninitNode::from_raw) if the list has not been modified since.
+/// Use [`CursorMut::insert_node_before`](crate::cursor::CursorMut::insert_node_before) to insert a node after the list has changed.
 pub type MaybeUninitNode<'a, U, A> = dynode::MaybeUninitNode<U, &'a mut DynList<U, A>>;
+/// A node for a [`DynList`] with an initialised value.
+pub type InitNode<'a, U, A> = dynode::InitNode<U, &'a mut DynList<U, A>>;
 
 impl<U, A> StructureHandle<U> for &mut DynList<U, A>
 where
@@ -82,6 +90,8 @@ where
                 back: node,
             });
         }
+
+        self.len += 1;
     }
 
     #[inline]
@@ -0,0 +1,112 @@
+use core::{alloc::Allocator, fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::DynList;
+
+impl<U, A> Serialize for DynList<U, A>
+where
+    U: ?Sized + Serialize,
+    A: Allocator,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+struct DynListVisitor<T, A>
+where
+    A: Allocator,
+{
+    _phantom: PhantomData<fn() -> DynList<T, A>>,
+}
+
+impl<'de, T, A> Visitor<'de> for DynListVisitor<T, A>
+where
+    T: Deserialize<'de>,
+    A: Allocator + Default,
+{
+    type Value = DynList<T, A>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let mut list = DynList::new_in(A::default());
+
+        while let Some(value) = seq.next_element()? {
+            list.try_push_back(value)
+                .map_err(|error| de::Error::custom(error.into_parts().1))?;
+        }
+
+        Ok(list)
+    }
+}
+
+impl<'de, T, A> Deserialize<'de> for DynList<T, A>
+where
+    T: Deserialize<'de>,
+    A: Allocator + Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(DynListVisitor {
+            _phantom: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_test::{assert_ser_tokens, assert_tokens, Token};
+
+    use crate::DynList;
+
+    #[test]
+    fn serde() {
+        let mut list = DynList::<u8>::new();
+        list.extend([1, 2, 3]);
+
+        assert_tokens(
+            &list,
+            &[
+                Token::Seq { len: Some(3) },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::SeqEnd,
+            ],
+        );
+
+        let mut list = DynList::<str>::new();
+        list.push_back_copy_string("Hello");
+        list.push_back_copy_string("World");
+
+        assert_ser_tokens(
+            &list,
+            &[
+                Token::Seq { len: Some(2) },
+                Token::Str("Hello"),
+                Token::Str("World"),
+                Token::SeqEnd,
+            ],
+        );
+    }
+}
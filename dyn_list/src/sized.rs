@@ -1,13 +1,55 @@
-use core::alloc::Allocator;
+use core::{
+    alloc::Allocator,
+    borrow::Borrow,
+    ops::{Index, IndexMut},
+};
 
 use dynode::AllocateError;
 
 use crate::{
     iter::IntoIter,
-    node::{self, Header},
+    node::{self, Header, Node},
     DynList, Ends, MaybeUninitNode,
 };
 
+/// Nodes that have been allocated for a list but not linked into it yet.
+///
+/// These are chained by their `next` pointers and any that are left are deallocated when this is dropped, so nothing is leaked if an allocation fails or a clone panics.
+struct UnlinkedNodes<'a, T, A>
+where
+    A: Allocator,
+{
+    list: &'a mut DynList<T, A>,
+    front: Option<Node<T>>,
+}
+
+impl<T, A> UnlinkedNodes<'_, T, A>
+where
+    A: Allocator,
+{
+    fn pop(&mut self) -> Option<Node<T>> {
+        let node = self.front?;
+        // SAFETY:
+        // The header was initialised when the node was added to the chain.
+        self.front = unsafe { node.header_ptr().as_ref() }.next;
+        Some(node)
+    }
+}
+
+impl<T, A> Drop for UnlinkedNodes<'_, T, A>
+where
+    A: Allocator,
+{
+    fn drop(&mut self) {
+        while let Some(node) = self.pop() {
+            // SAFETY:
+            // The node was allocated by the list's allocator, its value is not initialised and it
+            // is not used again.
+            unsafe { node.deallocate(self.list.allocator.by_ref()) };
+        }
+    }
+}
+
 impl<T, A> DynList<T, A>
 where
     A: Allocator,
@@ -26,6 +68,7 @@ where
         };
 
         unsafe { node::try_new_sized(self, header) }
+            .map_err(|error| error.with_context("allocate_uninit_sized_front"))
     }
 
     #[inline]
@@ -42,6 +85,7 @@ where
         };
 
         unsafe { node::try_new_sized(self, header) }
+            .map_err(|error| error.with_context("allocate_uninit_sized_back"))
     }
 
     #[must_use]
@@ -66,10 +110,9 @@ where
     pub fn try_push_front(&mut self, value: T) -> Result<(), AllocateError<T>> {
         let node = match self.try_allocate_uninit_sized_front() {
             Ok(node) => node,
-            Err(error) => return Err(error.with_value(value)),
+            Err(error) => return Err(error.with_context("push_front").with_value(value)),
         };
-        unsafe { node.value_ptr().cast().write(value) };
-        unsafe { node.insert() };
+        node.write(value).insert();
         Ok(())
     }
 
@@ -81,10 +124,9 @@ where
     pub fn try_push_back(&mut self, value: T) -> Result<(), AllocateError<T>> {
         let node = match self.try_allocate_uninit_sized_back() {
             Ok(node) => node,
-            Err(error) => return Err(error.with_value(value)),
+            Err(error) => return Err(error.with_context("push_back").with_value(value)),
         };
-        unsafe { node.value_ptr().cast().write(value) };
-        unsafe { node.insert() };
+        node.write(value).insert();
         Ok(())
     }
 
@@ -92,16 +134,91 @@ where
     /// Pushes `value` to the front of the list.
     pub fn push_front(&mut self, value: T) {
         let node = self.allocate_uninit_sized_front();
-        unsafe { node.value_ptr().cast().write(value) };
-        unsafe { node.insert() };
+        node.write(value).insert();
     }
 
     #[inline]
     /// Pushes `value` to the back of the list.
     pub fn push_back(&mut self, value: T) {
         let node = self.allocate_uninit_sized_back();
-        unsafe { node.value_ptr().cast().write(value) };
-        unsafe { node.insert() };
+        node.write(value).insert();
+    }
+
+    /// Attempts to clone each element of `src` onto the back of the list.
+    ///
+    /// All of the nodes are allocated before any elements are cloned or linked into the list, so if allocation fails, the list is left unchanged.
+    /// If a clone panics, the elements that have already been cloned are kept and the rest of the nodes are deallocated.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<String>::new();
+    /// list.push_back(String::from("a"));
+    ///
+    /// let Ok(()) = list.try_extend_from_slice(&[String::from("b"), String::from("c")]) else {
+    ///     panic!("allocation failed");
+    /// };
+    /// assert!(list.iter().eq(["a", "b", "c"]));
+    /// ```
+    pub fn try_extend_from_slice(&mut self, src: &[T]) -> Result<(), AllocateError>
+    where
+        T: Clone,
+    {
+        let mut unlinked = UnlinkedNodes {
+            list: self,
+            front: None,
+        };
+
+        for _ in src {
+            let node = Node::try_allocate_sized_in(unlinked.list.allocator.by_ref())
+                .map_err(|error| error.with_context("extend_from_slice"))?;
+            let header = Header {
+                next: unlinked.front,
+                previous: None,
+            };
+            // SAFETY:
+            // The node was just allocated, so its header is valid for writes.
+            unsafe { node.header_ptr().write(header) };
+            unlinked.front = Some(node);
+        }
+
+        // Nothing can fail from here, other than a panicking clone
+        for value in src {
+            let value = value.clone();
+            debug_assert!(unlinked.front.is_some());
+            // SAFETY:
+            // A node was allocated for each element of `src`.
+            let node = unsafe { unlinked.pop().unwrap_unchecked() };
+
+            let header = Header {
+                next: None,
+                previous: unlinked.list.ends.map(|Ends { back, .. }| back),
+            };
+            // SAFETY:
+            // The node has been taken from the chain, so its header is not aliased.
+            unsafe { node.header_ptr().write(header) };
+            // SAFETY:
+            // - the node was allocated by the list's allocator for a `T`
+            // - its header has been initialised and it is not aliased
+            let node = unsafe { dynode::new_maybe_uninit(&mut *unlinked.list, node.into()) };
+            node.write(value).insert();
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    /// Clones each element of `src` onto the back of the list.
+    ///
+    /// See [`Self::try_extend_from_slice`] for more information.
+    pub fn extend_from_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        AllocateError::unwrap_result(self.try_extend_from_slice(src));
     }
 
     #[must_use]
@@ -118,10 +235,299 @@ where
         self.pop_back_node().map(|back| unsafe { back.take() })
     }
 
+    #[must_use]
+    /// Returns [`true`] if the list contains an element that, when borrowed as a `Q`, is equal to `value`.
+    ///
+    /// This is like [`Self::contains`], but compares through [`Borrow`], as [`HashMap`](std::collections::HashMap) lookups do.
+    /// This allows types like `Box<str>` or `Rc<[u8]>`, which do not implement [`PartialEq`] for their borrowed forms, to be searched with a borrowed value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<String>::from_iter([String::from("a"), String::from("b")]);
+    /// assert!(list.contains_borrowed("a"));
+    /// assert!(!list.contains_borrowed("c"));
+    ///
+    /// let list = DynList::<Box<str>>::from_iter([Box::from("a")]);
+    /// assert!(list.contains_borrowed("a"));
+    /// ```
+    pub fn contains_borrowed<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: PartialEq + ?Sized,
+    {
+        self.iter().any(|element| element.borrow() == value)
+    }
+
     #[must_use]
     #[inline]
     /// Converts the list to an iterator that yields the elements.
     pub const fn into_iter(self) -> IntoIter<T, A> {
         IntoIter::new(self)
     }
+
+    #[must_use]
+    /// Consumes the list, creating a new list with `f` applied to each element.
+    ///
+    /// The new list uses a clone of the list's allocator.
+    /// As the nodes may have different layouts, each element is moved into a new node, and the old node is deallocated as soon as its element has been taken.
+    /// If `f` panics, the remaining elements and the new list are dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<u8>::from_iter([1, 2, 3]);
+    /// let list = list.map(|value| u32::from(value) * 1000);
+    /// assert!(list.iter().eq(&[1000, 2000, 3000]));
+    /// ```
+    pub fn map<T2, F>(self, f: F) -> DynList<T2, A>
+    where
+        A: Clone,
+        F: FnMut(T) -> T2,
+    {
+        let allocator = self.allocator.clone();
+        DynList::from_iter_in(self.into_iter().map(f), allocator)
+    }
+
+    /// Attempts to split the list into two at the given index, moving the elements from `at` onwards into a new list in the given allocator.
+    ///
+    /// See [`Self::split_off_in`] for more information.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`] with the partially built list in it.
+    /// The element that failed to move is pushed back onto `self`, so `self` is left with the elements up to and including it, and the error's list has the ones after it, in order; use [`AllocateError::into_value`] to get it.
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`.
+    pub fn try_split_off_in<A2>(
+        &mut self,
+        at: usize,
+        allocator: A2,
+    ) -> Result<DynList<T, A2>, AllocateError<DynList<T, A2>>>
+    where
+        A2: Allocator,
+    {
+        let len = self.len;
+        assert!(
+            at <= len,
+            "cannot split off at index {at} in a list of length {len}"
+        );
+
+        let mut tail = DynList::new_in(allocator);
+        // Moving from the back keeps the order without needing to find the split point first
+        while self.len > at {
+            let value = self.pop_back();
+            debug_assert!(value.is_some());
+            // SAFETY:
+            // The list is not empty as its length is greater than `at`.
+            let value = unsafe { value.unwrap_unchecked() };
+            if let Err(error) = tail.try_push_front(value) {
+                let (value, error) = error.with_context("split_off_in").into_parts();
+                // The value's node was just deallocated from `self`'s allocator, so this should
+                // not fail
+                self.push_back(value);
+                return Err(error.with_value(tail));
+            }
+        }
+        Ok(tail)
+    }
+
+    #[must_use]
+    /// Splits the list into two at the given index, moving the elements from `at` onwards into a new list in the given allocator.
+    ///
+    /// Nodes cannot be moved between allocators, so unlike [`Self::split_off`], which relinks the nodes in *O*(1) after finding the split point, this allocates a new node for each moved element and deallocates the old one.
+    /// The elements themselves are moved, not cloned.
+    /// For unsized elements, see [`Self::split_off_clone_in`].
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use std::alloc::Global;
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<String>::new();
+    /// list.extend(["a", "b", "c"].map(String::from));
+    ///
+    /// let tail: DynList<String, Global> = list.split_off_in(1, Global);
+    /// assert!(list.iter().eq(["a"]));
+    /// assert!(tail.iter().eq(["b", "c"]));
+    /// ```
+    pub fn split_off_in<A2>(&mut self, at: usize, allocator: A2) -> DynList<T, A2>
+    where
+        A2: Allocator,
+    {
+        AllocateError::unwrap_result(self.try_split_off_in(at, allocator))
+    }
+}
+
+impl<T, A> Index<usize> for DynList<T, A>
+where
+    A: Allocator,
+{
+    type Output = T;
+
+    #[track_caller]
+    /// Gets a reference to the element at `index`.
+    ///
+    /// This walks from whichever end of the list is nearer; see [`DynList::get`].
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len();
+        let Some(value) = self.get(index) else {
+            panic!("index out of bounds: the len is {len} but the index is {index}");
+        };
+        value
+    }
+}
+
+impl<T, A> IndexMut<usize> for DynList<T, A>
+where
+    A: Allocator,
+{
+    #[track_caller]
+    /// Gets a mutable reference to the element at `index`.
+    ///
+    /// This walks from whichever end of the list is nearer; see [`DynList::get_mut`].
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        let Some(value) = self.get_mut(index) else {
+            panic!("index out of bounds: the len is {len} but the index is {index}");
+        };
+        value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+    use std::panic;
+
+    use crate::{alloc::Global, test::FailingAllocator, DynList};
+
+    #[test]
+    fn index() {
+        let mut list = DynList::<i32>::new();
+        list.extend([1, 2, 3]);
+
+        assert_eq!(list[0], 1);
+        assert_eq!(list[2], 3);
+
+        list[1] = 5;
+        assert!(list.iter().eq(&[1, 5, 3]));
+    }
+
+    #[test]
+    #[should_panic = "index out of bounds: the len is 3 but the index is 3"]
+    fn index_out_of_bounds() {
+        let mut list = DynList::<i32>::new();
+        list.extend([1, 2, 3]);
+
+        let _ = list[3];
+    }
+
+    #[test]
+    fn map() {
+        let list = DynList::<u8>::new().map(u32::from);
+        assert!(list.is_empty());
+
+        let list = DynList::<u8>::from_iter([1, 2, 255]);
+        let list: DynList<u32> = list.map(|value| u32::from(value) << 16);
+        list.check_debug();
+        assert!(list.iter().eq(&[1 << 16, 2 << 16, 255 << 16]));
+
+        let list = list.map(|value| value.to_string());
+        list.check_debug();
+        assert!(list.iter().eq(["65536", "131072", "16711680"]));
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct PanicOnClone(bool);
+        impl Clone for PanicOnClone {
+            fn clone(&self) -> Self {
+                assert!(!self.0, "clone panicked");
+                Self(self.0)
+            }
+        }
+
+        let mut list = DynList::<String>::new();
+        list.extend_from_slice(&[]);
+        assert!(list.is_empty());
+
+        list.extend_from_slice(&[String::from("a"), String::from("b")]);
+        list.extend_from_slice(&[String::from("c")]);
+        list.check_debug();
+        assert!(list.iter().eq(["a", "b", "c"]));
+
+        // A failed allocation leaves the list unchanged
+        let budget = Cell::new(usize::MAX);
+        let mut list = DynList::<u8, _>::new_in(FailingAllocator { budget: &budget });
+        list.extend_from_slice(&[1, 2]);
+        budget.set(2);
+        assert!(list.try_extend_from_slice(&[3, 4, 5]).is_err());
+        list.check_debug();
+        assert!(list.iter().eq(&[1, 2]));
+
+        let mut list = DynList::<PanicOnClone>::new();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.extend_from_slice(&[PanicOnClone(false), PanicOnClone(true), PanicOnClone(false)]);
+        }));
+        assert!(result.is_err());
+        list.check_debug();
+        assert!(list.iter().eq(&[PanicOnClone(false)]));
+    }
+
+    #[test]
+    fn split_off_in() {
+        let budget = Cell::new(usize::MAX);
+        let mut list = DynList::<String, _>::new_in(FailingAllocator { budget: &budget });
+        list.extend(["a", "b", "c", "d"].map(String::from));
+
+        // The moved elements are allocated by the new list's allocator
+        budget.set(0);
+        let mut tail = list.split_off_in(2, Global);
+        list.check_debug();
+        tail.check_debug();
+        assert!(list.iter().eq(["a", "b"]));
+        assert!(tail.iter().eq(["c", "d"]));
+
+        let empty = tail.split_off_in(2, Global);
+        assert!(empty.is_empty());
+        assert_eq!(tail.len(), 2);
+
+        let whole = list.split_off_in(0, Global);
+        assert!(list.is_empty());
+        list.check_debug();
+        assert!(whole.iter().eq(["a", "b"]));
+
+        // On failure, the element that failed is pushed back and the moved elements are returned
+        // in the error
+        let mut list = DynList::<String>::new();
+        list.extend(["a", "b", "c", "d"].map(String::from));
+        budget.set(1);
+        let partial = list
+            .try_split_off_in(1, FailingAllocator { budget: &budget })
+            .unwrap_err()
+            .into_value();
+        list.check_debug();
+        partial.check_debug();
+        assert!(list.iter().eq(["a", "b", "c"]));
+        assert!(partial.iter().eq(["d"]));
+    }
+
+    #[test]
+    #[should_panic = "cannot split off at index 2 in a list of length 1"]
+    fn split_off_in_out_of_bounds() {
+        let mut list = DynList::<u8>::new();
+        list.push_back(0);
+        let _ = list.split_off_in(2, Global);
+    }
 }
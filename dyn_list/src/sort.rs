@@ -0,0 +1,624 @@
+use core::{alloc::Allocator, cmp::Ordering, mem, ptr::NonNull};
+
+use crate::{node::Node, DynList, Ends};
+
+/// Gets the node after `node`.
+///
+/// # Safety
+/// `node` must be a node from a list that is being sorted.
+unsafe fn next<U>(node: Node<U>) -> Option<Node<U>>
+where
+    U: ?Sized,
+{
+    // SAFETY:
+    // As the node is in the list, its header must be properly initialised.
+    unsafe { node.header_ptr().as_ref() }.next
+}
+
+/// Sets the node after `node`.
+///
+/// # Safety
+/// `node` must be a node from a list that is being sorted.
+unsafe fn set_next<U>(node: Node<U>, next: Option<Node<U>>)
+where
+    U: ?Sized,
+{
+    // SAFETY:
+    // As the list is mutably borrowed while sorting, the node's header is not aliased.
+    unsafe { node.header_ptr().as_mut() }.next = next;
+}
+
+/// Gets a reference to `node`'s value.
+///
+/// # Safety
+/// `node` must be a node from a list that is being sorted.
+const unsafe fn value<'a, U>(node: Node<U>) -> &'a U
+where
+    U: ?Sized,
+{
+    // SAFETY:
+    // As the node is in the list, its metadata must be properly initialised.
+    let ptr = unsafe { node.data_ptr() };
+    // SAFETY:
+    // As the node is in the list, its value must be properly initialised.
+    unsafe { ptr.as_ref() }
+}
+
+/// Cuts the chain starting at `start` after `n` nodes, returning the rest of the chain.
+///
+/// # Safety
+/// `start` must be a node from a list that is being sorted, and `n` must not be zero.
+unsafe fn cut_after<U>(start: Node<U>, n: usize) -> Option<Node<U>>
+where
+    U: ?Sized,
+{
+    let mut node = start;
+    for _ in 1..n {
+        // SAFETY:
+        // `node` is in the same chain as `start`.
+        node = unsafe { next(node) }?;
+    }
+
+    // SAFETY:
+    // As above.
+    let rest = unsafe { next(node) };
+    // SAFETY:
+    // As above.
+    unsafe { set_next(node, None) };
+    rest
+}
+
+/// Relinks the nodes of a list while sorting or merging.
+///
+/// While this exists, the list's nodes are held in separate chains that are only linked by their `next` pointers and end with [`None`].
+/// When this is dropped, including if a comparison panics, the chains are joined back together and the `previous` pointers and ends are restored, so the list is always left valid.
+struct Sorter<'a, U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    list: &'a mut DynList<U, A>,
+    // The nodes that have been merged so far
+    head: Option<Node<U>>,
+    tail: Option<Node<U>>,
+    // The runs being merged
+    a: Option<Node<U>>,
+    b: Option<Node<U>>,
+    // The nodes that have not been visited yet
+    rest: Option<Node<U>>,
+}
+
+impl<U, A> Sorter<'_, U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    /// Appends the chain starting at `start` to the merged nodes.
+    fn push_chain(&mut self, start: Node<U>) {
+        match self.tail {
+            // SAFETY:
+            // `tail` is a node from the list.
+            Some(tail) => unsafe { set_next(tail, Some(start)) },
+            None => self.head = Some(start),
+        }
+
+        let mut tail = start;
+        // SAFETY:
+        // `tail` is a node from the list.
+        while let Some(next) = unsafe { next(tail) } {
+            tail = next;
+        }
+        self.tail = Some(tail);
+    }
+
+    /// Merges runs `a` and `b` onto the end of the merged nodes.
+    ///
+    /// Where elements are equal, the ones from `a` come first.
+    fn merge<F>(&mut self, compare: &mut F)
+    where
+        F: FnMut(&U, &U) -> Ordering,
+    {
+        while let (Some(a), Some(b)) = (self.a, self.b) {
+            // SAFETY:
+            // `a` and `b` are nodes from the list.
+            let take_b = compare(unsafe { value(b) }, unsafe { value(a) }) == Ordering::Less;
+            let node = if take_b {
+                // SAFETY:
+                // As above.
+                self.b = unsafe { next(b) };
+                b
+            } else {
+                // SAFETY:
+                // As above.
+                self.a = unsafe { next(a) };
+                a
+            };
+
+            // SAFETY:
+            // `node` is a node from the list.
+            unsafe { set_next(node, None) };
+            self.push_chain(node);
+        }
+
+        if let Some(remaining) = self.a.take().or_else(|| self.b.take()) {
+            self.push_chain(remaining);
+        }
+    }
+}
+
+impl<U, A> Drop for Sorter<'_, U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    fn drop(&mut self) {
+        let chains = [self.a.take(), self.b.take(), self.rest.take()];
+        for start in chains.into_iter().flatten() {
+            self.push_chain(start);
+        }
+
+        let Some(front) = self.head else {
+            debug_assert_eq!(self.list.len, 0);
+            self.list.ends = None;
+            return;
+        };
+
+        let mut previous = None;
+        let mut node = front;
+        loop {
+            // SAFETY:
+            // As the list is mutably borrowed while sorting, the node's header is not aliased.
+            let header = unsafe { node.header_ptr().as_mut() };
+            header.previous = previous;
+            previous = Some(node);
+
+            let Some(next) = header.next else {
+                break;
+            };
+            node = next;
+        }
+
+        self.list.ends = Some(Ends { front, back: node });
+    }
+}
+
+impl<U, A> DynList<U, A>
+where
+    U: ?Sized,
+    A: Allocator,
+{
+    /// Sorts the list.
+    ///
+    /// This is a stable merge sort, so equal elements keep their order.
+    /// The nodes are relinked rather than having their values moved, so this works for unsized elements, and it takes *O*(n log n) time without allocating.
+    ///
+    /// If the implementation of [`Ord`] for `U` panics, the list is left in a valid but unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_copy_string("c");
+    /// list.push_back_copy_string("a");
+    /// list.push_back_copy_string("b");
+    ///
+    /// list.sort();
+    /// assert!(list.iter().eq(["a", "b", "c"]));
+    /// ```
+    pub fn sort(&mut self)
+    where
+        U: Ord,
+    {
+        self.sort_by(U::cmp);
+    }
+
+    /// Sorts the list with a comparison function.
+    ///
+    /// See [`Self::sort`] for more information.
+    /// If `compare` panics, the list is left in a valid but unspecified order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<[u8]>::new();
+    /// list.push_back_copy_array(&[1, 2, 3]);
+    /// list.push_back_copy_array(&[4]);
+    /// list.push_back_copy_array(&[5, 6]);
+    ///
+    /// list.sort_by(|a, b| a.len().cmp(&b.len()));
+    /// assert!(list.iter().eq([&[4][..], &[5, 6], &[1, 2, 3]]));
+    /// ```
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&U, &U) -> Ordering,
+    {
+        let Some(Ends { front, .. }) = self.ends.take() else {
+            return;
+        };
+
+        let mut sorter = Sorter {
+            list: self,
+            head: None,
+            tail: None,
+            a: None,
+            b: None,
+            rest: Some(front),
+        };
+
+        // Merge runs of `width` nodes, doubling `width` until there is only one run
+        let mut width = 1;
+        loop {
+            let mut merges = 0_usize;
+            while let Some(start) = sorter.rest.take() {
+                merges += 1;
+                sorter.a = Some(start);
+                // SAFETY:
+                // `start` is a node from the list and `width` is not zero.
+                sorter.b = unsafe { cut_after(start, width) };
+                // SAFETY:
+                // As above.
+                sorter.rest = sorter.b.and_then(|b| unsafe { cut_after(b, width) });
+                sorter.merge(&mut compare);
+            }
+
+            if merges <= 1 {
+                break;
+            }
+            sorter.rest = sorter.head.take();
+            sorter.tail = None;
+            width *= 2;
+        }
+    }
+
+    /// Merges two sorted lists, moving all of the nodes from `other` into the list.
+    ///
+    /// If both lists are sorted, the result is sorted, with elements from `self` coming before equal elements from `other`.
+    /// If either list is not sorted, the result is unspecified, but all of the elements are kept.
+    /// No allocation or copying takes place, so this takes *O*(n) time.
+    ///
+    /// As with [`Self::append`], the nodes from `other` are deallocated with `self`'s allocator, as lists with the same allocator type are assumed to be able to deallocate each other's nodes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<u8>::from_iter([1, 4, 6]);
+    /// list.merge(DynList::from_iter([2, 3, 5]));
+    /// assert!(list.iter().copied().eq(1..=6));
+    /// ```
+    pub fn merge(&mut self, other: Self)
+    where
+        U: Ord,
+    {
+        self.merge_by(other, U::cmp);
+    }
+
+    /// Merges two lists that are sorted by a comparison function.
+    ///
+    /// See [`Self::merge`] for more information.
+    /// If `compare` panics, the list is left with all of the elements in a valid but unspecified order.
+    pub fn merge_by<F>(&mut self, mut other: Self, mut compare: F)
+    where
+        F: FnMut(&U, &U) -> Ordering,
+    {
+        let Some(Ends {
+            front: other_front, ..
+        }) = other.ends.take()
+        else {
+            return;
+        };
+        self.len += mem::take(&mut other.len);
+        let front = self.ends.take().map(|Ends { front, .. }| front);
+
+        let mut sorter = Sorter {
+            list: self,
+            head: None,
+            tail: None,
+            a: front,
+            b: Some(other_front),
+            rest: None,
+        };
+        sorter.merge(&mut compare);
+    }
+
+    #[must_use]
+    /// Checks if the list is sorted.
+    ///
+    /// This compares each pair of adjacent elements with [`PartialOrd::le`], stopping at the first pair that is out of order.
+    /// It takes *O*(n) time without allocating, and empty and single-element lists are always sorted.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.extend(["a", "b", "b"]);
+    /// assert!(list.is_sorted());
+    ///
+    /// list.push_back_copy_string("a");
+    /// assert!(!list.is_sorted());
+    /// ```
+    pub fn is_sorted(&self) -> bool
+    where
+        U: PartialOrd,
+    {
+        self.is_sorted_by(U::le)
+    }
+
+    /// Checks if the list is sorted with a comparison function.
+    ///
+    /// `compare` should return `true` if its arguments are in order.
+    /// See [`Self::is_sorted`] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<[u8]>::new();
+    /// list.push_back_copy_array(&[3]);
+    /// list.push_back_copy_array(&[1, 2]);
+    ///
+    /// assert!(list.is_sorted_by(|a, b| a.len() <= b.len()));
+    /// assert!(!list.is_sorted_by(|a, b| a <= b));
+    /// ```
+    pub fn is_sorted_by<F>(&self, mut compare: F) -> bool
+    where
+        F: FnMut(&U, &U) -> bool,
+    {
+        self.pairs().all(|(a, b)| compare(a, b))
+    }
+}
+
+impl<T, A> DynList<T, A>
+where
+    A: Allocator,
+{
+    /// Inserts `value` into a sorted list, keeping it sorted, and returns a reference to it.
+    ///
+    /// The value is inserted after any elements that are equal to it, so equal elements keep their insertion order.
+    /// Finding the position scans the list from the front, so this takes *O*(n) time.
+    /// If the list is not sorted, the value is inserted before the first element that is greater than it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<i32>::new();
+    /// for value in [3, 1, 2] {
+    ///     list.insert_sorted(value);
+    /// }
+    /// assert!(list.iter().eq(&[1, 2, 3]));
+    ///
+    /// *list.insert_sorted(0) -= 10;
+    /// assert_eq!(list.front(), Some(&-10));
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> &mut T
+    where
+        T: Ord,
+    {
+        self.insert_sorted_by(value, T::cmp)
+    }
+
+    /// Inserts `value` into a list that is sorted with a comparison function, keeping it sorted, and returns a reference to it.
+    ///
+    /// See [`Self::insert_sorted`] for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<&str>::new();
+    /// for value in ["ccc", "a", "bb", "d"] {
+    ///     list.insert_sorted_by(value, |a, b| a.len().cmp(&b.len()));
+    /// }
+    /// assert!(list.iter().eq(&["a", "d", "bb", "ccc"]));
+    /// ```
+    pub fn insert_sorted_by<F>(&mut self, value: T, compare: F) -> &mut T
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.insert_sorted_inner(value, false, compare)
+    }
+
+    /// Inserts `value` into a sorted list if there is not already an equal element, and returns a reference to the inserted or existing element.
+    ///
+    /// If there is an equal element, `value` is dropped and the list is unchanged.
+    /// This allows the list to be used as an ordered set.
+    /// As with [`Self::insert_sorted`], this takes *O*(n) time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<i32>::new();
+    /// for value in [3, 1, 3, 2, 1] {
+    ///     list.insert_sorted_unique(value);
+    /// }
+    /// assert!(list.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn insert_sorted_unique(&mut self, value: T) -> &mut T
+    where
+        T: Ord,
+    {
+        self.insert_sorted_inner(value, true, T::cmp)
+    }
+
+    /// Finds the position for `value` and inserts it, or, if `unique` is [`true`], returns an equal element if there is one.
+    fn insert_sorted_inner<F>(&mut self, value: T, unique: bool, mut compare: F) -> &mut T
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut cursor = self.cursor_front_mut();
+        let existing = loop {
+            let Some(current) = cursor.current() else {
+                break None;
+            };
+            match compare(current, &value) {
+                Ordering::Equal if unique => break Some(NonNull::from(current)),
+                Ordering::Less | Ordering::Equal => cursor.move_next(),
+                Ordering::Greater => break None,
+            }
+        };
+
+        let mut ptr = existing.unwrap_or_else(|| {
+            // If the cursor is on the "ghost" element, this inserts at the back
+            let node = cursor.allocate_uninit_sized_before().write(value);
+            // SAFETY:
+            // The list keeps inserted nodes allocated and does not access their values while it is
+            // mutably borrowed.
+            NonNull::from(unsafe { node.insert_and_get() })
+        });
+
+        // SAFETY:
+        // The node is in the list, which is mutably borrowed for as long as the reference, so it is
+        // not deallocated or accessed through the list.
+        unsafe { ptr.as_mut() }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::panic;
+
+    use crate::DynList;
+
+    #[test]
+    fn sort() {
+        let mut list = DynList::<i32>::new();
+        list.sort();
+        assert!(list.is_empty());
+
+        list.extend([5, -3, 9, 0, 0, 12, -7, 4, 1]);
+        list.sort();
+        list.check_debug();
+        assert!(list.iter().eq(&[-7, -3, 0, 0, 1, 4, 5, 9, 12]));
+
+        let mut list = DynList::<str>::new();
+        for string in ["pear", "apple", "fig", "banana", "apple"] {
+            list.push_back_copy_string(string);
+        }
+        list.sort();
+        list.check_debug();
+        assert!(list.iter().eq(["apple", "apple", "banana", "fig", "pear"]));
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let mut list = DynList::<i32>::new();
+        assert_eq!(*list.insert_sorted(5), 5);
+        list.check_debug();
+
+        // Insertions at the front, back and middle
+        list.insert_sorted(1);
+        list.insert_sorted(9);
+        *list.insert_sorted(3) += 1;
+        list.check_debug();
+        assert!(list.iter().eq(&[1, 4, 5, 9]));
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&9));
+
+        // Equal elements keep their insertion order
+        let mut list = DynList::<(u8, char)>::new();
+        for value in [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')] {
+            list.insert_sorted_by(value, |a, b| a.0.cmp(&b.0));
+        }
+        list.check_debug();
+        assert!(list.iter().eq(&[(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]));
+
+        let mut list = DynList::<String>::new();
+        for value in ["b", "a", "c", "b", "a"] {
+            list.insert_sorted_unique(value.to_owned());
+        }
+        list.insert_sorted_unique(String::from("b")).push('!');
+        list.check_debug();
+        assert!(list.iter().eq(["a", "b!", "c"]));
+    }
+
+    #[test]
+    fn sort_by_is_stable() {
+        let mut list = DynList::<[u8]>::new();
+        for slice in [&[3, 3, 3][..], &[1], &[2, 2], &[0], &[4, 4]] {
+            list.push_back_copy_array(slice);
+        }
+
+        list.sort_by(|a, b| a.len().cmp(&b.len()));
+        list.check_debug();
+        assert!(list
+            .iter()
+            .eq([&[1][..], &[0], &[2, 2], &[4, 4], &[3, 3, 3]]));
+    }
+
+    #[test]
+    fn sort_panic() {
+        let mut list = (0..10).rev().collect::<DynList<u8>>();
+
+        let mut comparisons = 0;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                comparisons += 1;
+                assert!(comparisons < 8, "comparison panicked");
+                a.cmp(b)
+            });
+        }));
+        assert!(result.is_err());
+
+        // The list is still valid and contains all of the elements
+        list.check_debug();
+        assert_eq!(list.len(), 10);
+        list.sort();
+        assert!(list.iter().copied().eq(0..10));
+    }
+
+    #[test]
+    fn is_sorted() {
+        let mut list = DynList::<f32>::new();
+        assert!(list.is_sorted());
+        list.push_back(1.0);
+        assert!(list.is_sorted());
+
+        list.extend([1.0, 2.5, 4.0]);
+        assert!(list.is_sorted());
+        assert!(!list.is_sorted_by(|a, b| a > b));
+        list.push_back(f32::NAN);
+        assert!(!list.is_sorted());
+
+        let mut calls = 0;
+        let list = DynList::<u8>::from_iter([1, 0, 2, 3, 4]);
+        assert!(!list.is_sorted_by(|a, b| {
+            calls += 1;
+            a <= b
+        }));
+        // Stops at the first pair that is out of order
+        assert_eq!(calls, 1);
+
+        let mut list = DynList::<str>::new();
+        list.extend(["apple", "banana", "fig"]);
+        assert!(list.is_sorted());
+        list.push_front_copy_string("pear");
+        assert!(!list.is_sorted());
+    }
+
+    #[test]
+    fn merge() {
+        let mut list = DynList::<i32>::new();
+        list.merge(DynList::new());
+        assert!(list.is_empty());
+
+        list.merge(DynList::from_iter([-1, 3]));
+        list.check_debug();
+        assert!(list.iter().eq(&[-1, 3]));
+        list.merge(DynList::new());
+        assert!(list.iter().eq(&[-1, 3]));
+
+        list.merge(DynList::from_iter([-2, 0, 3, 7]));
+        list.check_debug();
+        assert!(list.iter().eq(&[-2, -1, 0, 3, 3, 7]));
+
+        let mut list = DynList::<[u8]>::new();
+        list.push_back_copy_array(&[1]);
+        list.push_back_copy_array(&[1, 2]);
+        let mut other = DynList::<[u8]>::new();
+        other.push_back_copy_array(&[]);
+        other.push_back_copy_array(&[2]);
+        other.push_back_copy_array(&[2, 3, 4]);
+
+        // Equal elements from `self` come first
+        list.merge_by(other, |a, b| a.len().cmp(&b.len()));
+        list.check_debug();
+        assert!(list.iter().eq([&[][..], &[1], &[2], &[1, 2], &[2, 3, 4]]));
+    }
+}
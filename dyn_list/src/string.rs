@@ -1,4 +1,14 @@
-use core::alloc::Allocator;
+use core::{
+    alloc::Allocator,
+    error::Error,
+    fmt,
+    mem::MaybeUninit,
+    slice,
+    str::{self, Utf8Error},
+};
+
+#[cfg(feature = "alloc")]
+use crate::alloc;
 
 use dynode::AllocateError;
 
@@ -17,14 +27,63 @@ where
     /// All byte arrays in the list must be valid UTF-8.
     /// For more information, see [`str::from_utf8_unchecked`](core::str::from_utf8_unchecked).
     pub unsafe fn from_utf8_unchecked(bytes: DynList<[u8], A>) -> Self {
-        let (ends, allocator) = bytes.into_raw_parts();
-        unsafe { Self::from_raw_parts(ends, allocator) }
+        let (ends, len, allocator) = bytes.into_raw_parts();
+        // SAFETY:
+        // `str` has the same layout and metadata as `[u8]` and the bytes are valid UTF-8 (safety
+        // condition).
+        unsafe { Self::from_raw_parts(ends, len, allocator) }
+    }
+
+    /// Converts a list of byte arrays to a list of strings, checking that each byte array is valid UTF-8.
+    ///
+    /// The nodes are reused, so no allocation or copying takes place.
+    ///
+    /// # Errors
+    /// If any byte array is not valid UTF-8, this returns a [`FromUtf8Error`] containing the original list and the index of the first invalid node.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut bytes = DynList::<[u8]>::new();
+    /// bytes.push_back_copy_array(b"Hello");
+    /// bytes.push_back_copy_array(b"World");
+    ///
+    /// let strings = DynList::<str>::from_utf8(bytes).unwrap();
+    /// assert!(strings.iter().eq(["Hello", "World"]));
+    ///
+    /// let mut bytes = DynList::<[u8]>::new();
+    /// bytes.push_back_copy_array(b"Hello");
+    /// bytes.push_back_copy_array(&[0xff]);
+    ///
+    /// let error = DynList::<str>::from_utf8(bytes).unwrap_err();
+    /// assert_eq!(error.index(), 1);
+    /// assert_eq!(error.into_bytes().len(), 2);
+    /// ```
+    pub fn from_utf8(bytes: DynList<[u8], A>) -> Result<Self, FromUtf8Error<A>> {
+        let invalid = bytes.iter().enumerate().find_map(|(index, bytes)| {
+            core::str::from_utf8(bytes)
+                .err()
+                .map(|error| (index, error))
+        });
+
+        match invalid {
+            // SAFETY:
+            // All of the byte arrays have been checked to be valid UTF-8.
+            None => Ok(unsafe { Self::from_utf8_unchecked(bytes) }),
+            Some((index, error)) => Err(FromUtf8Error {
+                bytes,
+                index,
+                error,
+            }),
+        }
     }
 
     /// Converts the list of strings to a list of byte arrays.
     pub fn into_bytes(self) -> DynList<[u8], A> {
-        let (ends, allocator) = self.into_raw_parts();
-        unsafe { DynList::from_raw_parts(ends, allocator) }
+        let (ends, len, allocator) = self.into_raw_parts();
+        // SAFETY:
+        // `[u8]` has the same layout and metadata as `str` and has weaker invariants.
+        unsafe { DynList::from_raw_parts(ends, len, allocator) }
     }
 
     /// Attempts to allocate an uninitialised string node at the front of the list.
@@ -41,6 +100,7 @@ where
         };
 
         node::try_new_string(self, length, header)
+            .map_err(|error| error.with_context("allocate_uninit_string_front"))
     }
 
     /// Attempts to allocate an uninitialised string node at the back of the list.
@@ -57,6 +117,7 @@ where
         };
 
         node::try_new_string(self, length, header)
+            .map_err(|error| error.with_context("allocate_uninit_string_back"))
     }
 
     #[must_use]
@@ -71,12 +132,50 @@ where
         AllocateError::unwrap_result(self.try_allocate_uninit_string_back(length))
     }
 
+    #[must_use]
+    /// Gets the length of the string at the front of the list.
+    ///
+    /// This reads the length stored in the node, so the string's bytes are not accessed.
+    /// If the list is empty, this returns [`None`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// assert_eq!(list.front_len(), None);
+    ///
+    /// list.extend(["Hello", "é"]);
+    /// assert_eq!(list.front_len(), Some(5));
+    /// // The length is in bytes
+    /// assert_eq!(list.back_len(), Some(2));
+    /// ```
+    pub fn front_len(&self) -> Option<usize> {
+        let Ends { front, .. } = self.ends?;
+        // SAFETY:
+        // The node is in the list, so it has not been deallocated.
+        Some(unsafe { front.metadata() })
+    }
+
+    #[must_use]
+    /// Gets the length of the string at the back of the list.
+    ///
+    /// This reads the length stored in the node, so the string's bytes are not accessed.
+    /// If the list is empty, this returns [`None`].
+    pub fn back_len(&self) -> Option<usize> {
+        let Ends { back, .. } = self.ends?;
+        // SAFETY:
+        // The node is in the list, so it has not been deallocated.
+        Some(unsafe { back.metadata() })
+    }
+
     /// Attempts to copy the string slice `src` and push it to the front of the list.
     ///
     /// # Errors
     /// If allocation fails, this will return an [`AllocateError`].
     pub fn try_push_front_copy_string(&mut self, src: &str) -> Result<(), AllocateError> {
-        let mut node = self.try_allocate_uninit_string_front(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_string_front(src.len())
+            .map_err(|error| error.with_context("push_front_copy_string"))?;
         node.copy_from_str(src);
         unsafe { node.insert() };
         Ok(())
@@ -87,7 +186,9 @@ where
     /// # Errors
     /// If allocation fails, this will return an [`AllocateError`].
     pub fn try_push_back_copy_string(&mut self, src: &str) -> Result<(), AllocateError> {
-        let mut node = self.try_allocate_uninit_string_back(src.len())?;
+        let mut node = self
+            .try_allocate_uninit_string_back(src.len())
+            .map_err(|error| error.with_context("push_back_copy_string"))?;
         node.copy_from_str(src);
         unsafe { node.insert() };
         Ok(())
@@ -106,4 +207,313 @@ where
         node.copy_from_str(src);
         unsafe { node.insert() };
     }
+
+    /// Fills a new node's bytes with zeros and lets `f` write to them, then checks that they are valid UTF-8.
+    fn fill_string_with<F>(node: &mut MaybeUninitNode<str, A>, f: F) -> Result<(), Utf8Error>
+    where
+        F: FnOnce(&mut [u8]),
+    {
+        let bytes = node.as_bytes_mut();
+        bytes.fill(MaybeUninit::new(0));
+        // SAFETY:
+        // All of the bytes have been initialised above.
+        let bytes =
+            unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr().cast::<u8>(), bytes.len()) };
+        f(bytes);
+        str::from_utf8(bytes).map(|_| ())
+    }
+
+    /// Pushes a string of `length` bytes to the front of the list, which is written by `f`.
+    ///
+    /// The bytes are zeroed before `f` is called, so any that it does not write are `'\0'`.
+    /// Once `f` returns, the bytes are checked, and the node is only inserted if they are valid UTF-8.
+    ///
+    /// # Errors
+    /// If the bytes are not valid UTF-8, the node is deallocated and this will return a [`Utf8Error`].
+    pub fn push_front_string_with<F>(&mut self, length: usize, f: F) -> Result<(), Utf8Error>
+    where
+        F: FnOnce(&mut [u8]),
+    {
+        let mut node = self.allocate_uninit_string_front(length);
+        Self::fill_string_with(&mut node, f)?;
+        // SAFETY:
+        // The bytes have been initialised and are valid UTF-8.
+        unsafe { node.insert() };
+        Ok(())
+    }
+
+    /// Pushes a string of `length` bytes to the back of the list, which is written by `f`.
+    ///
+    /// See [`Self::push_front_string_with`] for more information.
+    ///
+    /// # Errors
+    /// If the bytes are not valid UTF-8, the node is deallocated and this will return a [`Utf8Error`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let mut list = DynList::<str>::new();
+    /// list.push_back_string_with(5, |bytes| bytes.copy_from_slice(b"Hello")).unwrap();
+    /// assert_eq!(list.back(), Some("Hello"));
+    ///
+    /// assert!(list.push_back_string_with(1, |bytes| bytes[0] = 0xFF).is_err());
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn push_back_string_with<F>(&mut self, length: usize, f: F) -> Result<(), Utf8Error>
+    where
+        F: FnOnce(&mut [u8]),
+    {
+        let mut node = self.allocate_uninit_string_back(length);
+        Self::fill_string_with(&mut node, f)?;
+        // SAFETY:
+        // The bytes have been initialised and are valid UTF-8.
+        unsafe { node.insert() };
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    /// Concatenates the strings in the list into a single [`String`](alloc::String).
+    ///
+    /// The total length is calculated first, so this allocates only once.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<str>::from_iter(["Hello", ", ", "World!"]);
+    /// assert_eq!(list.concat(), "Hello, World!");
+    /// ```
+    pub fn concat(&self) -> alloc::String {
+        let length = self.iter().map(str::len).sum();
+        let mut string = alloc::String::with_capacity(length);
+        for item in self {
+            string.push_str(item);
+        }
+        string
+    }
+
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    /// Joins the strings in the list into a single [`String`](alloc::String), placing `separator` between each of them.
+    ///
+    /// The total length is calculated first, so this allocates only once.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dyn_list::DynList;
+    /// let list = DynList::<str>::from_iter(["a", "b", "c"]);
+    /// assert_eq!(list.join(", "), "a, b, c");
+    /// ```
+    pub fn join(&self, separator: &str) -> alloc::String {
+        let length =
+            self.iter().map(str::len).sum::<usize>() + separator.len() * self.len.saturating_sub(1);
+        let mut string = alloc::String::with_capacity(length);
+
+        let mut iter = self.iter();
+        if let Some(first) = iter.next() {
+            string.push_str(first);
+        }
+        for item in iter {
+            string.push_str(separator);
+            string.push_str(item);
+        }
+        string
+    }
+}
+
+impl<'a, A> Extend<&'a str> for DynList<str, A>
+where
+    A: Allocator,
+{
+    fn extend<T: IntoIterator<Item = &'a str>>(&mut self, iter: T) {
+        for item in iter {
+            self.push_back_copy_string(item);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a> FromIterator<&'a str> for DynList<str> {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<A> TryFrom<DynList<[u8], A>> for DynList<str, A>
+where
+    A: Allocator,
+{
+    type Error = FromUtf8Error<A>;
+
+    #[inline]
+    fn try_from(bytes: DynList<[u8], A>) -> Result<Self, Self::Error> {
+        Self::from_utf8(bytes)
+    }
+}
+
+/// The error returned when converting a list of byte arrays to a list of strings fails.
+///
+/// This is created by [`DynList::from_utf8`].
+pub struct FromUtf8Error<
+    #[cfg(feature = "alloc")] A = alloc::Global,
+    #[cfg(not(feature = "alloc"))] A,
+> where
+    A: Allocator,
+{
+    bytes: DynList<[u8], A>,
+    index: usize,
+    error: Utf8Error,
+}
+
+impl<A> FromUtf8Error<A>
+where
+    A: Allocator,
+{
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the list that failed to convert.
+    pub const fn as_bytes(&self) -> &DynList<[u8], A> {
+        &self.bytes
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the list that failed to convert.
+    pub fn into_bytes(self) -> DynList<[u8], A> {
+        self.bytes
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the index of the first node that is not valid UTF-8.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the error from validating the node at [`Self::index`].
+    pub const fn utf8_error(&self) -> Utf8Error {
+        self.error
+    }
+}
+
+impl<A> fmt::Debug for FromUtf8Error<A>
+where
+    A: Allocator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FromUtf8Error")
+            .field("bytes", &self.bytes)
+            .field("index", &self.index)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
+impl<A> fmt::Display for FromUtf8Error<A>
+where
+    A: Allocator,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "node {} is not valid UTF-8: {}", self.index, self.error)
+    }
+}
+
+impl<A> Error for FromUtf8Error<A>
+where
+    A: Allocator,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::DynList;
+
+    #[test]
+    fn lengths() {
+        let mut list = DynList::<str>::new();
+        assert_eq!((list.front_len(), list.back_len()), (None, None));
+
+        list.extend(["ab", "", "ĉ"]);
+        assert_eq!((list.front_len(), list.back_len()), (Some(2), Some(2)));
+
+        let mut cursor = list.cursor_back_mut();
+        for len in [Some(2), Some(0), Some(2), None] {
+            assert_eq!(cursor.current_len(), len);
+            assert_eq!(cursor.as_cursor().current_len(), len);
+            assert_eq!(cursor.current().map(|string| string.len()), len);
+            cursor.move_previous();
+        }
+    }
+
+    #[test]
+    fn concat() {
+        let mut list = DynList::<str>::new();
+        assert_eq!(list.concat(), "");
+        assert_eq!(list.join(", "), "");
+
+        list.push_back_copy_string("one");
+        assert_eq!(list.concat(), "one");
+        assert_eq!(list.join(", "), "one");
+
+        list.extend(["", "three"]);
+        assert_eq!(list.concat(), "onethree");
+        assert_eq!(list.join(", "), "one, , three");
+    }
+
+    #[test]
+    fn from_utf8() {
+        let list = DynList::<str>::from_utf8(DynList::new()).unwrap();
+        assert!(list.is_empty());
+
+        let mut bytes = DynList::<[u8]>::new();
+        bytes.push_back_copy_array(b"a");
+        bytes.push_back_copy_array(&[]);
+        bytes.push_back_copy_array("é".as_bytes());
+        let list = DynList::<str>::try_from(bytes).unwrap();
+        list.check_debug();
+        assert!(list.iter().eq(["a", "", "é"]));
+
+        let mut bytes = list.into_bytes();
+        bytes.push_back_copy_array(&"é".as_bytes()[..1]);
+        bytes.push_back_copy_array(&[0xff]);
+        let error = DynList::<str>::from_utf8(bytes).unwrap_err();
+        assert_eq!(error.index(), 3);
+        assert_eq!(error.utf8_error().valid_up_to(), 0);
+        assert_eq!(
+            error.to_string(),
+            "node 3 is not valid UTF-8: incomplete utf-8 byte sequence from index 0"
+        );
+        assert_eq!(error.into_bytes().len(), 5);
+    }
+
+    #[test]
+    fn push_string_with() {
+        let mut list = DynList::<str>::new();
+        assert!(list
+            .push_back_string_with(2, |bytes| bytes.copy_from_slice(b"bc"))
+            .is_ok());
+        assert!(list
+            .push_front_string_with(1, |bytes| bytes[0] = b'a')
+            .is_ok());
+        // Unwritten bytes are zeroed
+        assert!(list
+            .push_back_string_with(2, |bytes| bytes[0] = b'd')
+            .is_ok());
+        list.check_debug();
+        assert!(list.iter().eq(["a", "bc", "d\0"]));
+
+        let Err(error) = list.push_front_string_with(2, |bytes| bytes[1] = 0xC0) else {
+            panic!("the bytes are not valid UTF-8");
+        };
+        assert_eq!(error.valid_up_to(), 1);
+        list.check_debug();
+        assert_eq!(list.len(), 3);
+    }
 }
@@ -1,6 +1,14 @@
-use core::mem;
+#![feature(allocator_api)]
 
-use dynode::NodePtr;
+extern crate alloc;
+
+use alloc::alloc::Global;
+use core::{
+    alloc::Allocator,
+    mem::{self, MaybeUninit},
+};
+
+use dynode::{HeaderOpaqueNodePtr, NodePtr, StructureHandle};
 
 #[cfg_attr(test, test)]
 fn main() {
@@ -23,6 +31,8 @@ fn main() {
 }
 
 type Node<T> = NodePtr<Header<T>, T>;
+type MaybeUninitNode<'a, T> = dynode::MaybeUninitNode<T, &'a mut LinkedQueue<T>>;
+
 struct Header<T> {
     next: Option<Node<T>>,
 }
@@ -31,6 +41,40 @@ struct LinkedQueue<T> {
     ends: Option<(Node<T>, Node<T>)>,
 }
 
+impl<T> StructureHandle<T> for &mut LinkedQueue<T> {
+    type Allocator = Global;
+
+    unsafe fn insert(self, node: HeaderOpaqueNodePtr<T>) {
+        // SAFETY:
+        // All nodes in the queue have a `Header<T>`.
+        let node = unsafe { node.to_transparent::<Header<T>>() };
+
+        match self.ends {
+            None => self.ends = Some((node, node)),
+            Some((front, back)) => {
+                // SAFETY:
+                // As we have a mutable reference to the queue, the back node's header is not
+                // aliased.
+                unsafe { back.header_ptr().as_mut() }.next = Some(node);
+                self.ends = Some((front, node));
+            }
+        }
+    }
+
+    fn allocator(&self) -> &Self::Allocator {
+        Global.by_ref()
+    }
+
+    unsafe fn deallocate(&self, node: HeaderOpaqueNodePtr<T>) {
+        // SAFETY:
+        // All nodes in the queue have a `Header<T>`.
+        let node = unsafe { node.to_transparent::<Header<T>>() };
+        // SAFETY:
+        // The node was allocated with the global allocator and is not used again.
+        unsafe { node.deallocate_global() };
+    }
+}
+
 impl<T> LinkedQueue<T> {
     const fn new() -> Self {
         Self { ends: None }
@@ -40,21 +84,29 @@ impl<T> LinkedQueue<T> {
         self.ends.is_none()
     }
 
-    fn queue(&mut self, value: T) {
-        let node = Node::allocate_sized();
-        unsafe { node.header_ptr().write(Header { next: None }) };
-        unsafe { node.data_ptr().write(value) };
+    fn allocate_uninit(&mut self) -> MaybeUninitNode<T> {
+        // As it's being added to the back of the queue, there is no node after it
+        // SAFETY:
+        // The node was just allocated, so its header is valid for writes and not referenced.
+        let node = unsafe { Node::allocate_sized().write_header(Header { next: None }) };
+        // SAFETY:
+        // The header has been initialised and there are no other aliases to the node.
+        unsafe { dynode::new_maybe_uninit(self, node.to_header_opaque()) }
+    }
 
-        match self.ends {
-            None => self.ends = Some((node, node)),
-            Some((front, back)) => {
-                unsafe { back.header_ptr().as_mut() }.next = Some(node);
-                self.ends = Some((front, node));
-            }
-        }
+    fn queue(&mut self, value: T) {
+        let node = self.allocate_uninit();
+        // SAFETY:
+        // The node was allocated for a `T` and is not aliased.
+        unsafe { node.value_ptr().cast::<MaybeUninit<T>>().as_mut() }.write(value);
+        // Once the value is written, the node can be inserted without any more `unsafe`
+        // SAFETY:
+        // The value was initialised above.
+        let node = unsafe { node.assume_init() };
+        node.insert();
     }
 
-    fn dequeue(&mut self) -> Option<T> {
+    fn unlink_front(&mut self) -> Option<Node<T>> {
         let (front, back) = self.ends?;
 
         let next = unsafe { front.header_ptr().as_ref() }.next;
@@ -65,42 +117,27 @@ impl<T> LinkedQueue<T> {
             self.ends = None;
         }
 
+        Some(front)
+    }
+
+    fn dequeue(&mut self) -> Option<T> {
+        let front = self.unlink_front()?;
+
         let value = unsafe { front.data_ptr().read() };
         unsafe { front.deallocate_global() };
         Some(value)
     }
 
     fn delete_front(&mut self) -> bool {
-        struct Guard<T> {
-            node: Node<T>,
-        }
-
-        impl<T> Drop for Guard<T> {
-            fn drop(&mut self) {
-                unsafe { self.node.deallocate_global() };
-            }
-        }
-
-        let Some((front, back)) = self.ends else {
+        let Some(front) = self.unlink_front() else {
             return false;
         };
 
-        let next = unsafe { front.header_ptr().as_ref() }.next;
-        if let Some(next) = next {
-            self.ends = Some((next, back));
-        } else {
-            debug_assert_eq!(front, back);
-            self.ends = None;
-        }
-
-        // If `<T as Drop>::drop` panics, we still want to deallocate the node.
-        // In the event of a panic, the guard's `drop` will still be called
-        // (unless an abort happens).
-        let guard = Guard { node: front };
-        unsafe { guard.node.data_ptr().drop_in_place() };
-        // Explicitly calling drop is unnecessary but it makes the flow more
-        // obvious.
-        drop(guard);
+        // This drops the value and deallocates the node, even if `<T as Drop>::drop` panics
+        let handle = &mut *self;
+        // SAFETY:
+        // The node has been unlinked from the queue and its value is initialised.
+        unsafe { handle.drop_node(front.to_header_opaque()) };
         true
     }
 }
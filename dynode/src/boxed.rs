@@ -0,0 +1,273 @@
+use core::{
+    alloc::{Allocator, Layout},
+    ptr::{self, NonNull},
+};
+
+use crate::{
+    alloc::{Box, Global},
+    AllocateError, NodePtr,
+};
+
+impl<Header, U> NodePtr<Header, U>
+where
+    U: ?Sized,
+{
+    /// Attempts to move the value out of a box and into a new node in the given allocator.
+    ///
+    /// The value is always copied into a new allocation, as the node has to store the header and the value's metadata before the value.
+    /// The box's allocation is then deallocated without dropping the value.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`] with the box in it.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use dynode::NodePtr;
+    /// let boxed: Box<[u8]> = Box::new([1, 2, 3]);
+    /// let Ok(node) = NodePtr::<u8, [u8]>::try_from_box_in(boxed, 4, std::alloc::Global) else {
+    ///     panic!("allocation failed");
+    /// };
+    ///
+    /// assert_eq!(unsafe { node.header_ptr().read() }, 4);
+    /// assert_eq!(unsafe { node.data_ptr().as_ref() }, &[1, 2, 3]);
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub fn try_from_box_in<A, A2>(
+        boxed: Box<U, A>,
+        header: Header,
+        allocator: A2,
+    ) -> Result<Self, AllocateError<Box<U, A>>>
+    where
+        A: Allocator,
+        A2: Allocator,
+    {
+        let metadata = ptr::metadata(&raw const *boxed);
+        // SAFETY:
+        // The metadata is from a valid reference to `U`, so it is valid for `Layout::for_value_raw`.
+        let node = match unsafe { Self::try_allocate_in(metadata, allocator) } {
+            Ok(node) => node,
+            Err(error) => return Err(error.with_context("from_box").with_value(boxed)),
+        };
+
+        let value_layout = Layout::for_value(&*boxed);
+        let (value, box_allocator) = Box::into_raw_with_allocator(boxed);
+        // SAFETY:
+        // Boxes are never null.
+        let value = unsafe { NonNull::new_unchecked(value) }.cast::<u8>();
+        // SAFETY:
+        // - `value` is from a box, so it is valid for reads of `value_layout.size()` bytes
+        // - the node was allocated with the value's metadata, so its value pointer is valid for
+        //   writes of the same size
+        // - the node is a new allocation, so it cannot overlap with the box
+        unsafe {
+            node.value_ptr()
+                .cast::<u8>()
+                .copy_from_nonoverlapping(value, value_layout.size());
+        }
+        // SAFETY:
+        // The header pointer is in the node's allocation, which is valid for writes.
+        unsafe { node.header_ptr().write(header) };
+
+        // `Box` does not allocate for zero-sized values
+        if value_layout.size() != 0 {
+            // SAFETY:
+            // - `value` was allocated by `box_allocator` with `value_layout`
+            // - the value has been moved into the node, so it must not be dropped
+            unsafe { box_allocator.deallocate(value, value_layout) };
+        }
+
+        Ok(node)
+    }
+
+    /// Attempts to move the value out of a box and into a new node.
+    ///
+    /// See [`Self::try_from_box_in`] for more information.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`] with the box in it.
+    pub fn try_from_box<A>(
+        boxed: Box<U, A>,
+        header: Header,
+    ) -> Result<Self, AllocateError<Box<U, A>>>
+    where
+        A: Allocator,
+    {
+        Self::try_from_box_in(boxed, header, Global)
+    }
+
+    #[must_use]
+    /// Moves the value out of a box and into a new node in the given allocator.
+    ///
+    /// See [`Self::try_from_box_in`] for more information.
+    pub fn from_box_in<A, A2>(boxed: Box<U, A>, header: Header, allocator: A2) -> Self
+    where
+        A: Allocator,
+        A2: Allocator,
+    {
+        AllocateError::unwrap_result(Self::try_from_box_in(boxed, header, allocator))
+    }
+
+    #[must_use]
+    /// Moves the value out of a box and into a new node.
+    ///
+    /// See [`Self::try_from_box_in`] for more information.
+    pub fn from_box<A>(boxed: Box<U, A>, header: Header) -> Self
+    where
+        A: Allocator,
+    {
+        Self::from_box_in(boxed, header, Global)
+    }
+}
+
+impl<U> NodePtr<(), U>
+where
+    U: ?Sized,
+{
+    /// Attempts to move the node's value into a box in the given allocator.
+    ///
+    /// If the value's metadata is zero-sized, as it is for sized types, the node's allocation has the same layout as the value, so it is reused for the box.
+    /// Otherwise, the value is copied into a new allocation and the node is deallocated.
+    /// Only nodes with a `()` header can share an allocation with a box, which is why this is not available for other headers.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node's value must be initialised.
+    /// - The node must have been allocated by `allocator`.
+    /// - If this succeeds, the node must not be used again; this includes aliases!
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    /// The node is left untouched, so it can still be used.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use dynode::NodePtr;
+    /// let node = NodePtr::<(), str>::allocate_string(5);
+    /// unsafe { node.value_ptr().cast::<[u8; 5]>().write(*b"Hello") };
+    ///
+    /// let boxed = unsafe { node.try_into_box_in(std::alloc::Global) }.unwrap();
+    /// assert_eq!(&*boxed, "Hello");
+    ///
+    /// // The node's allocation is reused for sized values
+    /// let node = NodePtr::<(), u32>::allocate_sized();
+    /// unsafe { node.value_ptr().cast::<u32>().write(7) };
+    /// assert_eq!(*unsafe { node.into_box() }, 7);
+    /// ```
+    pub unsafe fn try_into_box_in<A>(self, allocator: A) -> Result<Box<U, A>, AllocateError>
+    where
+        A: Allocator,
+    {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let metadata = unsafe { self.metadata() };
+        // SAFETY:
+        // The metadata must be valid for the allocation.
+        let layout_result = unsafe { Self::layout_from_metadata(metadata) };
+        debug_assert!(layout_result.is_ok());
+        // SAFETY:
+        // This was calculated when allocating the node, so it cannot fail.
+        let (layout, _, value_offset) = unsafe { layout_result.unwrap_unchecked() };
+
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let data = unsafe { self.data_ptr() };
+
+        if value_offset == 0 {
+            // The header and metadata are both zero-sized, so the node's layout is the value's layout
+            // SAFETY:
+            // - the node's value is initialised (safety condition)
+            // - the node was allocated by `allocator` with the value's layout
+            // - the node is not used again (safety condition)
+            return Ok(unsafe { Box::from_raw_in(data.as_ptr(), allocator) });
+        }
+
+        // SAFETY:
+        // The metadata is valid for the value's layout, as it was used to allocate the node.
+        let value_layout = unsafe { Layout::for_value_raw(data.as_ptr()) };
+        let ptr = match allocator.allocate(value_layout) {
+            Ok(ptr) => ptr,
+            Err(error) => {
+                return Err(AllocateError::new_alloc(error, value_layout).with_context("into_box"))
+            }
+        };
+        debug_assert!(layout.size() > value_layout.size());
+
+        // SAFETY:
+        // - `ptr` is valid for writes up to length `value_layout.size()`
+        // - the node's value is initialised (safety condition)
+        // - `ptr` is from a new allocation, so it cannot overlap with the node
+        unsafe {
+            ptr.cast::<u8>()
+                .copy_from_nonoverlapping(self.value_ptr().cast(), value_layout.size());
+        }
+        // SAFETY:
+        // The node was allocated by `allocator` and is not used again (safety condition); its value
+        // has been moved into `ptr`.
+        unsafe { self.deallocate(&allocator) };
+
+        let ptr = NonNull::from_raw_parts(ptr.cast::<()>(), metadata);
+        Ok(
+            // SAFETY:
+            // - `ptr` is not aliased
+            // - `ptr` was allocated with `allocator` using the value's layout
+            // - `ptr`'s data has been initialised
+            unsafe { Box::from_raw_in(ptr.as_ptr(), allocator) },
+        )
+    }
+
+    /// Attempts to move the node's value into a box.
+    ///
+    /// See [`Self::try_into_box_in`] for more information.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node's value must be initialised.
+    /// - The node must have been allocated using the global allocator.
+    /// - If this succeeds, the node must not be used again; this includes aliases!
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    /// The node is left untouched, so it can still be used.
+    pub unsafe fn try_into_box(self) -> Result<Box<U>, AllocateError> {
+        // SAFETY:
+        // The safety conditions are the same as for `try_into_box_in`, with the global allocator.
+        unsafe { self.try_into_box_in(Global) }
+    }
+
+    #[must_use]
+    /// Moves the node's value into a box in the given allocator.
+    ///
+    /// See [`Self::try_into_box_in`] for more information.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node's value must be initialised.
+    /// - The node must have been allocated by `allocator`.
+    /// - The node must not be used again; this includes aliases!
+    pub unsafe fn into_box_in<A>(self, allocator: A) -> Box<U, A>
+    where
+        A: Allocator,
+    {
+        // SAFETY:
+        // The safety conditions are the same as for `try_into_box_in`.
+        AllocateError::unwrap_result(unsafe { self.try_into_box_in(allocator) })
+    }
+
+    #[must_use]
+    /// Moves the node's value into a box.
+    ///
+    /// See [`Self::try_into_box_in`] for more information.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node's value must be initialised.
+    /// - The node must have been allocated using the global allocator.
+    /// - The node must not be used again; this includes aliases!
+    pub unsafe fn into_box(self) -> Box<U> {
+        // SAFETY:
+        // The safety conditions are the same as for `try_into_box_in`, with the global allocator.
+        unsafe { self.into_box_in(Global) }
+    }
+}
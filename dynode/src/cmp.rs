@@ -1,4 +1,4 @@
-use crate::{HeaderOpaqueNodePtr, NodePtr};
+use crate::{FullyOpaqueNodePtr, HeaderOpaqueNodePtr, NodeLayout, NodePtr};
 
 impl<Header, T> PartialEq for NodePtr<Header, T>
 where
@@ -81,3 +81,46 @@ where
         self.value_ptr().cmp(&other.value_ptr())
     }
 }
+
+impl PartialEq for FullyOpaqueNodePtr {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value_ptr().eq(&other.value_ptr())
+    }
+}
+
+impl PartialEq<Option<Self>> for FullyOpaqueNodePtr {
+    #[inline]
+    fn eq(&self, other: &Option<Self>) -> bool {
+        other.is_some_and(|other| self.eq(&other))
+    }
+}
+
+impl PartialOrd for FullyOpaqueNodePtr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for FullyOpaqueNodePtr {}
+impl Ord for FullyOpaqueNodePtr {
+    #[inline]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.value_ptr().cmp(&other.value_ptr())
+    }
+}
+
+impl<Header, U> PartialEq for NodeLayout<Header, U>
+where
+    U: ?Sized,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.layout() == other.layout()
+            && self.metadata_offset() == other.metadata_offset()
+            && self.value_offset() == other.value_offset()
+    }
+}
+
+impl<Header, U> Eq for NodeLayout<Header, U> where U: ?Sized {}
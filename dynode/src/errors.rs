@@ -7,15 +7,19 @@ use core::{
 #[derive(Clone, PartialEq, Eq)]
 enum AllocateErrorInternal {
     Layout { error: LayoutError },
+    Unalignable,
     Alloc { error: AllocError, layout: Layout },
 }
 
 #[derive(Clone, PartialEq, Eq)]
 /// The error type returned when an allocation fails.
 ///
-/// This can either be from an arithmetic error when calculating the layout or from an allocator when allocating.
+/// This can either be from an arithmetic error when calculating the layout, from the node's header and
+/// value not being able to both be aligned, or from an allocator when allocating.
 pub struct AllocateError<Value = ()> {
     internal: AllocateErrorInternal,
+    requested_len: Option<usize>,
+    context: Option<&'static str>,
     value: Value,
 }
 
@@ -23,7 +27,7 @@ impl AllocateErrorInternal {
     fn handle(self) -> ! {
         #[cfg(feature = "alloc")]
         crate::alloc::handle_alloc_error(match self {
-            Self::Layout { .. } => Layout::new::<()>(),
+            Self::Layout { .. } | Self::Unalignable => Layout::new::<()>(),
             Self::Alloc { layout, .. } => layout,
         });
 
@@ -54,6 +58,8 @@ impl<Value> AllocateError<Value> {
             self.value,
             AllocateError {
                 internal: self.internal,
+                requested_len: self.requested_len,
+                context: self.context,
                 value: (),
             },
         )
@@ -74,11 +80,123 @@ impl<Value> AllocateError<Value> {
     /// If this returns [`None`], the layout calculation failed.
     pub const fn layout(&self) -> Option<Layout> {
         match self.internal {
-            AllocateErrorInternal::Layout { .. } => None,
+            AllocateErrorInternal::Layout { .. } | AllocateErrorInternal::Unalignable => None,
             AllocateErrorInternal::Alloc { layout, .. } => Some(layout),
         }
     }
 
+    #[must_use]
+    #[inline]
+    /// Returns `true` if the error is from an arithmetic error when calculating the layout.
+    ///
+    /// Retrying these allocations will never succeed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use dynode::NodePtr;
+    /// let error = NodePtr::<(), [u64]>::try_allocate_array_in(usize::MAX, std::alloc::Global)
+    ///     .unwrap_err();
+    /// assert!(error.is_layout());
+    /// assert!(!error.is_alloc());
+    /// ```
+    pub const fn is_layout(&self) -> bool {
+        matches!(self.internal, AllocateErrorInternal::Layout { .. })
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns `true` if the error is from the node's header and value not being able to both be aligned.
+    ///
+    /// The metadata sits between the header and the value, so if the padding needed to align the value
+    /// is not a multiple of the header's alignment, no layout exists.
+    /// Retrying these allocations will never succeed.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use dynode::NodePtr;
+    /// #[repr(align(16))]
+    /// struct Header([u8; 16]);
+    /// #[repr(align(16))]
+    /// struct Value(u8);
+    ///
+    /// let error = unsafe { NodePtr::<Header, [Value]>::try_allocate_in(1, std::alloc::Global) }
+    ///     .unwrap_err();
+    /// assert!(error.is_unalignable());
+    /// assert!(!error.is_layout());
+    /// assert!(!error.is_alloc());
+    /// ```
+    pub const fn is_unalignable(&self) -> bool {
+        matches!(self.internal, AllocateErrorInternal::Unalignable)
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns `true` if the error is from the allocator failing to allocate.
+    ///
+    /// If this returns `true`, [`Self::layout`] returns the layout that could not be allocated.
+    pub const fn is_alloc(&self) -> bool {
+        matches!(self.internal, AllocateErrorInternal::Alloc { .. })
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the number of elements that was requested, if the error is from allocating an array or string.
+    ///
+    /// This is the length in elements (or bytes for strings), not the size of the allocation.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let error = NodePtr::<(), [u64]>::try_allocate_array(usize::MAX).unwrap_err();
+    /// assert_eq!(error.requested_len(), Some(usize::MAX));
+    ///
+    /// let error = NodePtr::<(), str>::try_allocate_string(usize::MAX).unwrap_err();
+    /// assert_eq!(error.requested_len(), Some(usize::MAX));
+    /// ```
+    pub const fn requested_len(&self) -> Option<usize> {
+        self.requested_len
+    }
+
+    #[must_use]
+    #[inline]
+    /// Records the number of elements that was requested.
+    ///
+    /// See [`Self::requested_len`].
+    pub const fn with_requested_len(mut self, requested_len: usize) -> Self {
+        self.requested_len = Some(requested_len);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the name of the operation that failed, if it was recorded.
+    ///
+    /// This is the name of the method that was called, without any `try_` prefix or `_in` suffix.
+    /// When one operation is built from another, the outermost one is recorded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let error = NodePtr::<(), [u64]>::try_allocate_array(usize::MAX).unwrap_err();
+    /// assert_eq!(error.context(), Some("allocate_array"));
+    /// assert!(error.to_string().ends_with("(while: allocate_array)"));
+    /// ```
+    pub const fn context(&self) -> Option<&'static str> {
+        self.context
+    }
+
+    #[must_use]
+    #[inline]
+    /// Records the name of the operation that failed, replacing any previous context.
+    ///
+    /// See [`Self::context`].
+    pub const fn with_context(mut self, context: &'static str) -> Self {
+        self.context = Some(context);
+        self
+    }
+
     #[inline]
     /// Applies a function `f` to the value.
     ///
@@ -98,6 +216,8 @@ impl AllocateError {
     pub const fn with_value<Value>(self, value: Value) -> AllocateError<Value> {
         AllocateError {
             internal: self.internal,
+            requested_len: self.requested_len,
+            context: self.context,
             value,
         }
     }
@@ -108,6 +228,22 @@ impl AllocateError {
     pub const fn new_layout(source: LayoutError) -> Self {
         Self {
             internal: AllocateErrorInternal::Layout { error: source },
+            requested_len: None,
+            context: None,
+            value: (),
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Create a new error for a node whose header and value cannot both be aligned.
+    ///
+    /// See [`Self::is_unalignable`].
+    pub const fn new_unalignable() -> Self {
+        Self {
+            internal: AllocateErrorInternal::Unalignable,
+            requested_len: None,
+            context: None,
             value: (),
         }
     }
@@ -121,25 +257,37 @@ impl AllocateError {
                 error: source,
                 layout,
             },
+            requested_len: None,
+            context: None,
             value: (),
         }
     }
 }
 
-impl fmt::Debug for AllocateErrorInternal {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl AllocateErrorInternal {
+    fn debug_tuple<'a, 'b>(&self, f: &'a mut fmt::Formatter<'b>) -> fmt::DebugTuple<'a, 'b> {
         let mut tuple;
         match self {
             Self::Layout { .. } => {
                 tuple = f.debug_tuple("AllocateError::Layout");
             }
+            Self::Unalignable => {
+                tuple = f.debug_tuple("AllocateError::Unalignable");
+            }
             Self::Alloc { layout, .. } => {
                 tuple = f.debug_tuple("AllocateError::Alloc");
                 tuple.field(&layout);
             }
         }
 
-        tuple.finish()
+        tuple
+    }
+}
+
+impl fmt::Debug for AllocateErrorInternal {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.debug_tuple(f).finish()
     }
 }
 
@@ -147,6 +295,7 @@ impl fmt::Display for AllocateErrorInternal {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Layout { error } => write!(f, "{error}"),
+            Self::Unalignable => f.write_str("the node's header and value cannot both be aligned"),
             Self::Alloc { error, layout } => write!(
                 f,
                 "{error} (size: {}, align: {})",
@@ -158,26 +307,40 @@ impl fmt::Display for AllocateErrorInternal {
 }
 
 impl fmt::Debug for AllocateError {
-    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Debug::fmt(&self.internal, f)
+        let mut tuple = self.internal.debug_tuple(f);
+        if let Some(requested_len) = self.requested_len {
+            tuple.field(&format_args!("requested_len: {requested_len}"));
+        }
+        if let Some(context) = self.context {
+            tuple.field(&format_args!("context: {context:?}"));
+        }
+
+        tuple.finish()
     }
 }
 
 impl fmt::Display for AllocateError {
-    #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.internal, f)
+        fmt::Display::fmt(&self.internal, f)?;
+        if let Some(requested_len) = self.requested_len {
+            write!(f, " (requested length: {requested_len})")?;
+        }
+        if let Some(context) = self.context {
+            write!(f, " (while: {context})")?;
+        }
+        Ok(())
     }
 }
 
 impl Error for AllocateErrorInternal {
     #[inline]
     fn source(&self) -> Option<&(dyn Error + 'static)> {
-        Some(match self {
-            Self::Layout { error } => error,
-            Self::Alloc { error, .. } => error,
-        })
+        match self {
+            Self::Layout { error } => Some(error),
+            Self::Unalignable => None,
+            Self::Alloc { error, .. } => Some(error),
+        }
     }
 }
 
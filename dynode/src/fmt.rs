@@ -1,6 +1,6 @@
 use core::fmt::{Debug, Formatter, Pointer, Result};
 
-use crate::{HeaderOpaqueNodePtr, NodePtr};
+use crate::{FullyOpaqueNodePtr, HeaderOpaqueNodePtr, NodeLayout, NodePtr};
 
 impl<Header, U> Debug for NodePtr<Header, U>
 where
@@ -39,3 +39,29 @@ where
         Pointer::fmt(&self.value_ptr(), f)
     }
 }
+
+impl Debug for FullyOpaqueNodePtr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_tuple("NodePtr").field(&self.value_ptr()).finish()
+    }
+}
+
+impl Pointer for FullyOpaqueNodePtr {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        Pointer::fmt(&self.value_ptr(), f)
+    }
+}
+
+impl<Header, U> Debug for NodeLayout<Header, U>
+where
+    U: ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.debug_struct("NodeLayout")
+            .field("layout", &self.layout())
+            .field("metadata_offset", &self.metadata_offset())
+            .field("value_offset", &self.value_offset())
+            .finish()
+    }
+}
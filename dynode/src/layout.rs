@@ -0,0 +1,123 @@
+use core::{alloc::Layout, marker::PhantomData, ptr::Pointee};
+
+use crate::{AllocateError, NodePtr};
+
+/// The layout of a node with a `Header` and a value of type `U`.
+///
+/// This is useful for structures that manage their own allocations.
+/// The node's allocation starts with the header, followed by the value's metadata and then the value.
+/// If the value is more aligned than the header, there may be padding before the header instead.
+///
+/// ```rust
+/// # #![feature(allocator_api)]
+/// # use core::alloc::{Allocator, Layout};
+/// # use std::alloc::Global;
+/// # use dynode::{NodeLayout, NodePtr};
+/// let layout = NodeLayout::<u32, [u8]>::for_value_layout(Layout::array::<u8>(4).unwrap()).unwrap();
+/// let base = Global.allocate(layout.layout()).unwrap();
+///
+/// // SAFETY: `base` was allocated with `layout` and the metadata matches the value layout.
+/// let node = unsafe { NodePtr::from_base_ptr_with_layout(base.cast(), layout, 4) };
+/// unsafe { node.header_ptr().write(7) };
+/// unsafe { node.value_ptr().cast::<[u8; 4]>().write([1, 2, 3, 4]) };
+/// assert_eq!(unsafe { node.data_ptr().as_ref() }, [1, 2, 3, 4]);
+///
+/// // The header is at the start of the allocation
+/// unsafe { Global.deallocate(node.header_ptr().cast(), layout.layout()) };
+/// ```
+pub struct NodeLayout<Header, U>
+where
+    U: ?Sized,
+{
+    layout: Layout,
+    metadata_offset: usize,
+    value_offset: usize,
+    _phantom: PhantomData<fn() -> NodePtr<Header, U>>,
+}
+// Manually implemented to avoid bounds on `Header` and `U`
+impl<Header, U> Clone for NodeLayout<Header, U>
+where
+    U: ?Sized,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Header, U> Copy for NodeLayout<Header, U> where U: ?Sized {}
+
+impl<Header, U> NodeLayout<Header, U>
+where
+    U: ?Sized,
+{
+    /// Calculates the layout of a node from the layout of its value.
+    ///
+    /// # Errors
+    /// On arithmetic overflow, or if the header and the value cannot both be aligned, this returns an
+    /// [`AllocateError`].
+    /// See [`Layout::extend`] and [`AllocateError::is_unalignable`] for more information.
+    pub fn for_value_layout(value_layout: Layout) -> Result<Self, AllocateError> {
+        let (layout, metadata_offset, value_offset) =
+            NodePtr::<Header, U>::layout_from_value(value_layout)?;
+        Ok(Self {
+            layout,
+            metadata_offset,
+            value_offset,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Calculates the layout of a node from its value's metadata.
+    ///
+    /// # Safety
+    /// `metadata` must be valid under the safety conditions for [`Layout::for_value_raw`].
+    ///
+    /// # Errors
+    /// On arithmetic overflow, or if the header and the value cannot both be aligned, this returns an
+    /// [`AllocateError`].
+    /// See [`Layout::extend`] and [`AllocateError::is_unalignable`] for more information.
+    pub unsafe fn for_metadata(metadata: <U as Pointee>::Metadata) -> Result<Self, AllocateError> {
+        let (layout, metadata_offset, value_offset) =
+            // SAFETY:
+            // `metadata` is valid under the safety conditions for `Layout::for_value_raw` (safety
+            // condition).
+            unsafe { NodePtr::<Header, U>::layout_from_metadata(metadata) }?;
+        Ok(Self {
+            layout,
+            metadata_offset,
+            value_offset,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Calculates the layout of a node that can hold a copy of `value`.
+    ///
+    /// # Errors
+    /// On arithmetic overflow, or if the header and the value cannot both be aligned, this returns an
+    /// [`AllocateError`].
+    /// See [`Layout::extend`] and [`AllocateError::is_unalignable`] for more information.
+    pub fn for_value(value: &U) -> Result<Self, AllocateError> {
+        Self::for_value_layout(Layout::for_value(value))
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the layout of the whole node allocation.
+    pub const fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the offset of the value's metadata from the start of the allocation.
+    pub const fn metadata_offset(&self) -> usize {
+        self.metadata_offset
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the offset of the value from the start of the allocation.
+    pub const fn value_offset(&self) -> usize {
+        self.value_offset
+    }
+}
@@ -3,6 +3,7 @@
     layout_for_ptr,
     allocator_api,
     unsize,
+    clone_to_uninit,
     non_null_from_ref,
     ptr_as_uninit,
     maybe_uninit_write_slice
@@ -13,19 +14,29 @@
 #![doc = include_str!("../README.md")]
 
 use core::{
-    alloc::{Allocator, Layout, LayoutError},
+    alloc::{Allocator, Layout},
+    clone::CloneToUninit,
     marker::{PhantomData, Unsize},
+    mem::{self, MaybeUninit},
     ptr::{self, NonNull, Pointee},
 };
 
+#[cfg(feature = "alloc")]
+mod boxed;
 mod cmp;
 mod errors;
 mod fmt;
+mod layout;
 mod maybe_uninit;
 mod opaque;
+#[cfg(feature = "alloc")]
+mod thin_box;
 pub use errors::AllocateError;
-pub use maybe_uninit::{new_maybe_uninit, MaybeUninitNode, StructureHandle};
-pub use opaque::HeaderOpaqueNodePtr;
+pub use layout::NodeLayout;
+pub use maybe_uninit::{new_maybe_uninit, InitNode, MaybeUninitNode, StructureHandle};
+pub use opaque::{FullyOpaqueNodePtr, HeaderOpaqueNodePtr};
+#[cfg(feature = "alloc")]
+pub use thin_box::ThinBox;
 
 #[cfg(feature = "alloc")]
 mod alloc {
@@ -69,15 +80,30 @@ where
     /// The layout is the node's `Header` followed by the value.
     /// On success, this returns the node's layout, the metadata offset and the value offset.
     ///
+    /// The header and metadata are always directly before the value, so if the value needs more
+    /// padding to be aligned, it is put before the header.
+    ///
     /// # Errors:
-    /// On arithmetic overflow, returns [`LayoutError`].
+    /// On arithmetic overflow, returns a layout [`AllocateError`].
     /// See [`Layout::extend`] for more information.
-    fn layout_from_value(value_layout: Layout) -> Result<(Layout, usize, usize), LayoutError> {
+    /// If the header and the value cannot both be aligned, returns an unalignable [`AllocateError`].
+    pub(crate) fn layout_from_value(
+        value_layout: Layout,
+    ) -> Result<(Layout, usize, usize), AllocateError> {
         let header_layout = Layout::new::<Header>();
-
         let metadata_layout = Layout::new::<<U as Pointee>::Metadata>();
-        let (layout, metadata_offset) = header_layout.extend(metadata_layout)?;
 
+        let (_, prefix_metadata_offset) = header_layout.extend(metadata_layout)?;
+        let prefix_size = prefix_metadata_offset + size_of::<<U as Pointee>::Metadata>();
+        let padding = prefix_size
+            .checked_next_multiple_of(value_layout.align())
+            .map(|aligned| aligned - prefix_size);
+        let Some(padding) = padding.filter(|padding| padding % header_layout.align() == 0) else {
+            return Err(AllocateError::new_unalignable());
+        };
+
+        let (layout, _) = Layout::from_size_align(padding, 1)?.extend(header_layout)?;
+        let (layout, metadata_offset) = layout.extend(metadata_layout)?;
         let (layout, value_offset) = layout.extend(value_layout)?;
 
         debug_assert_eq!(
@@ -92,9 +118,9 @@ where
     ///
     /// # Safety
     /// `metadata` must be valid under the safety conditions for [`Layout::for_value_raw`].
-    unsafe fn layout_from_metadata(
+    pub(crate) unsafe fn layout_from_metadata(
         metadata: <U as Pointee>::Metadata,
-    ) -> Result<(Layout, usize, usize), LayoutError> {
+    ) -> Result<(Layout, usize, usize), AllocateError> {
         let fake_ptr = ptr::from_raw_parts::<U>(ptr::null::<()>(), metadata);
         // SAFETY:
         // The `metadata` is valid for `Layout::for_value_raw` (safety condition).
@@ -102,6 +128,21 @@ where
         Self::layout_from_value(value_layout)
     }
 
+    /// Attempts to calculate the layout for the node from the value's metadata, with the value aligned to at least `align`.
+    ///
+    /// # Safety
+    /// `metadata` must be valid under the safety conditions for [`Layout::for_value_raw`].
+    unsafe fn layout_from_metadata_aligned(
+        metadata: <U as Pointee>::Metadata,
+        align: usize,
+    ) -> Result<(Layout, usize, usize), AllocateError> {
+        let fake_ptr = ptr::from_raw_parts::<U>(ptr::null::<()>(), metadata);
+        // SAFETY:
+        // The `metadata` is valid for `Layout::for_value_raw` (safety condition).
+        let value_layout = unsafe { Layout::for_value_raw(fake_ptr) };
+        Self::layout_from_value(value_layout.align_to(align)?)
+    }
+
     #[must_use]
     #[inline]
     /// Create a node pointer with an abstracted header type.
@@ -111,6 +152,17 @@ where
         unsafe { HeaderOpaqueNodePtr::from_value_ptr(self.mid) }
     }
 
+    #[must_use]
+    #[inline]
+    /// Create a node pointer with abstracted header and value types.
+    ///
+    /// This is useful if you want to store nodes of different types together.
+    pub const fn to_fully_opaque(self) -> FullyOpaqueNodePtr {
+        // SAFETY:
+        // `self.mid` is the value pointer of a node.
+        unsafe { FullyOpaqueNodePtr::from_value_ptr(self.mid) }
+    }
+
     #[must_use]
     /// Get the metadata of the node's data.
     ///
@@ -128,6 +180,50 @@ where
         unsafe { ptr.read() }
     }
 
+    /// Overwrites the metadata of the node's data.
+    ///
+    /// This can be used to change the logical length of an array or string node without reallocating it.
+    /// Functions like [`Self::deallocate`] calculate the node's layout from its metadata, so the original metadata must be restored before they are called, unless the layout from the new metadata *fits* the allocation as described in [`Allocator`].
+    /// Note that this does not drop or initialise any part of the value.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - `metadata` must be valid under the safety conditions for [`Layout::for_value_raw`].
+    /// - The node's layout calculated from `metadata` must not be larger than its allocation.
+    /// - If the value is initialised, it must still be valid with `metadata`.
+    /// - There must not be any living references to the node's data.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let node = NodePtr::<(), [u8]>::allocate_array(4);
+    /// unsafe { node.value_ptr().cast::<[u8; 4]>().write([1, 2, 3, 4]) };
+    ///
+    /// unsafe { node.set_metadata(2) };
+    /// assert_eq!(unsafe { node.data_ptr().as_ref() }, &[1, 2]);
+    ///
+    /// // The node was allocated with a length of 4, so it must be deallocated with that length
+    /// unsafe { node.set_metadata(4) };
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub unsafe fn set_metadata(self, metadata: <U as Pointee>::Metadata) {
+        // The allocation's size is not stored, so only the layout calculation can be checked
+        // SAFETY:
+        // `metadata` is valid for `Layout::for_value_raw` (safety condition).
+        let layout_result = unsafe { Self::layout_from_metadata(metadata) };
+        debug_assert!(layout_result.is_ok());
+
+        // SAFETY:
+        // `self.mid` is a pointer immediately after the metadata and in the same allocation, so
+        // subtracting the metadata's size will stay in the same allocation.
+        let ptr = unsafe { self.mid.byte_sub(size_of::<<U as Pointee>::Metadata>()) }.cast();
+        // SAFETY:
+        // For the same reasons as above, `ptr` is a pointer to the metadata.
+        // The allocation has not been deallocated (safety condition) and the data is not
+        // referenced (safety condition), so it is valid for writes.
+        unsafe { ptr.write(metadata) };
+    }
+
     #[must_use]
     /// Calculates the negative offset from the mid pointer to the header.
     ///
@@ -171,6 +267,30 @@ where
         self.mid
     }
 
+    #[must_use]
+    #[inline]
+    /// Checks whether two node pointers point to the same node, even if they have different header types.
+    ///
+    /// This compares the value pointers, as [`PartialEq`] does for node pointers with the same header type.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let node = NodePtr::<u32, u32>::allocate_sized();
+    /// // SAFETY: the header is never read through this pointer.
+    /// let other = unsafe { node.to_header_opaque().to_transparent::<[u8; 4]>() };
+    /// assert!(node.same_value_ptr_as(other));
+    ///
+    /// let different = NodePtr::<u32, u32>::allocate_sized();
+    /// assert!(!node.same_value_ptr_as(different));
+    ///
+    /// unsafe { node.deallocate_global() };
+    /// unsafe { different.deallocate_global() };
+    /// ```
+    pub fn same_value_ptr_as<Header2>(self, other: NodePtr<Header2, U>) -> bool {
+        self.mid == other.mid
+    }
+
     #[must_use]
     #[inline]
     /// Get a node back from its value pointer.
@@ -199,6 +319,32 @@ where
         )
     }
 
+    #[must_use]
+    #[inline]
+    /// Writes the node's header, returning the node for chaining.
+    ///
+    /// This should be called exactly once on a freshly allocated node, before it is used.
+    /// Any existing header is overwritten without being dropped.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - There must not be any living references to the node's header.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let node = unsafe { NodePtr::<u8, str>::allocate_string(0).write_header(3) };
+    /// assert_eq!(unsafe { node.header_ptr().read() }, 3);
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub unsafe fn write_header(self, header: Header) -> Self {
+        // SAFETY:
+        // The header pointer is in the node's allocation, which has not been deallocated (safety
+        // condition) and is not referenced (safety condition), so it is valid for writes.
+        unsafe { self.header_ptr().write(header) };
+        self
+    }
+
     #[must_use]
     #[inline]
     /// Creates a node from the base pointer to a node allocation, the offsets and the metadata
@@ -233,6 +379,34 @@ where
         }
     }
 
+    #[must_use]
+    #[inline]
+    /// Creates a node from the base pointer to an allocation made with a [`NodeLayout`].
+    ///
+    /// This writes the metadata into the node, but does not initialise the header or the value.
+    /// Unless the value is more aligned than the header, the node's header pointer is the same as the base pointer, so it can be used to deallocate the node.
+    ///
+    /// # Safety
+    /// - `base` must be valid for writes of `layout.layout()` and properly aligned for it.
+    /// - `metadata` must be valid for the value layout that `layout` was calculated from.
+    pub const unsafe fn from_base_ptr_with_layout(
+        base: NonNull<u8>,
+        layout: NodeLayout<Header, U>,
+        metadata: <U as Pointee>::Metadata,
+    ) -> Self {
+        // SAFETY:
+        // The offsets are from the same layout calculation as the allocation of `base`, which is
+        // valid for writes (safety conditions).
+        unsafe {
+            Self::from_base_ptr(
+                base.cast(),
+                layout.metadata_offset(),
+                layout.value_offset(),
+                metadata,
+            )
+        }
+    }
+
     /// Attempts to allocate a node with the given value layout and metadata in the given allocator.
     ///
     /// **Using this function is not recommended!** Try to use one of the other allocation functions first.
@@ -251,7 +425,8 @@ where
     where
         A: Allocator,
     {
-        let (layout, metadata_offset, value_offset) = Self::layout_from_value(value_layout)?;
+        let (layout, metadata_offset, value_offset) = Self::layout_from_value(value_layout)
+            .map_err(|error| error.with_context("allocate_with_layout"))?;
         match allocator.allocate(layout) {
             Ok(base) => Ok(
                 // SAFETY:
@@ -261,7 +436,9 @@ where
                     Self::from_base_ptr(base.cast(), metadata_offset, value_offset, metadata)
                 },
             ),
-            Err(error) => Err(AllocateError::new_alloc(error, layout)),
+            Err(error) => {
+                Err(AllocateError::new_alloc(error, layout).with_context("allocate_with_layout"))
+            }
         }
     }
 
@@ -272,6 +449,23 @@ where
     ///
     /// # Errors
     /// If allocation fails, or an arithmetic overflow occours in [`Layout::extend`], this will return an [`AllocateError`].
+    /// If the header and the value cannot both be aligned, this returns an [`AllocateError`] for which [`AllocateError::is_unalignable`] is `true`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use dynode::NodePtr;
+    /// # use std::alloc::Global;
+    /// #[repr(align(16))]
+    /// struct Header([u8; 16]);
+    /// #[repr(align(16))]
+    /// struct Value(u8);
+    ///
+    /// // The metadata between the header and the value cannot be padded to align both
+    /// let error = unsafe { NodePtr::<Header, [Value]>::try_allocate_in(1, Global) }.unwrap_err();
+    /// assert!(error.is_unalignable());
+    /// assert_eq!(error.context(), Some("allocate"));
+    /// ```
     pub unsafe fn try_allocate_in<A>(
         metadata: <U as Pointee>::Metadata,
         allocator: A,
@@ -284,7 +478,8 @@ where
             // `metadata` is valid under the safety conditions for `Layout::for_value_raw` (safety
             // condition).
             unsafe { Self::layout_from_metadata(metadata) }
-        )?;
+        )
+        .map_err(|error| error.with_context("allocate"))?;
         match allocator.allocate(layout) {
             Ok(base) => Ok(
                 // SAFETY:
@@ -294,7 +489,143 @@ where
                     Self::from_base_ptr(base.cast(), metadata_offset, value_offset, metadata)
                 },
             ),
-            Err(error) => Err(AllocateError::new_alloc(error, layout)),
+            Err(error) => Err(AllocateError::new_alloc(error, layout).with_context("allocate")),
+        }
+    }
+
+    /// Attempts to allocate a node with the given metadata in the given allocator, with the value aligned to at least `align`.
+    ///
+    /// This is useful for values that need a larger alignment than their type, such as buffers that are read with SIMD instructions.
+    /// The value's layout is calculated from the metadata and then aligned to `align` before the header is added, so any extra padding goes before the header.
+    ///
+    /// Nodes allocated with this must only be deallocated with [`Self::deallocate_aligned`], using the same `align`.
+    /// Other functions that calculate the node's layout from its metadata, such as [`Self::deallocate`] or [`Self::try_grow_in`], must not be used on it.
+    ///
+    /// # Safety
+    /// `metadata` must be valid under the safety conditions for [`Layout::for_value_raw`].
+    ///
+    /// # Errors
+    /// If `align` is not a power of two, the layout overflows or allocation fails, this will return an [`AllocateError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use dynode::NodePtr;
+    /// # use std::alloc::Global;
+    /// let node = unsafe { NodePtr::<u8, [u8]>::try_allocate_aligned_in(3, 64, Global) }.unwrap();
+    /// assert!(node.value_ptr().cast::<u8>().as_ptr().addr() % 64 == 0);
+    ///
+    /// unsafe { node.header_ptr().write(7) };
+    /// unsafe { node.value_ptr().cast::<[u8; 3]>().write([1, 2, 3]) };
+    /// assert_eq!(unsafe { node.header_ptr().read() }, 7);
+    /// assert_eq!(unsafe { node.data_ptr().as_ref() }, [1, 2, 3]);
+    /// unsafe { node.deallocate_aligned(64, Global) };
+    ///
+    /// assert!(unsafe { NodePtr::<u8, [u8]>::try_allocate_aligned_in(3, 3, Global) }.is_err());
+    /// ```
+    pub unsafe fn try_allocate_aligned_in<A>(
+        metadata: <U as Pointee>::Metadata,
+        align: usize,
+        allocator: A,
+    ) -> Result<Self, AllocateError>
+    where
+        A: Allocator,
+    {
+        let (layout, metadata_offset, value_offset) = (
+            // SAFETY:
+            // `metadata` is valid under the safety conditions for `Layout::for_value_raw` (safety
+            // condition).
+            unsafe { Self::layout_from_metadata_aligned(metadata, align) }
+        )
+        .map_err(|error| error.with_context("allocate_aligned"))?;
+        match allocator.allocate(layout) {
+            Ok(base) => Ok(
+                // SAFETY:
+                // The offsets are from the same call to `Self::layout_from_metadata_aligned` as the
+                // layout of `base`.
+                // `base` is valid for writes.
+                unsafe {
+                    Self::from_base_ptr(base.cast(), metadata_offset, value_offset, metadata)
+                },
+            ),
+            Err(error) => {
+                Err(AllocateError::new_alloc(error, layout).with_context("allocate_aligned"))
+            }
+        }
+    }
+
+    /// Attempts to allocate a node with the given value layout and metadata in the given allocator, with the value zeroed.
+    ///
+    /// **Using this function is not recommended!** Try to use one of the other allocation functions first.
+    /// The returned node's value pointer will be valid for reads and writes within the size of the `value_layout`.
+    /// Only the value's bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Safety
+    /// `metadata` must be valid for `value_layout`.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::extend`], this will return an [`AllocateError`].
+    pub unsafe fn try_allocate_zeroed_with_layout_in<A>(
+        metadata: <U as Pointee>::Metadata,
+        value_layout: Layout,
+        allocator: A,
+    ) -> Result<Self, AllocateError>
+    where
+        A: Allocator,
+    {
+        let (layout, metadata_offset, value_offset) = Self::layout_from_value(value_layout)
+            .map_err(|error| error.with_context("allocate_zeroed_with_layout"))?;
+        match allocator.allocate_zeroed(layout) {
+            Ok(base) => Ok(
+                // SAFETY:
+                // The offsets are from the same call to `Self::layout_from_value` as the layout of `base`.
+                // `base` is valid for writes.
+                unsafe {
+                    Self::from_base_ptr(base.cast(), metadata_offset, value_offset, metadata)
+                },
+            ),
+            Err(error) => {
+                Err(AllocateError::new_alloc(error, layout)
+                    .with_context("allocate_zeroed_with_layout"))
+            }
+        }
+    }
+
+    /// Attempts to allocate a node with the given metadata in the given allocator, with the value zeroed.
+    ///
+    /// Only the value's bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Safety
+    /// `metadata` must be valid under the safety conditions for [`Layout::for_value_raw`].
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::extend`], this will return an [`AllocateError`].
+    pub unsafe fn try_allocate_zeroed_in<A>(
+        metadata: <U as Pointee>::Metadata,
+        allocator: A,
+    ) -> Result<Self, AllocateError>
+    where
+        A: Allocator,
+    {
+        let (layout, metadata_offset, value_offset) = (
+            // SAFETY:
+            // `metadata` is valid under the safety conditions for `Layout::for_value_raw` (safety
+            // condition).
+            unsafe { Self::layout_from_metadata(metadata) }
+        )
+        .map_err(|error| error.with_context("allocate_zeroed"))?;
+        match allocator.allocate_zeroed(layout) {
+            Ok(base) => Ok(
+                // SAFETY:
+                // The offsets are from the same call to `Self::layout_from_metadata` as the layout of `base`.
+                // `base` is valid for writes.
+                unsafe {
+                    Self::from_base_ptr(base.cast(), metadata_offset, value_offset, metadata)
+                },
+            ),
+            Err(error) => {
+                Err(AllocateError::new_alloc(error, layout).with_context("allocate_zeroed"))
+            }
         }
     }
 
@@ -310,7 +641,9 @@ where
     {
         let metadata = ptr::metadata(ptr::null::<T>() as *const U);
 
-        let (layout, metadata_offset, value_offset) = Self::layout_from_value(Layout::new::<T>())?;
+        let (layout, metadata_offset, value_offset) =
+            Self::layout_from_value(Layout::new::<T>())
+                .map_err(|error| error.with_context("allocate_unsize"))?;
         #[cfg(debug_assertions)]
         {
             // SAFETY:
@@ -330,7 +663,9 @@ where
                     Self::from_base_ptr(base.cast(), metadata_offset, value_offset, metadata)
                 },
             ),
-            Err(error) => Err(AllocateError::new_alloc(error, layout)),
+            Err(error) => {
+                Err(AllocateError::new_alloc(error, layout).with_context("allocate_unsize"))
+            }
         }
     }
 
@@ -368,6 +703,47 @@ where
         unsafe { Self::try_allocate_in(metadata, crate::alloc::Global) }
     }
 
+    #[cfg(feature = "alloc")]
+    /// Attempts to allocate a node with the given value layout and metadata, with the value zeroed.
+    ///
+    /// **Using this function is not recommended!** Try to use one of the other allocation functions first.
+    /// Only the value's bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Safety
+    /// `metadata` must be valid for `value_layout`.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::extend`], this will return an [`AllocateError`].
+    pub unsafe fn try_allocate_zeroed_with_layout(
+        metadata: <U as Pointee>::Metadata,
+        value_layout: Layout,
+    ) -> Result<Self, AllocateError> {
+        // SAFETY:
+        // `metadata` is valid for `value_layout` (safety condition).
+        unsafe {
+            Self::try_allocate_zeroed_with_layout_in(metadata, value_layout, crate::alloc::Global)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Attempts to allocate a node with the given metadata, with the value zeroed.
+    ///
+    /// Only the value's bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Safety
+    /// `metadata` must be valid under the safety conditions for [`Layout::for_value_raw`].
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::extend`], this will return an [`AllocateError`].
+    pub unsafe fn try_allocate_zeroed(
+        metadata: <U as Pointee>::Metadata,
+    ) -> Result<Self, AllocateError> {
+        // SAFETY:
+        // `metadata` is be valid under the safety conditions for [`Layout::for_value_raw`] (safety
+        // condition).
+        unsafe { Self::try_allocate_zeroed_in(metadata, crate::alloc::Global) }
+    }
+
     #[cfg(feature = "alloc")]
     /// Attempts to allocate a node with value layout of `T` but metadata of `&T as &U`.
     /// The resulting node's value pointer will be valid for writes of `T`.
@@ -480,6 +856,96 @@ where
         Self::allocate_unsize_in::<_, T>(crate::alloc::Global)
     }
 
+    /// Attempts to allocate a new node in the given allocator with a clone of this node's value and the given header.
+    ///
+    /// The new node has the same metadata, and therefore the same layout, as this node.
+    /// If cloning the value panics, the new node is deallocated before the panic continues.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node's value must be initialised.
+    /// - There must not be a living mutable reference to the node's data.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use dynode::NodePtr;
+    /// let node = NodePtr::<(), [u8]>::allocate_array(3);
+    /// unsafe { node.value_ptr().cast::<[u8; 3]>().write([1, 2, 3]) };
+    ///
+    /// let clone = unsafe { node.try_clone_node_in((), std::alloc::Global) }.unwrap();
+    /// unsafe { node.deallocate_global() };
+    ///
+    /// assert_eq!(unsafe { clone.data_ptr().as_ref() }, &[1, 2, 3]);
+    /// unsafe { clone.deallocate_global() };
+    /// ```
+    pub unsafe fn try_clone_node_in<A>(
+        self,
+        header: Header,
+        allocator: A,
+    ) -> Result<Self, AllocateError>
+    where
+        U: CloneToUninit,
+        A: Allocator,
+    {
+        struct DeallocateGuard<'a, Header, U, A>
+        where
+            U: ?Sized,
+            <U as Pointee>::Metadata: Copy,
+            A: Allocator,
+        {
+            node: NodePtr<Header, U>,
+            allocator: &'a A,
+        }
+
+        impl<Header, U, A> Drop for DeallocateGuard<'_, Header, U, A>
+        where
+            U: ?Sized,
+            <U as Pointee>::Metadata: Copy,
+            A: Allocator,
+        {
+            fn drop(&mut self) {
+                // SAFETY:
+                // The node was allocated by `self.allocator` and has not been initialised or
+                // exposed, so there are no aliases or references to it.
+                unsafe { self.node.deallocate(self.allocator) };
+            }
+        }
+
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let metadata = unsafe { self.metadata() };
+        // SAFETY:
+        // The metadata is from an allocated node, so it is valid for `Layout::for_value_raw`.
+        let node = unsafe { Self::try_allocate_in(metadata, &allocator) }
+            .map_err(|error| error.with_context("clone_node"))?;
+        let guard = DeallocateGuard {
+            node,
+            allocator: &allocator,
+        };
+
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let data = unsafe { self.data_ptr() };
+        // SAFETY:
+        // The node's value is initialised and there are no living mutable references to it
+        // (safety condition).
+        let value = unsafe { data.as_ref() };
+        // SAFETY:
+        // The new node has the same metadata as `value`, so its value pointer is valid for writes
+        // of `value`'s layout.
+        unsafe { value.clone_to_uninit(node.value_ptr().cast().as_ptr()) };
+        mem::forget(guard);
+
+        // SAFETY:
+        // The header pointer is in the node's allocation, which is valid for writes.
+        unsafe { node.header_ptr().write(header) };
+        Ok(node)
+    }
+
     /// Deallocates the node.
     ///
     /// Note that this does not drop the contined value.
@@ -514,6 +980,42 @@ where
         unsafe { allocator.deallocate(base, layout) };
     }
 
+    /// Deallocates a node that was allocated with [`Self::try_allocate_aligned_in`].
+    ///
+    /// Note that this does not drop the contined value.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated already.
+    /// - The node must not be used at all after this call; this includes aliases!
+    /// - `allocator` must be the same allocator used to allocate the node.
+    /// - `align` must be the same alignment used to allocate the node.
+    /// - This must not be called whilst there is a living reference to the node's data.
+    pub unsafe fn deallocate_aligned<A>(self, align: usize, allocator: A)
+    where
+        A: Allocator,
+    {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let metadata = unsafe { self.metadata() };
+        // SAFETY:
+        // The metadata must be valid for the allocation.
+        let layout_result = unsafe { Self::layout_from_metadata_aligned(metadata, align) };
+        debug_assert!(layout_result.is_ok());
+        // SAFETY:
+        // This was calculated with the same alignment when allocating the node (safety condition),
+        // so it cannot fail.
+        let (layout, _, value_offset) = unsafe { layout_result.unwrap_unchecked() };
+
+        // SAFETY:
+        // Subtracting `value_offset` from `self.mid` gives the base pointer, which is in the same
+        // allocation.
+        let base = unsafe { self.mid.byte_sub(value_offset) }.cast();
+        // SAFETY:
+        // `allocator` is the same allocator used to allocate the node (safety condition).
+        // `layout` is the same layout used to allocate the node.
+        unsafe { allocator.deallocate(base, layout) };
+    }
+
     #[cfg(feature = "alloc")]
     /// Deallocates the node.
     ///
@@ -548,6 +1050,7 @@ impl<Header, T> NodePtr<Header, T> {
         // SAFETY:
         // As `T` is sized, `()` is valid for it's layout.
         unsafe { Self::try_allocate_with_layout_in((), Layout::new::<T>(), allocator) }
+            .map_err(|error| error.with_context("allocate_sized"))
     }
 
     #[cfg(feature = "alloc")]
@@ -561,6 +1064,35 @@ impl<Header, T> NodePtr<Header, T> {
         Self::try_allocate_sized_in(crate::alloc::Global)
     }
 
+    /// Attempts to allocate a node for a value of type `T` in the given allocator, with the value zeroed.
+    ///
+    /// The returned node's value pointer is valid for writes of `T`.
+    /// Only the value's bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::extend`], this will return an [`AllocateError`].
+    pub fn try_allocate_zeroed_sized_in<A>(allocator: A) -> Result<Self, AllocateError>
+    where
+        A: Allocator,
+    {
+        // SAFETY:
+        // As `T` is sized, `()` is valid for it's layout.
+        unsafe { Self::try_allocate_zeroed_with_layout_in((), Layout::new::<T>(), allocator) }
+            .map_err(|error| error.with_context("allocate_zeroed_sized"))
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Attempts to allocate a node for a value of type `T`, with the value zeroed.
+    ///
+    /// The returned node's value pointer is valid for writes of `T`.
+    /// Only the value's bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::extend`], this will return an [`AllocateError`].
+    pub fn try_allocate_zeroed_sized() -> Result<Self, AllocateError> {
+        Self::try_allocate_zeroed_sized_in(crate::alloc::Global)
+    }
+
     #[must_use]
     /// Allocates a node for a value of type `T` in the given allocator.
     ///
@@ -583,6 +1115,101 @@ impl<Header, T> NodePtr<Header, T> {
     pub fn allocate_sized() -> Self {
         Self::allocate_sized_in(crate::alloc::Global)
     }
+
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the node's value as a [`MaybeUninit`].
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node must not be deallocated while the returned reference is alive.
+    /// - There must not be a living mutable reference to the node's value.
+    pub const unsafe fn as_uninit_ref<'a>(self) -> &'a MaybeUninit<T> {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let data = unsafe { self.data_ptr() };
+        // SAFETY:
+        // The value pointer is valid for reads of `T` and there are no mutable references to it
+        // (safety condition).
+        unsafe { data.as_uninit_ref() }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets a mutable reference to the node's value as a [`MaybeUninit`].
+    ///
+    /// This can be used to initialise the value.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node must not be deallocated while the returned reference is alive.
+    /// - There must not be any other living references to the node's value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let node = NodePtr::<(), u32>::allocate_sized();
+    /// unsafe { node.as_uninit_mut() }.write(5);
+    ///
+    /// assert_eq!(unsafe { node.as_uninit_ref().assume_init_read() }, 5);
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub const unsafe fn as_uninit_mut<'a>(self) -> &'a mut MaybeUninit<T> {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let data = unsafe { self.data_ptr() };
+        // SAFETY:
+        // The value pointer is valid for reads and writes of `T` and there are no other references
+        // to it (safety condition).
+        unsafe { data.as_uninit_mut() }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Writes the node's value, returning the node for chaining.
+    ///
+    /// This should be called exactly once on a freshly allocated node, before it is used.
+    /// Any existing value is overwritten without being dropped.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - There must not be any living references to the node's value.
+    pub const unsafe fn write_value(self, value: T) -> Self {
+        // SAFETY:
+        // The value pointer is valid for writes of `T`, as the node has not been deallocated
+        // (safety condition), and it is not referenced (safety condition).
+        unsafe { self.value_ptr().cast::<T>().write(value) };
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Writes the node's header and value, returning the node for chaining.
+    ///
+    /// This should be called exactly once on a freshly allocated node, before it is used.
+    /// Any existing header and value are overwritten without being dropped.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - There must not be any living references to the node's header or value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let node = unsafe { NodePtr::<char, u32>::allocate_sized().init_sized('a', 5) };
+    ///
+    /// assert_eq!(unsafe { node.header_ptr().read() }, 'a');
+    /// assert_eq!(unsafe { node.data_ptr().read() }, 5);
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub unsafe fn init_sized(self, header: Header, value: T) -> Self {
+        // SAFETY:
+        // The node has not been deallocated and its header is not referenced (safety conditions).
+        let node = unsafe { self.write_header(header) };
+        // SAFETY:
+        // The node has not been deallocated and its value is not referenced (safety conditions).
+        unsafe { node.write_value(value) }
+    }
 }
 
 impl<Header, T> NodePtr<Header, [T]> {
@@ -594,11 +1221,19 @@ impl<Header, T> NodePtr<Header, [T]> {
     where
         A: Allocator,
     {
-        let layout = Layout::array::<T>(length)?;
-        // SAFETY:
-        // The length is valid metadata for the layout from `Layout::array` with the same type and
-        // length.
-        unsafe { Self::try_allocate_with_layout_in(length, layout, allocator) }
+        Layout::array::<T>(length)
+            .map_err(AllocateError::new_layout)
+            .and_then(|layout| {
+                // SAFETY:
+                // The length is valid metadata for the layout from `Layout::array` with the same
+                // type and length.
+                unsafe { Self::try_allocate_with_layout_in(length, layout, allocator) }
+            })
+            .map_err(|error| {
+                error
+                    .with_requested_len(length)
+                    .with_context("allocate_array")
+            })
     }
 
     #[cfg(feature = "alloc")]
@@ -610,6 +1245,54 @@ impl<Header, T> NodePtr<Header, [T]> {
         Self::try_allocate_array_in(length, crate::alloc::Global)
     }
 
+    /// Attempts to allocate an array of `T` with the given length in the given allocator, with the elements zeroed.
+    ///
+    /// Only the elements' bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`], this will return an [`AllocateError`].
+    pub fn try_allocate_zeroed_array_in<A>(
+        length: usize,
+        allocator: A,
+    ) -> Result<Self, AllocateError>
+    where
+        A: Allocator,
+    {
+        Layout::array::<T>(length)
+            .map_err(AllocateError::new_layout)
+            .and_then(|layout| {
+                // SAFETY:
+                // The length is valid metadata for the layout from `Layout::array` with the same
+                // type and length.
+                unsafe { Self::try_allocate_zeroed_with_layout_in(length, layout, allocator) }
+            })
+            .map_err(|error| {
+                error
+                    .with_requested_len(length)
+                    .with_context("allocate_zeroed_array")
+            })
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Attempts to allocate an array of `T` with the given length, with the elements zeroed.
+    ///
+    /// Only the elements' bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`], this will return an [`AllocateError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let node = NodePtr::<(), [u8]>::try_allocate_zeroed_array(4).unwrap();
+    /// assert_eq!(unsafe { node.data_ptr().as_ref() }, &[0; 4]);
+    ///
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub fn try_allocate_zeroed_array(length: usize) -> Result<Self, AllocateError> {
+        Self::try_allocate_zeroed_array_in(length, crate::alloc::Global)
+    }
+
     #[must_use]
     /// Allocates an array of `T` with the given length in the given allocator.
     pub fn allocate_array_in<A>(length: usize, allocator: A) -> Self
@@ -628,6 +1311,257 @@ impl<Header, T> NodePtr<Header, [T]> {
     pub fn allocate_array(length: usize) -> Self {
         Self::allocate_array_in(length, crate::alloc::Global)
     }
+
+    #[must_use]
+    #[inline]
+    /// Gets a slice of the node's elements as [`MaybeUninit`]s.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node must not be deallocated or resized while the returned slice is alive.
+    /// - There must not be a living mutable reference to the node's value.
+    pub const unsafe fn as_uninit_slice<'a>(self) -> &'a [MaybeUninit<T>] {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let data = unsafe { self.data_ptr() };
+        // SAFETY:
+        // The value pointer is valid for reads of the array and there are no mutable references to
+        // it (safety condition).
+        unsafe { data.as_uninit_slice() }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets a mutable slice of the node's elements as [`MaybeUninit`]s.
+    ///
+    /// This can be used to initialise the elements.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node must not be deallocated or resized while the returned slice is alive.
+    /// - There must not be any other living references to the node's value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let node = NodePtr::<(), [u8]>::allocate_array(3);
+    /// let elements = unsafe { node.as_uninit_slice_mut() };
+    /// for (element, value) in elements.iter_mut().zip([1, 2, 3]) {
+    ///     element.write(value);
+    /// }
+    ///
+    /// assert_eq!(unsafe { node.data_ptr().as_ref() }, &[1, 2, 3]);
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub const unsafe fn as_uninit_slice_mut<'a>(self) -> &'a mut [MaybeUninit<T>] {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let data = unsafe { self.data_ptr() };
+        // SAFETY:
+        // The value pointer is valid for reads and writes of the array and there are no other
+        // references to it (safety condition).
+        unsafe { data.as_uninit_slice_mut() }
+    }
+
+    /// Calculates the layouts needed to resize the array node to `new_length`.
+    ///
+    /// On success, this returns the base pointer, the current layout, the new layout, the metadata offset and the value offset.
+    ///
+    /// # Safety
+    /// The node must not have been deallocated.
+    ///
+    /// # Errors
+    /// If an arithmetic overflow occours in [`Layout::array`] or [`Layout::extend`], this will return an [`AllocateError`].
+    unsafe fn resize_layouts(
+        self,
+        new_length: usize,
+    ) -> Result<(NonNull<u8>, Layout, Layout, usize, usize), AllocateError> {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let old_length = unsafe { self.metadata() };
+        // SAFETY:
+        // The length is valid metadata for an allocated array node.
+        let layout_result = unsafe { Self::layout_from_metadata(old_length) };
+        debug_assert!(layout_result.is_ok());
+        // SAFETY:
+        // This was calculated when allocating the node, so it cannot fail.
+        let (old_layout, _, old_value_offset) = unsafe { layout_result.unwrap_unchecked() };
+
+        let (new_layout, metadata_offset, value_offset) = Layout::array::<T>(new_length)
+            .map_err(AllocateError::new_layout)
+            .and_then(Self::layout_from_value)
+            .map_err(|error| error.with_requested_len(new_length))?;
+        // The offsets only depend on the header, metadata and `T`, not the length
+        debug_assert_eq!(old_value_offset, value_offset);
+
+        // SAFETY:
+        // Subtracting `value_offset` from `self.mid` gives the base pointer, which is in the same
+        // allocation.
+        let base = unsafe { self.mid.byte_sub(value_offset) }.cast();
+        Ok((base, old_layout, new_layout, metadata_offset, value_offset))
+    }
+
+    /// Attempts to grow the array node to `new_length` in the given allocator.
+    ///
+    /// The header and the existing elements are preserved, and the metadata is updated to the new length.
+    /// The new elements are uninitialised.
+    /// On success, the old node pointer (and any aliases) must not be used; use the returned node instead.
+    /// On failure, the old node is left unchanged.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - `allocator` must be the same allocator used to allocate the node.
+    /// - `new_length` must be greater than or equal to the node's current length.
+    /// - This must not be called whilst there is a living reference to the node's header or data.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`] or [`Layout::extend`], this will return an [`AllocateError`].
+    pub unsafe fn try_grow_in<A>(
+        self,
+        new_length: usize,
+        allocator: A,
+    ) -> Result<Self, AllocateError>
+    where
+        A: Allocator,
+    {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let (base, old_layout, new_layout, metadata_offset, value_offset) =
+            unsafe { self.resize_layouts(new_length) }
+                .map_err(|error| error.with_context("grow"))?;
+        debug_assert!(new_layout.size() >= old_layout.size());
+
+        // SAFETY:
+        // `base` was allocated by `allocator` (safety condition) with `old_layout`.
+        // `new_layout` is at least as large as `old_layout` as `new_length` is at least the current
+        // length (safety condition).
+        match unsafe { allocator.grow(base, old_layout, new_layout) } {
+            Ok(base) => Ok(
+                // SAFETY:
+                // The offsets are from the same call to `Self::layout_from_value` as the layout of `base`.
+                // `base` is valid for writes.
+                unsafe {
+                    Self::from_base_ptr(base.cast(), metadata_offset, value_offset, new_length)
+                },
+            ),
+            Err(error) => Err(AllocateError::new_alloc(error, new_layout)
+                .with_requested_len(new_length)
+                .with_context("grow")),
+        }
+    }
+
+    /// Attempts to shrink the array node to `new_length` in the given allocator.
+    ///
+    /// The header and the first `new_length` elements are preserved, and the metadata is updated to the new length.
+    /// Note that this does not drop the removed elements.
+    /// On success, the old node pointer (and any aliases) must not be used; use the returned node instead.
+    /// On failure, the old node is left unchanged.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - `allocator` must be the same allocator used to allocate the node.
+    /// - `new_length` must be less than or equal to the node's current length.
+    /// - This must not be called whilst there is a living reference to the node's header or data.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`] or [`Layout::extend`], this will return an [`AllocateError`].
+    pub unsafe fn try_shrink_in<A>(
+        self,
+        new_length: usize,
+        allocator: A,
+    ) -> Result<Self, AllocateError>
+    where
+        A: Allocator,
+    {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let (base, old_layout, new_layout, metadata_offset, value_offset) =
+            unsafe { self.resize_layouts(new_length) }
+                .map_err(|error| error.with_context("shrink"))?;
+        debug_assert!(new_layout.size() <= old_layout.size());
+
+        // SAFETY:
+        // `base` was allocated by `allocator` (safety condition) with `old_layout`.
+        // `new_layout` is at most as large as `old_layout` as `new_length` is at most the current
+        // length (safety condition).
+        match unsafe { allocator.shrink(base, old_layout, new_layout) } {
+            Ok(base) => Ok(
+                // SAFETY:
+                // The offsets are from the same call to `Self::layout_from_value` as the layout of `base`.
+                // `base` is valid for writes.
+                unsafe {
+                    Self::from_base_ptr(base.cast(), metadata_offset, value_offset, new_length)
+                },
+            ),
+            Err(error) => Err(AllocateError::new_alloc(error, new_layout)
+                .with_requested_len(new_length)
+                .with_context("shrink")),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Attempts to grow the array node to `new_length`.
+    ///
+    /// See [`Self::try_grow_in`] for more information.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node must have been allocated using the global allocator.
+    /// - `new_length` must be greater than or equal to the node's current length.
+    /// - This must not be called whilst there is a living reference to the node's header or data.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`] or [`Layout::extend`], this will return an [`AllocateError`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let node = NodePtr::<u16, [u8]>::allocate_array(2);
+    /// unsafe {
+    ///     node.header_ptr().write(7);
+    ///     node.value_ptr().cast::<[u8; 2]>().write([1, 2]);
+    /// }
+    ///
+    /// let node = unsafe { node.try_grow(4) }.unwrap();
+    /// unsafe { node.value_ptr().cast::<u8>().add(2).cast::<[u8; 2]>().write([3, 4]) };
+    ///
+    /// assert_eq!(unsafe { node.header_ptr().read() }, 7);
+    /// assert_eq!(unsafe { node.data_ptr().as_ref() }, &[1, 2, 3, 4]);
+    ///
+    /// let node = unsafe { node.try_shrink(1) }.unwrap();
+    /// assert_eq!(unsafe { node.header_ptr().read() }, 7);
+    /// assert_eq!(unsafe { node.data_ptr().as_ref() }, &[1]);
+    ///
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub unsafe fn try_grow(self, new_length: usize) -> Result<Self, AllocateError> {
+        // SAFETY:
+        // The node has not been deallocated and was allocated with `alloc::Global` (safety
+        // condition).
+        // `new_length` is at least the current length (safety condition).
+        unsafe { self.try_grow_in(new_length, crate::alloc::Global) }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Attempts to shrink the array node to `new_length`.
+    ///
+    /// See [`Self::try_shrink_in`] for more information.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node must have been allocated using the global allocator.
+    /// - `new_length` must be less than or equal to the node's current length.
+    /// - This must not be called whilst there is a living reference to the node's header or data.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`] or [`Layout::extend`], this will return an [`AllocateError`].
+    pub unsafe fn try_shrink(self, new_length: usize) -> Result<Self, AllocateError> {
+        // SAFETY:
+        // The node has not been deallocated and was allocated with `alloc::Global` (safety
+        // condition).
+        // `new_length` is at most the current length (safety condition).
+        unsafe { self.try_shrink_in(new_length, crate::alloc::Global) }
+    }
 }
 
 impl<Header> NodePtr<Header, str> {
@@ -639,11 +1573,19 @@ impl<Header> NodePtr<Header, str> {
     where
         A: Allocator,
     {
-        let layout = Layout::array::<u8>(length)?;
-        // SAFETY:
-        // The length is valid metadata for the layout from `Layout::array` with the `u8` type and
-        // same length (byte arrays have the same layout as strings).
-        unsafe { Self::try_allocate_with_layout_in(length, layout, allocator) }
+        Layout::array::<u8>(length)
+            .map_err(AllocateError::new_layout)
+            .and_then(|layout| {
+                // SAFETY:
+                // The length is valid metadata for the layout from `Layout::array` with the `u8`
+                // type and same length (byte arrays have the same layout as strings).
+                unsafe { Self::try_allocate_with_layout_in(length, layout, allocator) }
+            })
+            .map_err(|error| {
+                error
+                    .with_requested_len(length)
+                    .with_context("allocate_string")
+            })
     }
 
     #[cfg(feature = "alloc")]
@@ -655,6 +1597,47 @@ impl<Header> NodePtr<Header, str> {
         Self::try_allocate_string_in(length, crate::alloc::Global)
     }
 
+    /// Attempts to allocate a string with the given length in the given allocator, with the bytes zeroed.
+    ///
+    /// As zero bytes are valid UTF-8, the string is initialised to `length` null characters.
+    /// Only the string's bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`], this will return an [`AllocateError`].
+    pub fn try_allocate_zeroed_string_in<A>(
+        length: usize,
+        allocator: A,
+    ) -> Result<Self, AllocateError>
+    where
+        A: Allocator,
+    {
+        Layout::array::<u8>(length)
+            .map_err(AllocateError::new_layout)
+            .and_then(|layout| {
+                // SAFETY:
+                // The length is valid metadata for the layout from `Layout::array` with the `u8`
+                // type and same length (byte arrays have the same layout as strings).
+                unsafe { Self::try_allocate_zeroed_with_layout_in(length, layout, allocator) }
+            })
+            .map_err(|error| {
+                error
+                    .with_requested_len(length)
+                    .with_context("allocate_zeroed_string")
+            })
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Attempts to allocate a string with the given length, with the bytes zeroed.
+    ///
+    /// As zero bytes are valid UTF-8, the string is initialised to `length` null characters.
+    /// Only the string's bytes are guaranteed to be zero; the header is not initialised and must still be written.
+    ///
+    /// # Errors
+    /// If allocation fails, or an arithmetic overflow occours in [`Layout::array`], this will return an [`AllocateError`].
+    pub fn try_allocate_zeroed_string(length: usize) -> Result<Self, AllocateError> {
+        Self::try_allocate_zeroed_string_in(length, crate::alloc::Global)
+    }
+
     #[must_use]
     /// Allocates a string with the given length in the given allocator.
     pub fn allocate_string_in<A>(length: usize, allocator: A) -> Self
@@ -673,4 +1656,58 @@ impl<Header> NodePtr<Header, str> {
     pub fn allocate_string(length: usize) -> Self {
         Self::allocate_string_in(length, crate::alloc::Global)
     }
+
+    #[must_use]
+    #[inline]
+    /// Gets the node's string as a slice of [`MaybeUninit`] bytes.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node must not be deallocated while the returned slice is alive.
+    /// - There must not be a living mutable reference to the node's value.
+    pub const unsafe fn as_uninit_bytes<'a>(self) -> &'a [MaybeUninit<u8>] {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let (ptr, length) = unsafe { self.data_ptr() }.to_raw_parts();
+        let ptr = NonNull::from_raw_parts(ptr, length);
+        // SAFETY:
+        // The metadata for `str` slices is the same as byte slices of the same length.
+        // There are no mutable references to the value (safety condition).
+        unsafe { ptr.as_uninit_slice() }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets the node's string as a mutable slice of [`MaybeUninit`] bytes.
+    ///
+    /// This can be used to initialise the string.
+    /// Note that the value must be valid UTF-8 before it is used as a `str`.
+    ///
+    /// # Safety
+    /// - The node must not have been deallocated.
+    /// - The node must not be deallocated while the returned slice is alive.
+    /// - There must not be any other living references to the node's value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::NodePtr;
+    /// let node = NodePtr::<(), str>::allocate_string(5);
+    /// let bytes = unsafe { node.as_uninit_bytes_mut() };
+    /// for (byte, value) in bytes.iter_mut().zip(b"Hello") {
+    ///     byte.write(*value);
+    /// }
+    ///
+    /// assert_eq!(unsafe { node.data_ptr().as_ref() }, "Hello");
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub const unsafe fn as_uninit_bytes_mut<'a>(self) -> &'a mut [MaybeUninit<u8>] {
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let (ptr, length) = unsafe { self.data_ptr() }.to_raw_parts();
+        let ptr = NonNull::from_raw_parts(ptr, length);
+        // SAFETY:
+        // The metadata for `str` slices is the same as byte slices of the same length.
+        // There are no other references to the value (safety condition).
+        unsafe { ptr.as_uninit_slice_mut() }
+    }
 }
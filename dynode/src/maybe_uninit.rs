@@ -2,7 +2,9 @@ use core::{
     alloc::{Allocator, Layout},
     any::type_name,
     cmp, fmt,
+    marker::Unsize,
     mem::{self, ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
     ptr::NonNull,
 };
 
@@ -115,6 +117,52 @@ where
     /// - the node is in the same allocation as when created with [`new_maybe_uninit`]
     /// - the node is 'alive' and it's pointer is not aliased
     unsafe fn deallocate(&self, node: HeaderOpaqueNodePtr<U>);
+
+    /// Drop the node's value and deallocate the node.
+    ///
+    /// This is provided for structures to use when removing initialised nodes.
+    /// If the value's destructor panics, the node is still deallocated.
+    ///
+    /// # Safety
+    /// - the node's value must be initialised and not have been dropped
+    /// - the header and metadata must be in the same state as when created with [`new_maybe_uninit`]
+    /// - the node must be 'alive', not in the structure, and its pointer must not be aliased
+    unsafe fn drop_node(&self, node: HeaderOpaqueNodePtr<U>) {
+        struct DeallocateGuard<'a, U, S>
+        where
+            U: ?Sized,
+            S: StructureHandle<U> + ?Sized,
+        {
+            structure: &'a S,
+            node: HeaderOpaqueNodePtr<U>,
+        }
+
+        impl<U, S> Drop for DeallocateGuard<'_, U, S>
+        where
+            U: ?Sized,
+            S: StructureHandle<U> + ?Sized,
+        {
+            fn drop(&mut self) {
+                // SAFETY:
+                // The node is alive, not aliased and its header and metadata are unchanged (safety
+                // condition).
+                unsafe { self.structure.deallocate(self.node) };
+            }
+        }
+
+        // If the value's destructor panics, the guard still deallocates the node
+        let guard = DeallocateGuard {
+            structure: self,
+            node,
+        };
+        // SAFETY:
+        // The node has not been deallocated (safety condition).
+        let ptr = unsafe { node.data_ptr() };
+        // SAFETY:
+        // The value is initialised, has not been dropped and is not aliased (safety condition).
+        unsafe { ptr.drop_in_place() };
+        drop(guard);
+    }
 }
 
 // SAFETY:
@@ -211,6 +259,41 @@ where
         )
     }
 
+    #[must_use]
+    #[inline]
+    /// Gets a pointer to the value that can be used to initialise it in place.
+    ///
+    /// This is the same pointer as [`Self::as_ptr`], but as it borrows the node mutably, it can be written through while that borrow lasts.
+    /// The value may be initialised in any order, for example field by field, but it must be fully initialised and valid for `U` before [`Self::assume_init`] or [`Self::insert`] is called.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use dynode::{NodePtr, StructureHandle, HeaderOpaqueNodePtr};
+    /// # struct Handle;
+    /// # impl StructureHandle<[u16]> for Handle {
+    /// #     type Allocator = std::alloc::Global;
+    /// #     unsafe fn insert(self, node: HeaderOpaqueNodePtr<[u16]>) { unsafe { self.deallocate(node) } }
+    /// #     fn allocator(&self) -> &Self::Allocator { &std::alloc::Global }
+    /// #     unsafe fn deallocate(&self, node: HeaderOpaqueNodePtr<[u16]>) {
+    /// #         unsafe { node.to_transparent::<()>().deallocate_global() }
+    /// #     }
+    /// # }
+    /// let node = NodePtr::<(), [u16]>::allocate_array(3).to_header_opaque();
+    /// let mut node = unsafe { dynode::new_maybe_uninit(Handle, node) };
+    ///
+    /// let ptr = node.as_mut_ptr().cast::<u16>();
+    /// for i in 0..3 {
+    ///     unsafe { ptr.add(i).write(i as u16 * 10) };
+    /// }
+    ///
+    /// let node = unsafe { node.assume_init() };
+    /// assert_eq!(&*node, [0, 10, 20]);
+    /// ```
+    pub const fn as_mut_ptr(&mut self) -> NonNull<U> {
+        self.as_ptr()
+    }
+
     fn into_parts(self) -> (S, HeaderOpaqueNodePtr<U>) {
         let node = self.node;
         let structure = {
@@ -223,6 +306,33 @@ where
         (structure, node)
     }
 
+    #[must_use]
+    /// Converts the node into a raw node pointer without deallocating it.
+    ///
+    /// This transfers ownership of the node to the caller, who is now responsible for eventually deallocating it.
+    /// It can be turned back into a [`MaybeUninitNode`] with [`Self::from_raw`], which will deallocate it when dropped.
+    /// If the node's value has been initialised, it is not dropped.
+    pub fn into_raw(self) -> HeaderOpaqueNodePtr<U> {
+        let (_structure, node) = self.into_parts();
+        node
+    }
+
+    #[must_use]
+    /// Reconstructs a node from a pointer returned by [`Self::into_raw`].
+    ///
+    /// This allows nodes to be allocated ahead of time and inserted later, or moved between compatible structures.
+    /// Structures may store information about where the node will be inserted in its header, so check the structure's documentation before inserting a reconstructed node.
+    ///
+    /// # Safety
+    /// - `node` must have been returned by [`Self::into_raw`] on a node for a structure of the same type as `structure`
+    /// - `node` must have been allocated by an allocator compatible with `structure`'s
+    /// - the node must not have been deallocated, and its header and metadata must not have been changed
+    /// - `structure` must not have been modified since [`Self::into_raw`] in any way that invalidates the information stored in the node's header
+    /// - there must not be any other aliases to the node
+    pub const unsafe fn from_raw(structure: S, node: HeaderOpaqueNodePtr<U>) -> Self {
+        Self { structure, node }
+    }
+
     #[inline]
     /// Drops the contained value.
     ///
@@ -249,6 +359,38 @@ where
         unsafe { structure.insert(node) };
     }
 
+    #[must_use]
+    #[inline]
+    /// Converts the node into an [`InitNode`], asserting that the value has been initialised.
+    ///
+    /// Unlike [`MaybeUninitNode::insert`], [`InitNode::insert`] is safe to call.
+    ///
+    #[doc = init_docs!()]
+    pub const unsafe fn assume_init(self) -> InitNode<U, S> {
+        InitNode { node: self }
+    }
+
+    /// Writes `value` into the node and unsizes it to `U`, returning an initialised node.
+    ///
+    /// # Safety
+    /// The node must have been allocated for a value of type `F`, for example with metadata from `&value as &U`.
+    pub unsafe fn write_unsize<F>(self, value: F) -> InitNode<U, S>
+    where
+        F: Unsize<U>,
+    {
+        debug_assert_eq!(
+            // SAFETY:
+            // The node's metadata is valid for the allocation and for `U`.
+            unsafe { Layout::for_value_raw(self.as_ptr().as_ptr()) },
+            Layout::new::<F>()
+        );
+        // SAFETY:
+        // The node was allocated for a value of type `F` (safety condition), so the value pointer is
+        // valid for writes of `F`.
+        unsafe { self.value_ptr().cast::<F>().write(value) };
+        InitNode { node: self }
+    }
+
     #[cfg(feature = "alloc")]
     /// Attempts to move the value into a box in the given allocator and return it.
     ///
@@ -268,7 +410,9 @@ where
         let ptr = match allocator.allocate(value_layout) {
             Ok(value) => value,
             Err(error) => {
-                return Err(AllocateError::new_alloc(error, value_layout).with_value(self))
+                return Err(AllocateError::new_alloc(error, value_layout)
+                    .with_context("take_boxed")
+                    .with_value(self))
             }
         };
 
@@ -359,6 +503,34 @@ where
     }
 }
 
+impl<'a, U, S> MaybeUninitNode<U, &'a mut S>
+where
+    U: ?Sized,
+    S: ?Sized,
+    &'a mut S: StructureHandle<U>,
+{
+    #[must_use]
+    /// Inserts the node into the structure and returns a mutable reference to its value.
+    ///
+    /// The handle's mutable borrow of the structure is moved into the returned reference, so the structure cannot be used again until the reference is dropped.
+    /// This is useful for mutating an element after inserting it without having to find it again.
+    ///
+    #[doc = init_docs!()]
+    ///
+    /// The structure must also keep the node allocated, and must not access its value, after inserting it for as long as the structure is mutably borrowed.
+    pub unsafe fn insert_and_get(self) -> &'a mut U {
+        let mut ptr = self.as_ptr();
+        // SAFETY:
+        // The value is initialised (safety condition).
+        unsafe { self.insert() };
+        // SAFETY:
+        // - the value is initialised (safety condition)
+        // - the node is kept allocated and its value is not accessed by the structure while it is
+        //   borrowed for `'a` (safety condition), so the reference is not aliased
+        unsafe { ptr.as_mut() }
+    }
+}
+
 impl<U, S> Drop for MaybeUninitNode<U, S>
 where
     U: ?Sized,
@@ -377,6 +549,29 @@ impl<T, S> MaybeUninitNode<T, S>
 where
     S: StructureHandle<T>,
 {
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the possibly uninitialised value.
+    ///
+    /// This is the same as [`AsRef::as_ref`].
+    pub const fn value(&self) -> &MaybeUninit<T> {
+        // SAFETY:
+        // The pointer is only accessible through this node, so there are only shared references to it.
+        unsafe { self.as_ptr().as_uninit_ref() }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets a mutable reference to the possibly uninitialised value.
+    ///
+    /// This is the same as [`AsMut::as_mut`].
+    /// Once the value has been written, the node can be initialised with [`Self::assume_init`].
+    pub const fn value_mut(&mut self) -> &mut MaybeUninit<T> {
+        // SAFETY:
+        // The pointer is only accessible through this node, so there are no references to it.
+        unsafe { self.as_mut_ptr().as_uninit_mut() }
+    }
+
     #[must_use]
     #[inline]
     /// Removes the contained value.
@@ -388,6 +583,15 @@ where
         // - this node's data is initialised and valid (safety condition)
         unsafe { self.as_ptr().read() }
     }
+
+    #[inline]
+    /// Writes `value` into the node, returning an initialised node.
+    ///
+    /// Unlike [`MaybeUninitNode::insert`], [`InitNode::insert`] is safe to call.
+    pub const fn write(mut self, value: T) -> InitNode<T, S> {
+        self.value_mut().write(value);
+        InitNode { node: self }
+    }
 }
 
 impl<T, S> MaybeUninitNode<[T], S>
@@ -413,37 +617,90 @@ where
     where
         T: Clone,
     {
-        struct DropGuard<'a, T, S>
-        where
-            S: StructureHandle<[T]>,
-        {
-            node: &'a mut MaybeUninitNode<[T], S>,
-            len: usize,
-        }
+        self.write_from_iter(src.iter().cloned());
+    }
 
-        impl<T, S> Drop for DropGuard<'_, T, S>
-        where
-            S: StructureHandle<[T]>,
-        {
-            fn drop(&mut self) {
-                self.node.as_mut()[..self.len].iter_mut().for_each(|value| {
-                    // SAFETY:
-                    // The first `self.len` elements have been initialised by `T`'s `clone`
-                    // method.
-                    unsafe { value.assume_init_drop() }
-                });
-            }
+    /// Fills the node's slice with clones of `value`.
+    ///
+    /// If cloning panics, the elements that have already been written are dropped.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        let dest = self.as_mut();
+        let Some((last, rest)) = dest.split_last_mut() else {
+            return;
+        };
+
+        let mut guard = InitGuard {
+            slice: rest,
+            len: 0,
+        };
+        for dst in guard.slice.iter_mut() {
+            dst.write(value.clone());
+            guard.len += 1;
         }
+        last.write(value);
 
-        let mut guard = DropGuard { node: self, len: 0 };
+        // Prevent the guard from dropping the written values
+        mem::forget(guard);
+    }
 
-        for (dst, value) in guard.node.as_mut().iter_mut().zip(src) {
-            dst.write(value.clone());
+    /// Fills the node's slice with values returned by calling `f` repeatedly.
+    ///
+    /// If `f` panics, the elements that have already been written are dropped.
+    pub fn fill_with<F>(&mut self, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        let len = self.as_mut().len();
+        let written = self.write_from_iter(core::iter::repeat_with(&mut f).take(len));
+        debug_assert_eq!(written, len);
+    }
+
+    /// Writes values from `iter` into the node's slice, returning the number of elements written.
+    ///
+    /// This stops when either the slice is full or `iter` runs out; `iter` is not advanced once the slice is full.
+    /// Note that if fewer elements than the length of the slice are written, some of the slice will not be initialised.
+    /// If `iter` panics, the elements that have already been written are dropped.
+    pub fn write_from_iter<I>(&mut self, iter: I) -> usize
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut guard = InitGuard {
+            slice: self.as_mut(),
+            len: 0,
+        };
+
+        let mut iter = iter.into_iter();
+        while guard.len < guard.slice.len() {
+            let Some(value) = iter.next() else {
+                break;
+            };
+            guard.slice[guard.len].write(value);
             guard.len += 1;
         }
 
-        // Prevent the guard from dropping the cloned values
+        let len = guard.len;
+        // Prevent the guard from dropping the written values
         mem::forget(guard);
+        len
+    }
+}
+
+/// Drops the first `len` elements of `slice` if a panic occurs while initialising it.
+struct InitGuard<'a, T> {
+    slice: &'a mut [MaybeUninit<T>],
+    len: usize,
+}
+
+impl<T> Drop for InitGuard<'_, T> {
+    fn drop(&mut self) {
+        self.slice[..self.len].iter_mut().for_each(|value| {
+            // SAFETY:
+            // The first `self.len` elements have been initialised.
+            unsafe { value.assume_init_drop() }
+        });
     }
 }
 
@@ -485,6 +742,180 @@ where
         let len = cmp::min(dest.len(), src.len());
         MaybeUninit::copy_from_slice(&mut self.as_bytes_mut()[..len], &src.as_bytes()[..len]);
     }
+
+    /// Copies as much of the string slice `src` into the node as fits, returning [`true`] if all of it fit.
+    ///
+    /// If `src` is too long, it is truncated to the last character boundary that fits, so the copied string is always valid UTF-8.
+    /// Note that if the copied string is shorter than the contained slice, some of the string may not be initialised.
+    pub fn copy_from_str_truncating(&mut self, src: &str) -> bool {
+        let dest = self.as_bytes_mut();
+        let mut len = cmp::min(dest.len(), src.len());
+        while !src.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        MaybeUninit::copy_from_slice(&mut dest[..len], &src.as_bytes()[..len]);
+        len == src.len()
+    }
+
+    /// Copies the string slice `src` into the node if it fits, returning [`true`] if it did.
+    ///
+    /// If `src` is longer than the contained slice, nothing is copied.
+    /// Note that if `src` is shorter than the contained slice, some of the string may not be initialised.
+    pub fn try_copy_from_str(&mut self, src: &str) -> bool {
+        let dest = self.as_bytes_mut();
+        if src.len() > dest.len() {
+            return false;
+        }
+
+        MaybeUninit::copy_from_slice(&mut dest[..src.len()], src.as_bytes());
+        true
+    }
+}
+
+/// A node with an initialised value.
+///
+/// This is created by [`MaybeUninitNode::write`], [`MaybeUninitNode::write_unsize`] or [`MaybeUninitNode::assume_init`].
+/// As the value is known to be initialised, [`Self::insert`] is safe to call.
+/// Dropping the node drops the value and deallocates the node.
+pub struct InitNode<U, S>
+where
+    U: ?Sized,
+    S: StructureHandle<U>,
+{
+    node: MaybeUninitNode<U, S>,
+}
+
+impl<U, S> InitNode<U, S>
+where
+    U: ?Sized,
+    S: StructureHandle<U>,
+{
+    #[must_use]
+    #[inline]
+    /// Gets a pointer to the value with no metadata.
+    pub const fn value_ptr(&self) -> NonNull<()> {
+        self.node.value_ptr()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets a pointer to the value.
+    pub const fn as_ptr(&self) -> NonNull<U> {
+        self.node.as_ptr()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Converts the node back into a [`MaybeUninitNode`] without dropping the value.
+    ///
+    /// If the returned node is dropped, the value will be leaked.
+    pub fn into_uninit(self) -> MaybeUninitNode<U, S> {
+        let me = ManuallyDrop::new(self);
+        // SAFETY:
+        // `me` is never used again, so this is a move.
+        unsafe { NonNull::from(&me.node).read() }
+    }
+
+    #[inline]
+    /// Inserts the node into the structure.
+    pub fn insert(self) {
+        let node = self.into_uninit();
+        // SAFETY:
+        // The value was initialised when this was created.
+        unsafe { node.insert() };
+    }
+}
+
+impl<T, S> InitNode<T, S>
+where
+    S: StructureHandle<T>,
+{
+    #[must_use]
+    #[inline]
+    /// Removes the contained value.
+    pub fn take(self) -> T {
+        let node = self.into_uninit();
+        // SAFETY:
+        // The value was initialised when this was created.
+        unsafe { node.take() }
+    }
+}
+
+impl<'a, U, S> InitNode<U, &'a mut S>
+where
+    U: ?Sized,
+    S: ?Sized,
+    &'a mut S: StructureHandle<U>,
+{
+    #[must_use]
+    #[inline]
+    /// Inserts the node into the structure and returns a mutable reference to its value.
+    ///
+    /// See [`MaybeUninitNode::insert_and_get`] for more information.
+    ///
+    /// # Safety
+    /// The structure must keep the node allocated, and must not access its value, after inserting it for as long as the structure is mutably borrowed.
+    pub unsafe fn insert_and_get(self) -> &'a mut U {
+        let node = self.into_uninit();
+        // SAFETY:
+        // The value was initialised when this was created, and the structure keeps the node
+        // (safety condition).
+        unsafe { node.insert_and_get() }
+    }
+}
+
+impl<U, S> Drop for InitNode<U, S>
+where
+    U: ?Sized,
+    S: StructureHandle<U>,
+{
+    fn drop(&mut self) {
+        // SAFETY:
+        // The value was initialised when this was created and has not been dropped.
+        // The node itself is deallocated when the `node` field is dropped, even if this panics.
+        unsafe { self.node.drop_in_place() };
+    }
+}
+
+impl<U, S> Deref for InitNode<U, S>
+where
+    U: ?Sized,
+    S: StructureHandle<U>,
+{
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // The value is initialised and the pointer is only accessible through this node, so there
+        // are no mutable references to it.
+        unsafe { self.as_ptr().as_ref() }
+    }
+}
+
+impl<U, S> DerefMut for InitNode<U, S>
+where
+    U: ?Sized,
+    S: StructureHandle<U>,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY:
+        // The value is initialised and the pointer is only accessible through this node, so there
+        // are no other references to it.
+        unsafe { self.as_ptr().as_mut() }
+    }
+}
+
+impl<U, S> fmt::Debug for InitNode<U, S>
+where
+    U: ?Sized + fmt::Debug,
+    S: StructureHandle<U>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("InitNode").field(&&**self).finish()
+    }
 }
 
 // SAFETY:
@@ -519,9 +950,7 @@ where
     #[must_use]
     #[inline]
     fn as_ref(&self) -> &MaybeUninit<T> {
-        // SAFETY:
-        // The pointer is only accessible through this node, so there are only shared references to it.
-        unsafe { self.as_ptr().as_uninit_ref() }
+        self.value()
     }
 }
 
@@ -546,9 +975,7 @@ where
     #[must_use]
     #[inline]
     fn as_mut(&mut self) -> &mut MaybeUninit<T> {
-        // SAFETY:
-        // The pointer is only accessible through this node, so there are no references to it.
-        unsafe { self.as_ptr().as_uninit_mut() }
+        self.value_mut()
     }
 }
 
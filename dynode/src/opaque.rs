@@ -50,6 +50,40 @@ where
         unsafe { NodePtr::from_value_ptr(self.mid) }
     }
 
+    #[must_use]
+    #[inline]
+    /// Get the pointer to the node's header.
+    ///
+    /// The header's offset only depends on `Header` and the value's metadata type, so this is the same pointer that [`NodePtr::header_ptr`] returns.
+    ///
+    /// # Safety
+    /// `Header` must be the same header type that the node was allocated with.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use dynode::{HeaderOpaqueNodePtr, NodePtr};
+    /// let node = NodePtr::<u32, [u8]>::allocate_array(3);
+    /// unsafe { node.header_ptr().write(7) };
+    ///
+    /// let opaque: HeaderOpaqueNodePtr<[u8]> = node.to_header_opaque();
+    /// assert_eq!(unsafe { opaque.header_ptr::<u32>().read() }, 7);
+    /// unsafe { node.deallocate_global() };
+    /// ```
+    pub unsafe fn header_ptr<Header>(self) -> NonNull<Header> {
+        // SAFETY:
+        // `Header` is the node's header type (safety condition).
+        unsafe { self.to_transparent::<Header>() }.header_ptr()
+    }
+
+    #[must_use]
+    #[inline]
+    /// Create a node pointer with abstracted header and value types.
+    pub const fn to_fully_opaque(self) -> FullyOpaqueNodePtr {
+        // SAFETY:
+        // `self.mid` is the value pointer of a node.
+        unsafe { FullyOpaqueNodePtr::from_value_ptr(self.mid) }
+    }
+
     #[must_use]
     /// Get the metadata of the node's data.
     ///
@@ -115,3 +149,96 @@ where
         value.to_header_opaque()
     }
 }
+
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+/// A pointer to a node with abstracted header and value types.
+///
+/// This is useful for storing nodes of different types together.
+/// These must be allocated and deallocated as [`NodePtr`]s.
+/// ```rust
+/// # use dynode::{FullyOpaqueNodePtr, NodePtr};
+/// # type Header = ();
+/// # type T = ();
+/// let node: NodePtr<Header, T> = NodePtr::allocate_sized();
+/// let opaque: FullyOpaqueNodePtr = node.to_fully_opaque();
+/// let node: NodePtr<Header, T> = unsafe { opaque.to_transparent() };
+/// unsafe { node.deallocate_global() };
+/// ```
+pub struct FullyOpaqueNodePtr {
+    mid: NonNull<()>,
+}
+
+impl FullyOpaqueNodePtr {
+    #[must_use]
+    #[inline]
+    /// Add a value type back into a node.
+    ///
+    /// # Safety
+    /// `U` must be the same value type that the node was allocated with.
+    /// Using the wrong type is undefined behaviour, as the node's metadata will be read as `U`'s metadata.
+    pub const unsafe fn to_header_opaque<U>(self) -> HeaderOpaqueNodePtr<U>
+    where
+        U: ?Sized,
+    {
+        // SAFETY:
+        // `self.mid` is the value pointer of a node with a value of type `U` (safety condition).
+        unsafe { HeaderOpaqueNodePtr::from_value_ptr(self.mid) }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Add header and value types back into a node.
+    ///
+    /// # Safety
+    /// `Header` and `U` must be the same header and value types that the node was allocated with.
+    /// Using the wrong types is undefined behaviour, as the node's metadata will be read as `U`'s metadata.
+    pub const unsafe fn to_transparent<Header, U>(self) -> NodePtr<Header, U>
+    where
+        U: ?Sized,
+    {
+        // SAFETY:
+        // `self.mid` is the value pointer of a node with a header of type `Header` and a value of
+        // type `U` (safety condition).
+        unsafe { NodePtr::from_value_ptr(self.mid) }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Get the pointer to the node's value.
+    ///
+    /// This does not include any metadata.
+    pub const fn value_ptr(self) -> NonNull<()> {
+        self.mid
+    }
+
+    #[must_use]
+    #[inline]
+    /// Get a node back from its value pointer.
+    ///
+    /// # Safety
+    /// The value pointer must have come from a call to a `value_ptr` method on a node.
+    pub const unsafe fn from_value_ptr(ptr: NonNull<()>) -> Self {
+        Self { mid: ptr }
+    }
+}
+
+impl<Header, U> From<NodePtr<Header, U>> for FullyOpaqueNodePtr
+where
+    U: ?Sized,
+{
+    #[inline]
+    fn from(value: NodePtr<Header, U>) -> Self {
+        value.to_fully_opaque()
+    }
+}
+
+impl<U> From<HeaderOpaqueNodePtr<U>> for FullyOpaqueNodePtr
+where
+    U: ?Sized,
+{
+    #[inline]
+    fn from(value: HeaderOpaqueNodePtr<U>) -> Self {
+        value.to_fully_opaque()
+    }
+}
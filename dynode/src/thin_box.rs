@@ -0,0 +1,305 @@
+use core::{
+    alloc::Allocator,
+    clone::CloneToUninit,
+    fmt,
+    marker::Unsize,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    ptr,
+};
+
+use crate::{alloc::Global, AllocateError, NodePtr};
+
+/// An owned pointer to a possibly unsized value that is only one pointer wide.
+///
+/// The value's metadata, such as the length of a slice or the vtable of a trait object, is stored in the allocation before the value rather than in the pointer.
+/// This makes it useful for storing many unsized values compactly, at the cost of an extra read to get the metadata.
+///
+/// # Examples
+/// ```
+/// # use core::{fmt::Debug, mem::size_of};
+/// # use dynode::ThinBox;
+/// let slice = ThinBox::<[u8]>::new_unsize([1, 2, 3]);
+/// assert_eq!(&*slice, [1, 2, 3]);
+///
+/// let values: [ThinBox<dyn Debug>; 3] = [
+///     ThinBox::new_unsize("One"),
+///     ThinBox::new_unsize(1),
+///     ThinBox::new_unsize('1'),
+/// ];
+/// assert_eq!(format!("{values:?}"), r#"["One", 1, '1']"#);
+///
+/// assert_eq!(size_of::<ThinBox<dyn Debug>>(), size_of::<usize>());
+/// ```
+pub struct ThinBox<T, A = Global>
+where
+    T: ?Sized,
+    A: Allocator,
+{
+    node: NodePtr<(), T>,
+    allocator: A,
+}
+
+/// Deallocates a node when dropped, even if dropping its value panicked.
+struct DeallocateGuard<'a, T, A>
+where
+    T: ?Sized,
+    A: Allocator,
+{
+    node: NodePtr<(), T>,
+    allocator: &'a A,
+}
+
+impl<T, A> Drop for DeallocateGuard<'_, T, A>
+where
+    T: ?Sized,
+    A: Allocator,
+{
+    fn drop(&mut self) {
+        // SAFETY:
+        // The node was allocated by `self.allocator` and is not used again.
+        unsafe { self.node.deallocate(self.allocator) };
+    }
+}
+
+impl<T> ThinBox<T>
+where
+    T: ?Sized,
+{
+    #[must_use]
+    /// Moves `value` into a new thin box and unsizes it.
+    pub fn new_unsize<F>(value: F) -> Self
+    where
+        F: Unsize<T>,
+    {
+        Self::new_unsize_in(value, Global)
+    }
+}
+
+impl<T, A> ThinBox<T, A>
+where
+    T: ?Sized,
+    A: Allocator,
+{
+    /// Attempts to move `value` into a new thin box in the given allocator and unsize it.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`] with the value in it.
+    pub fn try_new_unsize_in<F>(value: F, allocator: A) -> Result<Self, AllocateError<F>>
+    where
+        F: Unsize<T>,
+    {
+        let node = match NodePtr::try_allocate_unsize_in::<_, F>(&allocator) {
+            Ok(node) => node,
+            Err(error) => return Err(error.with_context("new_unsize").with_value(value)),
+        };
+        // SAFETY:
+        // The node was allocated for an `F` and is not aliased.
+        unsafe { node.value_ptr().cast::<F>().write(value) };
+
+        Ok(Self { node, allocator })
+    }
+
+    #[must_use]
+    /// Moves `value` into a new thin box in the given allocator and unsizes it.
+    pub fn new_unsize_in<F>(value: F, allocator: A) -> Self
+    where
+        F: Unsize<T>,
+    {
+        AllocateError::unwrap_result(Self::try_new_unsize_in(value, allocator))
+    }
+
+    #[must_use]
+    #[inline]
+    /// Gets a reference to the box's allocator.
+    ///
+    /// This is an associated function, so it does not shadow methods on the value.
+    pub const fn allocator(boxed: &Self) -> &A {
+        &boxed.allocator
+    }
+}
+
+impl<T> ThinBox<T> {
+    #[must_use]
+    /// Moves `value` into a new thin box.
+    pub fn new(value: T) -> Self {
+        Self::new_in(value, Global)
+    }
+}
+
+impl<T, A> ThinBox<T, A>
+where
+    A: Allocator,
+{
+    /// Attempts to move `value` into a new thin box in the given allocator.
+    ///
+    /// # Errors
+    /// If allocation fails, this will return an [`AllocateError`] with the value in it.
+    pub fn try_new_in(value: T, allocator: A) -> Result<Self, AllocateError<T>> {
+        let node = match NodePtr::try_allocate_sized_in(&allocator) {
+            Ok(node) => node,
+            Err(error) => return Err(error.with_context("new").with_value(value)),
+        };
+        // SAFETY:
+        // The node was allocated for a `T` and is not aliased.
+        unsafe { node.value_ptr().cast::<T>().write(value) };
+
+        Ok(Self { node, allocator })
+    }
+
+    #[must_use]
+    /// Moves `value` into a new thin box in the given allocator.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(allocator_api)]
+    /// # use dynode::ThinBox;
+    /// let boxed = ThinBox::new_in(5_u32, std::alloc::Global);
+    /// assert_eq!(ThinBox::into_inner(boxed), 5);
+    /// ```
+    pub fn new_in(value: T, allocator: A) -> Self {
+        AllocateError::unwrap_result(Self::try_new_in(value, allocator))
+    }
+
+    #[must_use]
+    /// Moves the value out of the box and deallocates it.
+    ///
+    /// This is an associated function, so it does not shadow methods on the value.
+    pub fn into_inner(boxed: Self) -> T {
+        let boxed = ManuallyDrop::new(boxed);
+        // SAFETY:
+        // `boxed` is never used or dropped again, so this is a move.
+        let allocator = unsafe { ptr::read(&raw const boxed.allocator) };
+        // SAFETY:
+        // The node has not been deallocated.
+        let ptr = unsafe { boxed.node.data_ptr() };
+        // SAFETY:
+        // The value is initialised and, as the box is not dropped, it is not used again.
+        let value = unsafe { ptr.read() };
+        // SAFETY:
+        // The node was allocated by `allocator` and the value has been moved out of it.
+        unsafe { boxed.node.deallocate(&allocator) };
+        value
+    }
+}
+
+impl<T, A> Deref for ThinBox<T, A>
+where
+    T: ?Sized,
+    A: Allocator,
+{
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // SAFETY:
+        // The node has not been deallocated.
+        let ptr = unsafe { self.node.data_ptr() };
+        // SAFETY:
+        // The value is initialised and can only be mutated through `&mut self`.
+        unsafe { ptr.as_ref() }
+    }
+}
+
+impl<T, A> DerefMut for ThinBox<T, A>
+where
+    T: ?Sized,
+    A: Allocator,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY:
+        // The node has not been deallocated.
+        let mut ptr = unsafe { self.node.data_ptr() };
+        // SAFETY:
+        // The value is initialised and the box is mutably borrowed, so it is not aliased.
+        unsafe { ptr.as_mut() }
+    }
+}
+
+impl<T, A> Drop for ThinBox<T, A>
+where
+    T: ?Sized,
+    A: Allocator,
+{
+    fn drop(&mut self) {
+        let guard = DeallocateGuard {
+            node: self.node,
+            allocator: &self.allocator,
+        };
+        // SAFETY:
+        // The node has not been deallocated.
+        let ptr = unsafe { guard.node.data_ptr() };
+        // SAFETY:
+        // The value is initialised and is not used again.
+        unsafe { ptr.drop_in_place() };
+    }
+}
+
+impl<T, A> Clone for ThinBox<T, A>
+where
+    T: ?Sized + CloneToUninit,
+    A: Allocator + Clone,
+{
+    /// Clones the value into a new thin box in a clone of the allocator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dynode::ThinBox;
+    /// let boxed = ThinBox::<[String]>::new_unsize([String::from("Hi")]);
+    /// let mut clone = boxed.clone();
+    /// clone[0].push('!');
+    ///
+    /// assert_eq!(boxed[0], "Hi");
+    /// assert_eq!(clone[0], "Hi!");
+    /// ```
+    fn clone(&self) -> Self {
+        let allocator = self.allocator.clone();
+        // SAFETY:
+        // The node has not been deallocated, its value is initialised and it can only be mutated
+        // through `&mut self`.
+        let result = unsafe { self.node.try_clone_node_in((), &allocator) };
+        let node = AllocateError::unwrap_result(result);
+        Self { node, allocator }
+    }
+}
+
+impl<T, A> fmt::Debug for ThinBox<T, A>
+where
+    T: ?Sized + fmt::Debug,
+    A: Allocator,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T, A> fmt::Display for ThinBox<T, A>
+where
+    T: ?Sized + fmt::Display,
+    A: Allocator,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+// SAFETY:
+// The box owns its value and allocator, so it can be sent between threads if they can.
+unsafe impl<T, A> Send for ThinBox<T, A>
+where
+    T: ?Sized + Send,
+    A: Allocator + Send,
+{
+}
+
+// SAFETY:
+// A shared box only gives out shared references to its value and allocator.
+unsafe impl<T, A> Sync for ThinBox<T, A>
+where
+    T: ?Sized + Sync,
+    A: Allocator + Sync,
+{
+}